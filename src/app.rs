@@ -1,11 +1,15 @@
 use std::path::{Path, PathBuf};
 
-use crate::cli::{CheckArgs, DetectArgs, FixArgs, FormatArgs, Verbosity};
-use crate::commands::CheckCommand;
+use crate::cli::{
+    CheckArgs, DetectArgs, FixArgs, FormatArgs, HookArgs, InitArgs, ReportArgs, SuggestArgs,
+    Verbosity,
+};
+use crate::commands::{CheckCommand, CheckStatus, WatchCommand};
 use crate::config::{ConfigProvider, SirenConfig};
 use crate::detection::ProjectDetector;
 use crate::errors::SirenError;
 use crate::output::OutputFormatter;
+use crate::suggestions::Suggestion;
 use crate::tools::ToolRegistry;
 
 /// Core application that orchestrates the workflow of Siren
@@ -21,6 +25,7 @@ where
     tool_registry: R,
     output_formatter: O,
     verbosity: Verbosity,
+    fail_level: Option<String>,
 }
 
 impl<D, C, R, O> SirenApp<D, C, R, O>
@@ -38,6 +43,7 @@ where
             tool_registry,
             output_formatter,
             verbosity: Verbosity::default(),
+            fail_level: None,
         }
     }
 
@@ -47,15 +53,39 @@ where
         self
     }
 
+    /// Override the configured fail level
+    pub fn with_fail_level(mut self, fail_level: Option<String>) -> Self {
+        self.fail_level = fail_level;
+        self
+    }
+
     /// Run the check command (lint)
     pub async fn check(
         &self,
         args: CheckArgs,
         paths: Vec<PathBuf>,
         git_modified_only: bool,
-    ) -> Result<(), SirenError> {
+    ) -> Result<CheckStatus, SirenError> {
         // Load configuration
-        let config = self.load_config(&paths)?;
+        let mut config = self.load_config(&paths)?;
+
+        // The command line takes precedence over the config file
+        if let Some(fail_level) = &self.fail_level {
+            config.general.fail_level = fail_level.clone();
+        }
+
+        if args.watch {
+            let watch_command = WatchCommand::new(
+                self.detector.clone(),
+                self.tool_registry.clone(),
+                self.output_formatter.clone(),
+                self.verbosity,
+            );
+
+            return watch_command
+                .execute(args, paths, git_modified_only, &config)
+                .await;
+        }
 
         // Create a CheckCommand instance and delegate execution
         let check_command = CheckCommand::new(
@@ -131,6 +161,70 @@ where
         detect_command.execute(args, paths)
     }
 
+    /// Run the init command
+    pub fn init(&self, args: InitArgs, paths: Vec<PathBuf>) -> Result<PathBuf, SirenError> {
+        // Create an InitCommand instance and delegate execution
+        let init_command = crate::commands::InitCommand::new(self.detector.clone(), self.verbosity);
+
+        // Delegate to the InitCommand
+        init_command.execute(args, paths)
+    }
+
+    /// Run the hook command
+    ///
+    /// Returns the paths of the hooks that were written.
+    pub fn hook(&self, args: HookArgs) -> Result<Vec<PathBuf>, SirenError> {
+        crate::commands::HookCommand::new(self.verbosity).execute(args)
+    }
+
+    /// Run the report command
+    pub async fn report(
+        &self,
+        args: ReportArgs,
+        paths: Vec<PathBuf>,
+        git_modified_only: bool,
+    ) -> Result<(), SirenError> {
+        // Load configuration
+        let config = self.load_config(&paths)?;
+
+        // Create a ReportCommand instance and delegate execution
+        let report_command = crate::commands::ReportCommand::new(
+            self.detector.clone(),
+            self.tool_registry.clone(),
+            self.output_formatter.clone(),
+            self.verbosity,
+        );
+
+        // Delegate to the ReportCommand
+        report_command
+            .execute(args, paths, git_modified_only, &config)
+            .await
+    }
+
+    /// Run the suggest command
+    pub async fn suggest(
+        &self,
+        args: SuggestArgs,
+        paths: Vec<PathBuf>,
+        git_modified_only: bool,
+    ) -> Result<Vec<Suggestion>, SirenError> {
+        // Load configuration
+        let config = self.load_config(&paths)?;
+
+        // Create a SuggestCommand instance and delegate execution
+        let suggest_command = crate::commands::SuggestCommand::new(
+            self.detector.clone(),
+            self.tool_registry.clone(),
+            self.output_formatter.clone(),
+            self.verbosity,
+        );
+
+        // Delegate to the SuggestCommand
+        suggest_command
+            .execute(args, paths, git_modified_only, &config)
+            .await
+    }
+
     // Helper methods
 
     /// Load configuration from the provided paths
@@ -0,0 +1,241 @@
+//! Baselines that hide issues which already existed when they were recorded
+//!
+//! An issue is fingerprinted by its tool, code, file and the normalized
+//! content of the line it points at, not by its line number, so the baseline
+//! keeps matching when code above it moves around. Editing the offending line
+//! itself changes the fingerprint and brings the issue back.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Component, Path};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::errors::{ConfigError, SirenError};
+use crate::models::{LintIssue, LintResult};
+use crate::utils::normalize_path;
+
+/// Name of the baseline file, relative to the project root
+pub const BASELINE_FILE: &str = ".siren-baseline.json";
+
+/// Version of the baseline file format
+const BASELINE_VERSION: u32 = 1;
+
+/// Identity of an issue that survives line shifts
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Fingerprint {
+    /// Tool that reported the issue
+    pub tool: String,
+
+    /// Rule code, if the tool reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// File relative to the project root, with forward slashes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+
+    /// Hash of the whitespace-normalized line content
+    pub hash: String,
+}
+
+/// A fingerprint and how many issues share it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    #[serde(flatten)]
+    pub fingerprint: Fingerprint,
+
+    /// Number of identical issues, e.g. the same warning on duplicated lines
+    pub count: usize,
+}
+
+/// Issues recorded by `check --write-baseline`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    /// File format version
+    pub version: u32,
+
+    /// Recorded issues, sorted so the file diffs cleanly
+    pub entries: Vec<BaselineEntry>,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl Baseline {
+    /// Record every issue in the results, with files relative to `root`
+    pub fn from_results(results: &[LintResult], root: &Path) -> Self {
+        let mut lines = LineCache::default();
+        let mut counts: BTreeMap<Fingerprint, usize> = BTreeMap::new();
+
+        for result in results {
+            for issue in &result.issues {
+                *counts
+                    .entry(fingerprint(&result.tool_name, issue, root, &mut lines))
+                    .or_default() += 1;
+            }
+        }
+
+        Self {
+            version: BASELINE_VERSION,
+            entries: counts
+                .into_iter()
+                .map(|(fingerprint, count)| BaselineEntry { fingerprint, count })
+                .collect(),
+        }
+    }
+
+    /// Load a baseline, returning `None` when the file does not exist
+    pub fn load(path: &Path) -> Result<Option<Self>, SirenError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+        let baseline: Self = serde_json::from_str(&content).map_err(|e| {
+            ConfigError::ParseError(format!("Invalid baseline {}: {}", path.display(), e))
+        })?;
+
+        if baseline.version != BASELINE_VERSION {
+            return Err(ConfigError::InvalidValue {
+                key: "version".to_string(),
+                value: baseline.version.to_string(),
+                message: format!(
+                    "{} was written by a different version of siren, re-create it with --write-baseline",
+                    path.display()
+                ),
+            }
+            .into());
+        }
+
+        Ok(Some(baseline))
+    }
+
+    /// Write the baseline as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<(), SirenError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| SirenError::Io(std::io::Error::other(e)))?;
+        fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    /// Total number of recorded issues
+    pub fn len(&self) -> usize {
+        self.entries.iter().map(|entry| entry.count).sum()
+    }
+
+    /// Whether the baseline records no issues
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every issue the baseline already knows about
+    ///
+    /// Each entry hides at most `count` issues, so a second copy of a
+    /// baselined problem is still reported. Returns how many were hidden.
+    pub fn filter(&self, results: &mut [LintResult], root: &Path) -> usize {
+        let mut remaining: HashMap<&Fingerprint, usize> = self
+            .entries
+            .iter()
+            .map(|entry| (&entry.fingerprint, entry.count))
+            .collect();
+        let mut lines = LineCache::default();
+        let mut hidden = 0;
+
+        for result in results.iter_mut() {
+            let before = result.issues.len();
+            let tool = result.tool_name.clone();
+
+            result.issues.retain(|issue| {
+                match remaining.get_mut(&fingerprint(&tool, issue, root, &mut lines)) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                }
+            });
+
+            if result.issues.len() != before {
+                hidden += before - result.issues.len();
+                result.success = result.issues.is_empty();
+            }
+        }
+
+        hidden
+    }
+}
+
+/// Fingerprint a single issue
+///
+/// Issues without a readable line (project-wide messages, deleted files) fall
+/// back to hashing the message instead.
+pub fn fingerprint(
+    tool: &str,
+    issue: &LintIssue,
+    root: &Path,
+    lines: &mut LineCache,
+) -> Fingerprint {
+    let content = issue
+        .file
+        .as_deref()
+        .zip(issue.line)
+        .and_then(|(file, line)| lines.line(file, line))
+        .unwrap_or_else(|| issue.message.clone());
+
+    Fingerprint {
+        tool: tool.to_string(),
+        code: issue.code.clone(),
+        file: issue.file.as_deref().map(|file| portable_path(file, root)),
+        hash: hash_line(&content),
+    }
+}
+
+/// Reads each source file once while fingerprinting
+#[derive(Debug, Default)]
+pub struct LineCache {
+    files: HashMap<std::path::PathBuf, Option<Vec<String>>>,
+}
+
+impl LineCache {
+    /// Content of a 1-based line, if the file and line exist
+    pub fn line(&mut self, file: &Path, line: usize) -> Option<String> {
+        let lines = self.files.entry(file.to_path_buf()).or_insert_with(|| {
+            fs::read_to_string(file)
+                .ok()
+                .map(|content| content.lines().map(String::from).collect())
+        });
+
+        lines.as_ref()?.get(line.checked_sub(1)?).cloned()
+    }
+}
+
+/// Hash a line with all runs of whitespace collapsed
+fn hash_line(line: &str) -> String {
+    let normalized = line.split_whitespace().collect::<Vec<_>>().join(" ");
+    Sha256::digest(normalized.as_bytes())
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Path relative to the project root with forward slashes, the same on every
+/// machine and from every working directory
+fn portable_path(file: &Path, root: &Path) -> String {
+    let file = normalize_path(file);
+    file.strip_prefix(normalize_path(root))
+        .unwrap_or(&file)
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -0,0 +1,321 @@
+//! Content-hash result cache for skipping unchanged files
+//!
+//! Every entry holds the issues one tool reported for one file. Entries are
+//! keyed by the file's content hash, the tool name and version and the
+//! effective tool configuration, so editing a file, upgrading a tool or
+//! changing its arguments all miss the cache naturally and nothing ever has to
+//! be invalidated by hand.
+//!
+//! Tools whose issues depend on more than one file, see
+//! [`LintTool::cross_file`], also key every entry by a digest of all the
+//! project files they can handle, so any edit re-runs them in full.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::models::tools::ToolConfig;
+use crate::models::{LintIssue, LintResult, ToolInfo};
+use crate::tools::LintTool;
+use crate::utils::normalize_path;
+
+/// Cache directory, relative to the project root
+pub const CACHE_DIR: &str = ".siren/cache";
+
+/// What is stored on disk for one tool and one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Tool that produced the issues, kept for humans poking at the cache
+    tool: String,
+
+    /// Issues reported for the file
+    issues: Vec<LintIssue>,
+}
+
+/// On-disk cache of per-file lint issues
+#[derive(Debug, Clone)]
+pub struct ResultCache {
+    root: PathBuf,
+    dir: PathBuf,
+}
+
+impl ResultCache {
+    /// Create the cache for a project rooted at `root`
+    pub fn for_project(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            dir: root.join(CACHE_DIR),
+        }
+    }
+
+    /// Directory the entries live in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Compute the cache key for one file's content
+    pub fn key(tool_name: &str, version: &str, config: &ToolConfig, content: &[u8]) -> String {
+        // Going through a JSON value sorts map keys, which keeps env_vars stable
+        let config = serde_json::to_value(config)
+            .map(|value| value.to_string())
+            .unwrap_or_else(|_| format!("{:?}", config));
+
+        let mut hasher = Sha256::new();
+        for part in [
+            tool_name.as_bytes(),
+            version.as_bytes(),
+            config.as_bytes(),
+            &Sha256::digest(content),
+        ] {
+            hasher.update(part);
+            hasher.update([0]);
+        }
+
+        to_hex(&hasher.finalize())
+    }
+
+    /// Read the issues cached under a key
+    pub fn get(&self, key: &str) -> Option<Vec<LintIssue>> {
+        let content = fs::read_to_string(self.entry_path(key)).ok()?;
+        match serde_json::from_str::<CacheEntry>(&content) {
+            Ok(entry) => Some(entry.issues),
+            Err(e) => {
+                debug!("Ignoring unreadable cache entry {}: {}", key, e);
+                None
+            }
+        }
+    }
+
+    /// Store the issues for a key
+    pub fn put(&self, key: &str, tool_name: &str, issues: &[LintIssue]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let entry = CacheEntry {
+            tool: tool_name.to_string(),
+            issues: issues.to_vec(),
+        };
+        let json = serde_json::to_string(&entry).map_err(io::Error::other)?;
+
+        // Write then rename so a concurrent run never reads half an entry
+        let path = self.entry_path(key);
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &path)
+    }
+
+    /// Split the files a tool would check into cache hits and misses
+    ///
+    /// Directories are expanded to the files the tool can handle, honouring
+    /// `.gitignore`. Returns `None` when the tool does not report a version,
+    /// because a cache that survives tool upgrades would serve stale issues.
+    pub fn lookup(
+        &self,
+        tool: &dyn LintTool,
+        config: &ToolConfig,
+        paths: &[PathBuf],
+    ) -> Option<CacheLookup> {
+        let version = tool.version()?;
+        let project_digest = tool.cross_file().then(|| self.project_digest(tool));
+
+        let mut lookup = CacheLookup {
+            tool_name: tool.name().to_string(),
+            version,
+            hit_files: 0,
+            replayed: Vec::new(),
+            misses: Vec::new(),
+        };
+
+        for file in expand_paths(tool, paths) {
+            let Ok(content) = fs::read(&file) else {
+                continue;
+            };
+            let key = match &project_digest {
+                Some(digest) => Self::key(
+                    &lookup.tool_name,
+                    &lookup.version,
+                    config,
+                    &[content.as_slice(), digest.as_bytes()].concat(),
+                ),
+                None => Self::key(&lookup.tool_name, &lookup.version, config, &content),
+            };
+
+            match self.get(&key) {
+                Some(issues) => {
+                    lookup.hit_files += 1;
+                    lookup
+                        .replayed
+                        .extend(issues.into_iter().map(|issue| LintIssue {
+                            file: issue.file.as_ref().map(|_| file.clone()),
+                            ..issue
+                        }));
+                }
+                None => lookup.misses.push((file, key)),
+            }
+        }
+
+        Some(lookup)
+    }
+
+    /// Record the issues from a run over a lookup's missed files
+    ///
+    /// Issues are bucketed by file. If the tool reported anything that cannot
+    /// be tied to one of the missed files (a project-wide message, or a file
+    /// outside the checked set) nothing is stored, so the next run asks the
+    /// tool again rather than silently dropping that issue.
+    pub fn store(&self, lookup: &CacheLookup, result: &LintResult) {
+        let mut by_file: HashMap<PathBuf, Vec<LintIssue>> = lookup
+            .misses
+            .iter()
+            .map(|(file, _)| (normalize_path(file), Vec::new()))
+            .collect();
+
+        for issue in &result.issues {
+            let bucket = issue
+                .file
+                .as_ref()
+                .and_then(|file| by_file.get_mut(&normalize_path(file)));
+            match bucket {
+                Some(bucket) => bucket.push(issue.clone()),
+                None => {
+                    debug!(
+                        "Not caching {} results: issue outside the checked files",
+                        lookup.tool_name
+                    );
+                    return;
+                }
+            }
+        }
+
+        for (file, key) in &lookup.misses {
+            let issues = by_file.get(&normalize_path(file)).map(Vec::as_slice);
+            if let Err(e) = self.put(key, &lookup.tool_name, issues.unwrap_or_default()) {
+                debug!("Failed to write cache entry for {}: {}", file.display(), e);
+                return;
+            }
+        }
+    }
+
+    /// Digest of every project file a tool can handle, names and contents
+    fn project_digest(&self, tool: &dyn LintTool) -> String {
+        let mut hasher = Sha256::new();
+        for file in expand_paths(tool, std::slice::from_ref(&self.root)) {
+            let Ok(content) = fs::read(&file) else {
+                continue;
+            };
+            let name = file.strip_prefix(&self.root).unwrap_or(&file);
+            hasher.update(name.to_string_lossy().as_bytes());
+            hasher.update([0]);
+            hasher.update(Sha256::digest(&content));
+        }
+
+        to_hex(&hasher.finalize())
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
+
+/// Result of looking up one tool's files in the cache
+#[derive(Debug, Clone)]
+pub struct CacheLookup {
+    tool_name: String,
+    version: String,
+    hit_files: usize,
+    replayed: Vec<LintIssue>,
+    misses: Vec<(PathBuf, String)>,
+}
+
+impl CacheLookup {
+    /// Number of files answered from the cache
+    pub fn hit_files(&self) -> usize {
+        self.hit_files
+    }
+
+    /// Files the tool still has to check
+    pub fn missed_files(&self) -> Vec<PathBuf> {
+        self.misses.iter().map(|(file, _)| file.clone()).collect()
+    }
+
+    /// Whether every file was answered from the cache
+    ///
+    /// A lookup that found no files at all is never complete, the tool may
+    /// discover files on its own that `can_handle` does not know about.
+    pub fn is_complete(&self) -> bool {
+        self.hit_files > 0 && self.misses.is_empty()
+    }
+
+    /// Paths to hand to the tool
+    ///
+    /// On a cold cache the original paths are kept so the tool sees the same
+    /// arguments as an uncached run; otherwise only the changed files are passed.
+    pub fn paths_to_run(&self, original: &[PathBuf]) -> Vec<PathBuf> {
+        if self.hit_files == 0 {
+            original.to_vec()
+        } else {
+            self.missed_files()
+        }
+    }
+
+    /// Add the replayed issues to a fresh result
+    pub fn merge_into(&self, result: &mut LintResult) {
+        result.success &= self.replayed.is_empty();
+        result.issues.extend(self.replayed.iter().cloned());
+    }
+
+    /// Build a result entirely from cached issues
+    pub fn replay(&self, tool: &dyn LintTool) -> LintResult {
+        LintResult {
+            tool_name: self.tool_name.clone(),
+            tool: Some(ToolInfo {
+                name: self.tool_name.clone(),
+                tool_type: tool.tool_type(),
+                languages: tool.languages(),
+                available: true,
+                version: Some(self.version.clone()),
+                description: tool.description().to_string(),
+            }),
+            success: self.replayed.is_empty(),
+            issues: self.replayed.clone(),
+            execution_time: Duration::from_secs(0),
+            stdout: None,
+            stderr: None,
+        }
+    }
+}
+
+/// Expand directories into the files a tool can handle
+fn expand_paths(tool: &dyn LintTool, paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for path in paths {
+        if path.is_file() {
+            if tool.can_handle(path) {
+                files.push(path.clone());
+            }
+            continue;
+        }
+
+        let walker = ignore::WalkBuilder::new(path).git_global(false).build();
+        for entry in walker.filter_map(Result::ok) {
+            let file = entry.path();
+            if file.is_file() && tool.can_handle(file) {
+                files.push(file.to_path_buf());
+            }
+        }
+    }
+
+    files.sort();
+    files.dedup();
+    files
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -4,12 +4,13 @@ use clap::{Args, Parser, Subcommand};
 use std::path::PathBuf;
 
 /// Verbosity level for output
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
 pub enum Verbosity {
     /// Quiet mode - only show errors
     Quiet = 0,
 
     /// Normal mode - show errors and warnings
+    #[default]
     Normal = 1,
 
     /// Verbose mode - show errors, warnings, and info
@@ -19,12 +20,6 @@ pub enum Verbosity {
     Debug = 3,
 }
 
-impl Default for Verbosity {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl From<u8> for Verbosity {
     fn from(value: u8) -> Self {
         match value {
@@ -42,7 +37,8 @@ impl From<u8> for Verbosity {
     author,
     version,
     about = "🧜‍♀️ Siren - Enchanting code quality with irresistible standards",
-    long_about = "Siren is a bewitching frontend for multiple linting tools that makes maintaining code quality a delightful experience. Like the mythological sirens that lured sailors with their enchanting voices, Siren entices developers with beautiful output, smart defaults, and an intuitive interface - making code quality standards impossible to resist."
+    long_about = "Siren is a bewitching frontend for multiple linting tools that makes maintaining code quality a delightful experience. Like the mythological sirens that lured sailors with their enchanting voices, Siren entices developers with beautiful output, smart defaults, and an intuitive interface - making code quality standards impossible to resist.",
+    after_long_help = "Exit codes:\n  0  No issues at or above the fail level\n  1  Issues at or above the fail level were found\n  2  Invalid configuration, arguments or paths\n  3  A tool failed to execute\n  4  Any other error (I/O, project detection)"
 )]
 pub struct Cli {
     /// Command to execute
@@ -61,8 +57,8 @@ pub struct Cli {
     #[arg(short = 'l', long)]
     pub language: Option<String>,
 
-    /// Fail on issues of this level or higher
-    #[arg(long)]
+    /// Fail on issues of this level or higher (error, warning, info, style)
+    #[arg(long, global = true)]
     pub fail_level: Option<String>,
 
     /// Verbosity level (-q=quiet, -v=verbose, -vv=very verbose)
@@ -78,7 +74,7 @@ pub struct Cli {
     pub config: Option<PathBuf>,
 
     /// CI mode (non-interactive, machine-readable output)
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub ci: bool,
 }
 
@@ -115,6 +111,9 @@ pub enum Commands {
 
     /// Output suggestion for improving code quality
     Suggest(SuggestArgs),
+
+    /// Manage git hooks that run Siren
+    Hook(HookArgs),
 }
 
 /// Arguments for the check command
@@ -132,7 +131,7 @@ pub struct CheckArgs {
     #[arg(long)]
     pub tool_types: Option<Vec<String>>,
 
-    /// Output format
+    /// Output format (pretty, json, sarif, github, junit)
     #[arg(long, default_value = "pretty")]
     pub format: String,
 
@@ -140,6 +139,34 @@ pub struct CheckArgs {
     #[arg(short, long)]
     pub auto_fix: bool,
 
+    /// Re-run every tool instead of replaying cached results for unchanged files
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Keep running and re-check whenever files change
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// Record the current issues in .siren-baseline.json so later runs only report new ones
+    #[arg(long, conflicts_with = "watch")]
+    pub write_baseline: bool,
+
+    /// Report every issue, including those recorded in the baseline
+    #[arg(long, conflicts_with = "write_baseline")]
+    pub no_baseline: bool,
+
+    /// Only check files changed since the merge base with this ref (e.g. origin/main)
+    #[arg(long, visible_alias = "since", value_name = "REF")]
+    pub diff_base: Option<String>,
+
+    /// With --diff-base, only report issues on lines that changed
+    #[arg(long, requires = "diff_base")]
+    pub changed_lines: bool,
+
+    /// Only check files staged for commit, as they are in the index
+    #[arg(long, conflicts_with_all = ["diff_base", "watch", "auto_fix"])]
+    pub staged: bool,
+
     /// Files or directories to check
     #[arg(name = "PATH")]
     pub paths: Vec<PathBuf>,
@@ -239,6 +266,10 @@ pub struct ReportArgs {
     /// Output file (defaults to stdout)
     #[arg(short, long)]
     pub output: Option<PathBuf>,
+
+    /// Files or directories to include in the report
+    #[arg(name = "PATH")]
+    pub paths: Vec<PathBuf>,
 }
 
 /// Arguments for the suggest command
@@ -247,6 +278,37 @@ pub struct SuggestArgs {
     /// Maximum number of suggestions to show
     #[arg(short, long, default_value = "5")]
     pub max_suggestions: usize,
+
+    /// Files or directories to analyze
+    #[arg(name = "PATH")]
+    pub paths: Vec<PathBuf>,
+}
+
+/// Arguments for the hook command
+#[derive(Args, Debug, Clone)]
+pub struct HookArgs {
+    /// Hook action to perform
+    #[command(subcommand)]
+    pub action: HookAction,
+}
+
+/// Actions of the hook command
+#[derive(Subcommand, Debug, Clone)]
+pub enum HookAction {
+    /// Install a pre-commit hook that checks the staged files
+    Install(HookInstallArgs),
+}
+
+/// Arguments for `hook install`
+#[derive(Args, Debug, Clone)]
+pub struct HookInstallArgs {
+    /// Also install a pre-push hook that checks the branch against its upstream
+    #[arg(long)]
+    pub pre_push: bool,
+
+    /// Overwrite hooks that were not installed by Siren
+    #[arg(short, long)]
+    pub force: bool,
 }
 
 /// Arguments for the format-and-fix command
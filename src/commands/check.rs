@@ -1,20 +1,64 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use crate::baseline::{Baseline, BASELINE_FILE};
+use crate::cache::{CacheLookup, ResultCache};
 use crate::cli::{CheckArgs, Verbosity};
 use crate::config::{SirenConfig, ToolConfig as ConfigToolConfig};
 use crate::detection::ProjectDetector;
-use crate::errors::SirenError;
+use crate::errors::{exit_code, ConfigError, SirenError, ToolError};
 use crate::models::tools::ToolConfig;
-use crate::models::ToolType;
-use crate::output::{terminal, OutputFormatter};
+use crate::models::{IssueSeverity, LintResult, ProjectInfo, ToolType};
+use crate::output::{github, terminal, OutputFormatter};
 use crate::runner::ToolRunner;
+use crate::suppression::{apply_suppressions, SuppressedCounts};
 use crate::tools::{LintTool, ToolRegistry};
+use crate::utils::git::{ChangeSet, StagedSnapshot};
 use crate::utils::path_manager::PathManager;
 use colored::*;
 use log::debug;
 
+/// Everything produced by a check run, before any output is rendered
+#[derive(Debug, Clone)]
+pub struct CheckRun {
+    /// Project information detected for the checked paths
+    pub project_info: ProjectInfo,
+
+    /// Results from every tool that ran successfully
+    pub results: Vec<LintResult>,
+
+    /// Names of the tools that failed to execute
+    pub failed_tools: Vec<String>,
+
+    /// Issues hidden by inline suppression comments, per tool
+    pub suppressed: SuppressedCounts,
+}
+
+/// Overall outcome of a check, used for the process exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// No issues at or above the fail level
+    Passed,
+
+    /// Issues at or above the fail level were found
+    IssuesFound,
+
+    /// At least one tool failed to execute, so the results are incomplete
+    ToolFailed,
+}
+
+impl CheckStatus {
+    /// Process exit code for this status
+    pub fn exit_code(self) -> i32 {
+        match self {
+            CheckStatus::Passed => exit_code::SUCCESS,
+            CheckStatus::IssuesFound => exit_code::ISSUES_FOUND,
+            CheckStatus::ToolFailed => exit_code::TOOL_FAILED,
+        }
+    }
+}
+
 /// Command handler for the check (lint) command
 pub struct CheckCommand<D, R, O>
 where
@@ -51,7 +95,110 @@ where
         paths: Vec<PathBuf>,
         git_modified_only: bool,
         config: &SirenConfig,
-    ) -> Result<(), SirenError> {
+    ) -> Result<CheckStatus, SirenError> {
+        // Validate the threshold before spending time on a full run
+        let fail_level = parse_fail_level(&config.general.fail_level)?;
+
+        let write_baseline = args.write_baseline;
+        let run = self.run(args, paths, git_modified_only, config).await?;
+
+        if write_baseline {
+            let root = project_root();
+            let baseline = Baseline::from_results(&run.results, &root);
+            baseline.save(&root.join(BASELINE_FILE))?;
+
+            if self.verbosity >= Verbosity::Normal {
+                println!(
+                    "\n📌 Recorded {} issues in {}",
+                    baseline.len(),
+                    BASELINE_FILE.bright_white().bold()
+                );
+            }
+
+            // Everything found is now accepted, only tool failures still count
+            return Ok(check_status(&[], &run.failed_tools, fail_level));
+        }
+
+        Ok(self.print_run(&run, config, fail_level))
+    }
+
+    /// Print the results of a finished run and work out its status
+    pub fn print_run(
+        &self,
+        run: &CheckRun,
+        config: &SirenConfig,
+        fail_level: IssueSeverity,
+    ) -> CheckStatus {
+        let all_results = &run.results;
+        let status = check_status(all_results, &run.failed_tools, fail_level);
+
+        // The job summary is written on every run, clean ones included
+        if let Some(summary) = self
+            .output_formatter
+            .job_summary(all_results, &run.suppressed)
+        {
+            github::append_step_summary(&summary);
+        }
+
+        // Machine-readable output is always a single complete document
+        if self.output_formatter.is_machine_readable() {
+            println!(
+                "{}",
+                self.output_formatter
+                    .format_results(all_results, &config.output)
+            );
+            return status;
+        }
+
+        // Print the results
+        if !all_results.is_empty() {
+            // Print the results
+            println!(
+                "{}",
+                self.output_formatter
+                    .format_results(all_results, &config.output)
+            );
+
+            // Print the summary
+            println!(
+                "{}",
+                self.output_formatter
+                    .format_summary(all_results, &run.suppressed)
+            );
+        } else if self.verbosity >= Verbosity::Normal {
+            println!("\nNo issues found!");
+        }
+
+        if self.verbosity >= Verbosity::Normal {
+            match status {
+                CheckStatus::Passed => {}
+                CheckStatus::IssuesFound => println!(
+                    "\n{} Found issues at or above the '{}' fail level",
+                    "✖".red(),
+                    config.general.fail_level
+                ),
+                CheckStatus::ToolFailed => println!(
+                    "\n{} Failed to run: {}",
+                    "✖".red(),
+                    run.failed_tools.join(", ")
+                ),
+            }
+        }
+
+        status
+    }
+
+    /// Run all applicable linters and collect their results without printing them
+    ///
+    /// Progress output (detection info, spinners) still follows the verbosity
+    /// level; pass `Verbosity::Quiet` to keep stdout untouched.
+    pub async fn run(
+        &self,
+        args: CheckArgs,
+        paths: Vec<PathBuf>,
+        git_modified_only: bool,
+        config: &SirenConfig,
+    ) -> Result<CheckRun, SirenError> {
         // Combine paths from the Cli struct and CheckArgs
         let all_paths = if !args.paths.is_empty() {
             args.paths.clone()
@@ -61,7 +208,19 @@ where
 
         // Create and initialize the path manager
         let mut path_manager = PathManager::new();
-        path_manager.collect_files(&all_paths, git_modified_only)?;
+        let mut changeset = None;
+        let mut snapshot = None;
+        if let Some(base) = &args.diff_base {
+            let changes = ChangeSet::since(Path::new("."), base)?;
+            path_manager.add_files_within(changes.files(), &all_paths);
+            changeset = Some(changes);
+        } else if args.staged {
+            // Lint what is about to be committed, not what is on disk
+            snapshot = Some(StagedSnapshot::take(Path::new("."))?);
+            path_manager.collect_staged_files(&all_paths)?;
+        } else {
+            path_manager.collect_files(&all_paths, git_modified_only)?;
+        }
 
         // Detect project information
         let (project_info, _) = self.detector.detect(&all_paths)?;
@@ -119,9 +278,21 @@ where
             }
         }
 
+        // Narrow down to the tools asked for on the command line
+        if let Some(names) = &args.tools {
+            linters.retain(|linter| names.iter().any(|name| name == linter.name()));
+        }
+
         if linters.is_empty() {
-            println!("⚠️ No linters found for the detected languages.");
-            return Ok(());
+            if self.verbosity >= Verbosity::Normal {
+                println!("⚠️ No linters found for the detected languages.");
+            }
+            return Ok(CheckRun {
+                project_info,
+                results: Vec::new(),
+                failed_tools: Vec::new(),
+                suppressed: SuppressedCounts::default(),
+            });
         }
 
         // Collect files to check
@@ -152,8 +323,19 @@ where
         // Create a tool runner
         let tool_runner = ToolRunner::new();
 
+        // Fixes rewrite files while the tools run, so their results are never cached
+        let cache = if args.no_cache || args.auto_fix {
+            None
+        } else {
+            Some(ResultCache::for_project(&project_root()))
+        };
+
         // Create our neon status display
-        let mut status_display = terminal::NeonDisplay::new();
+        let mut status_display = if self.verbosity >= Verbosity::Normal {
+            terminal::NeonDisplay::new()
+        } else {
+            terminal::NeonDisplay::hidden()
+        };
 
         // Store captured outputs for display at the end - only if in verbose mode
         let mut captured_outputs = Vec::new();
@@ -208,6 +390,7 @@ where
 
         // Process results and update the status
         let mut all_results = Vec::new();
+        let mut failed_tools = Vec::new();
         let mut tool_statuses = Vec::new();
         let mut total_issues = 0;
 
@@ -245,12 +428,36 @@ where
                 spinner_indices.push(spinner_index);
             }
 
-            // Run all tools in this group in parallel with their specific paths
+            // Look up every tool's files in the cache before running anything
+            let lookups: Vec<Option<CacheLookup>> = group_tools
+                .iter()
+                .map(|tool| {
+                    let paths = tool_paths_map.get(tool.name())?;
+                    cache
+                        .as_ref()?
+                        .lookup(tool.as_ref(), &config_for_runner, paths)
+                })
+                .collect();
+
+            // Only tools with uncached files need to run
+            let mut tools_to_run = Vec::new();
             let mut tool_specific_paths_vec = Vec::new();
-            for tool in &group_tools {
-                let tool_specific_paths =
+            for (tool, lookup) in group_tools.iter().zip(&lookups) {
+                let mut tool_specific_paths =
                     tool_paths_map.get(tool.name()).cloned().unwrap_or_default();
 
+                if let Some(lookup) = lookup {
+                    if lookup.is_complete() {
+                        debug!(
+                            "Replaying {} cached files for {}",
+                            lookup.hit_files(),
+                            tool.name()
+                        );
+                        continue;
+                    }
+                    tool_specific_paths = lookup.paths_to_run(&tool_specific_paths);
+                }
+
                 // Log tool execution if verbose
                 if self.verbosity >= Verbosity::Verbose {
                     debug!(
@@ -260,17 +467,43 @@ where
                     );
                 }
 
+                tools_to_run.push(tool.clone());
                 tool_specific_paths_vec.push(tool_specific_paths);
             }
 
             // Run all tools in this group in parallel with their specific paths
-            let group_results = tool_runner
+            let mut run_results = tool_runner
                 .run_tools_with_specific_paths(
-                    group_tools.clone(),
+                    tools_to_run,
                     tool_specific_paths_vec,
                     &config_for_runner,
                 )
-                .await;
+                .await
+                .into_iter();
+
+            // Line fresh and replayed results back up with the group's tools
+            let group_results: Vec<_> = group_tools
+                .iter()
+                .zip(&lookups)
+                .map(|(tool, lookup)| match lookup {
+                    Some(lookup) if lookup.is_complete() => Ok(lookup.replay(tool.as_ref())),
+                    _ => {
+                        let mut result = run_results.next().unwrap_or_else(|| {
+                            Err(ToolError::ExecutionFailed {
+                                name: tool.name().to_string(),
+                                message: "No result returned by the runner".to_string(),
+                            })
+                        });
+                        if let (Some(cache), Some(lookup), Ok(result)) =
+                            (&cache, lookup, &mut result)
+                        {
+                            cache.store(lookup, result);
+                            lookup.merge_into(result);
+                        }
+                        result
+                    }
+                })
+                .collect();
 
             // Process results for this group
             for (i, result) in group_results.into_iter().enumerate() {
@@ -312,6 +545,7 @@ where
                         all_results.push(result);
                     }
                     Err(err) => {
+                        failed_tools.push(linter.name().to_string());
                         status_display.finish_spinner(
                             spinner_index,
                             format!("{} 「{}」", linter.name(), "execution failed".red()),
@@ -339,7 +573,9 @@ where
         status_display.finish(total_issues);
 
         // A moment to appreciate the UI
-        std::thread::sleep(std::time::Duration::from_millis(300));
+        if self.verbosity >= Verbosity::Normal {
+            std::thread::sleep(std::time::Duration::from_millis(300));
+        }
 
         // Now display the captured outputs if in verbose mode
         if self.verbosity >= Verbosity::Verbose && !captured_outputs.is_empty() {
@@ -362,22 +598,41 @@ where
             }
         }
 
-        // Print the results
-        if !all_results.is_empty() {
-            // Print the results
-            println!(
-                "{}",
-                self.output_formatter
-                    .format_results(&all_results, &config.output)
-            );
+        // Leave out issues on lines the change did not touch
+        if let Some(changeset) = changeset.as_ref().filter(|_| args.changed_lines) {
+            let dropped = changeset.filter_results(&mut all_results);
+            if dropped > 0 && self.verbosity >= Verbosity::Normal {
+                println!("\n✂️ {} issues outside the changed lines left out", dropped);
+            }
+        }
 
-            // Print the summary
-            println!("{}", self.output_formatter.format_summary(&all_results));
-        } else {
-            println!("\nNo issues found!");
+        // Drop issues silenced by `siren: ignore` comments in the sources
+        let suppressed = apply_suppressions(&mut all_results);
+
+        // Hide issues that were already there when the baseline was recorded
+        if !args.write_baseline && !args.no_baseline {
+            let root = project_root();
+            if let Some(baseline) = Baseline::load(&root.join(BASELINE_FILE))? {
+                let hidden = baseline.filter(&mut all_results, &root);
+                if hidden > 0 && self.verbosity >= Verbosity::Normal {
+                    println!(
+                        "\n📌 {} known issues hidden by {} (--no-baseline to show them)",
+                        hidden, BASELINE_FILE
+                    );
+                }
+            }
         }
 
-        Ok(())
+        if let Some(snapshot) = snapshot {
+            snapshot.restore()?;
+        }
+
+        Ok(CheckRun {
+            project_info,
+            results: all_results,
+            failed_tools,
+            suppressed,
+        })
     }
 
     /// Convert from ConfigToolConfig to ToolConfig
@@ -389,7 +644,55 @@ where
             executable_path: None,
             report_level: None,
             auto_fix: config.auto_fix.unwrap_or(false),
+            unsafe_fixes: false,
             check: config.check.unwrap_or(false),
         }
     }
 }
+
+/// Directory the baseline and the result cache live in
+///
+/// The top of the git repository, so running from a subdirectory finds the
+/// same files, or the working directory outside of git.
+fn project_root() -> PathBuf {
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    crate::utils::git::toplevel(&cwd).unwrap_or(cwd)
+}
+
+/// Parse the configured fail level into a severity threshold
+pub(crate) fn parse_fail_level(fail_level: &str) -> Result<IssueSeverity, SirenError> {
+    fail_level.parse::<IssueSeverity>().map_err(|message| {
+        ConfigError::InvalidValue {
+            key: "fail_level".to_string(),
+            value: fail_level.to_string(),
+            message,
+        }
+        .into()
+    })
+}
+
+/// Work out the outcome of a check run
+///
+/// A tool failure wins over issues because the results are incomplete.
+/// Severities order from `Error` down to `Style`, so an issue is at or above
+/// the threshold when it compares less than or equal to it.
+pub fn check_status(
+    results: &[LintResult],
+    failed_tools: &[String],
+    fail_level: IssueSeverity,
+) -> CheckStatus {
+    if !failed_tools.is_empty() {
+        return CheckStatus::ToolFailed;
+    }
+
+    let failing = results
+        .iter()
+        .flat_map(|r| &r.issues)
+        .any(|issue| issue.severity <= fail_level);
+
+    if failing {
+        CheckStatus::IssuesFound
+    } else {
+        CheckStatus::Passed
+    }
+}
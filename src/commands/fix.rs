@@ -9,6 +9,7 @@ use crate::models::tools::ToolConfig as ModelsToolConfig;
 use crate::models::ToolType;
 use crate::output::OutputFormatter;
 use crate::runner::ToolRunner;
+use crate::suppression::SuppressedCounts;
 use crate::tools::ToolRegistry;
 use crate::utils::path_manager::PathManager;
 
@@ -137,6 +138,16 @@ where
             }
         }
 
+        // Narrow down to the tools asked for on the command line, by fixer
+        // name or by the checker it fixes for (`clippy` for `clippy-fix`)
+        if let Some(names) = &args.tools {
+            fixers.retain(|fixer| {
+                names.iter().any(|name| {
+                    name == fixer.name() || fixer.name().strip_suffix("-fix") == Some(name.as_str())
+                })
+            });
+        }
+
         if fixers.is_empty() {
             println!("⚠️ No fixers found for the detected languages.");
 
@@ -199,6 +210,7 @@ where
         // Setup default tool configuration
         let mut default_config = self.convert_tool_config(&default_tool_config);
         default_config.auto_fix = true; // Ensure auto_fix is enabled for fixers
+        default_config.unsafe_fixes = args.unsafe_fixes;
 
         if self.verbosity >= Verbosity::Normal {
             println!("🔧 Running {} fixers...", available_fixers.len());
@@ -252,7 +264,9 @@ where
             println!("{}", results_output);
 
             // Display summary
-            let summary = self.output_formatter.format_summary(&all_results);
+            let summary = self
+                .output_formatter
+                .format_summary(&all_results, &SuppressedCounts::default());
             println!("\n{}", summary);
         } else {
             println!("✨ No issues to fix!");
@@ -270,6 +284,7 @@ where
             executable_path: None,
             report_level: None,
             auto_fix: config.auto_fix.unwrap_or(false),
+            unsafe_fixes: false,
             check: config.check.unwrap_or(false),
         }
     }
@@ -9,6 +9,7 @@ use crate::models::tools::ToolConfig as ModelsToolConfig;
 use crate::models::ToolType;
 use crate::output::{terminal, OutputFormatter};
 use crate::runner::ToolRunner;
+use crate::suppression::SuppressedCounts;
 use crate::tools::ToolRegistry;
 use crate::utils::path_manager::PathManager;
 use colored::*;
@@ -112,6 +113,11 @@ where
             }
         }
 
+        // Narrow down to the tools asked for on the command line
+        if let Some(names) = &args.tools {
+            formatters.retain(|formatter| names.iter().any(|name| name == formatter.name()));
+        }
+
         if formatters.is_empty() {
             println!("⚠️ No formatters found for the detected languages.");
 
@@ -338,7 +344,11 @@ where
             println!("{}", results_output);
 
             // Display summary
-            println!("\n{}", self.output_formatter.format_summary(&all_results));
+            println!(
+                "\n{}",
+                self.output_formatter
+                    .format_summary(&all_results, &SuppressedCounts::default())
+            );
         } else if total_issues > 0 {
             // Just show a simple summary if there are issues but we're not showing details
             println!("\n✨ {} files were formatted!", total_issues);
@@ -356,6 +366,7 @@ where
             executable_path: None,
             report_level: None,
             auto_fix: config.auto_fix.unwrap_or(false),
+            unsafe_fixes: false,
             check: config.check.unwrap_or(false),
         }
     }
@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{HookAction, HookArgs, HookInstallArgs, Verbosity};
+use crate::errors::SirenError;
+use crate::utils::git;
+use colored::*;
+
+/// Comment that marks a hook as written by Siren, so it can be replaced safely
+pub const HOOK_MARKER: &str = "# Installed by siren hook install";
+
+/// Runs the staged content through `siren check` before every commit
+const PRE_COMMIT: &str = r#"#!/bin/sh
+# Installed by siren hook install
+exec siren check --staged
+"#;
+
+/// Checks everything the branch changed relative to its upstream before a push
+const PRE_PUSH: &str = r#"#!/bin/sh
+# Installed by siren hook install
+if base=$(git rev-parse --abbrev-ref --symbolic-full-name '@{upstream}' 2>/dev/null); then
+    exec siren check --diff-base "$base"
+fi
+exec siren check
+"#;
+
+/// Command handler for the hook command
+pub struct HookCommand {
+    verbosity: Verbosity,
+}
+
+impl HookCommand {
+    /// Create a new hook command handler
+    pub fn new(verbosity: Verbosity) -> Self {
+        Self { verbosity }
+    }
+
+    /// Execute the hook command
+    ///
+    /// Returns the paths of the hooks that were written.
+    pub fn execute(&self, args: HookArgs) -> Result<Vec<PathBuf>, SirenError> {
+        match args.action {
+            HookAction::Install(args) => self.install(&args, Path::new(".")),
+        }
+    }
+
+    /// Install the hooks into the repository containing `dir`
+    ///
+    /// Hooks written by someone else are left alone unless `force` is set, and
+    /// nothing is written at all when one of them is in the way.
+    pub fn install(&self, args: &HookInstallArgs, dir: &Path) -> Result<Vec<PathBuf>, SirenError> {
+        let hooks_dir = git::hooks_dir(dir)?;
+
+        let mut hooks = vec![(hooks_dir.join("pre-commit"), PRE_COMMIT)];
+        if args.pre_push {
+            hooks.push((hooks_dir.join("pre-push"), PRE_PUSH));
+        }
+
+        if !args.force {
+            if let Some((path, _)) = hooks.iter().find(|(path, _)| is_foreign_hook(path)) {
+                return Err(SirenError::InvalidArgument(format!(
+                    "{} already exists and was not installed by Siren (use --force to overwrite)",
+                    path.display()
+                )));
+            }
+        }
+
+        fs::create_dir_all(&hooks_dir)?;
+        let mut written = Vec::new();
+        for (path, script) in hooks {
+            fs::write(&path, script)?;
+            make_executable(&path)?;
+
+            if self.verbosity >= Verbosity::Normal {
+                println!(
+                    "🪝 Installed {}",
+                    path.display().to_string().bright_white().bold()
+                );
+            }
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+}
+
+fn is_foreign_hook(path: &Path) -> bool {
+    path.exists() && !fs::read_to_string(path).is_ok_and(|content| content.contains(HOOK_MARKER))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<(), SirenError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_mode(0o755);
+    fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<(), SirenError> {
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::path::{Path, PathBuf};
+
+use crate::cli::{InitArgs, Verbosity};
+use crate::config::{defaults, CONFIG_FILE_NAME};
+use crate::detection::ProjectDetector;
+use crate::errors::{ConfigError, SirenError};
+use colored::*;
+
+/// Command handler for the init command
+pub struct InitCommand<D>
+where
+    D: ProjectDetector,
+{
+    detector: D,
+    verbosity: Verbosity,
+}
+
+impl<D> InitCommand<D>
+where
+    D: ProjectDetector,
+{
+    /// Create a new init command handler
+    pub fn new(detector: D, verbosity: Verbosity) -> Self {
+        Self {
+            detector,
+            verbosity,
+        }
+    }
+
+    /// Execute the init command
+    ///
+    /// Returns the path of the written configuration file.
+    pub fn execute(&self, args: InitArgs, paths: Vec<PathBuf>) -> Result<PathBuf, SirenError> {
+        // Use the first path as project root or current dir if empty
+        let project_root = paths
+            .first()
+            .map(|p| p.as_path())
+            .unwrap_or_else(|| Path::new("."));
+
+        let config_path = project_root.join(CONFIG_FILE_NAME);
+
+        // Never clobber an existing config unless asked to
+        if config_path.exists() && !args.force {
+            return Err(ConfigError::AlreadyExists(config_path).into());
+        }
+
+        // Detect project information
+        let (project_info, _) = self.detector.detect(&[project_root.to_path_buf()])?;
+
+        // Build and serialize the configuration
+        let config = defaults::create_project_config(&project_info, project_root, args.team);
+        let mut content = String::from("# 🧜‍♀️ Siren configuration generated by `siren init`\n");
+        if args.team {
+            content.push_str("# Team profile: shared, stricter defaults\n");
+        }
+        content.push('\n');
+        content.push_str(&config.to_toml_string()?);
+
+        std::fs::write(&config_path, content)?;
+
+        if self.verbosity >= Verbosity::Normal {
+            println!(
+                "✨ Created {} {}",
+                config_path.display().to_string().bright_white().bold(),
+                if args.team {
+                    "(team profile)".bright_blue()
+                } else {
+                    "".normal()
+                }
+            );
+
+            let languages = project_info
+                .languages
+                .iter()
+                .filter(|l| config.languages.contains_key(l))
+                .map(|l| format!("{:?}", l))
+                .collect::<Vec<_>>();
+            if !languages.is_empty() {
+                println!("  {} Languages: {}", "•".cyan(), languages.join(", "));
+            }
+
+            let mut tools = config.tools.keys().cloned().collect::<Vec<_>>();
+            tools.sort();
+            if !tools.is_empty() {
+                println!("  {} Tools: {}", "•".cyan(), tools.join(", "));
+            }
+        }
+
+        Ok(config_path)
+    }
+}
@@ -4,8 +4,18 @@ mod check;
 mod detect;
 mod fix;
 mod format;
+mod hook;
+mod init;
+mod report;
+mod suggest;
+mod watch;
 
-pub use check::CheckCommand;
+pub use check::{check_status, CheckCommand, CheckRun, CheckStatus};
 pub use detect::DetectCommand;
 pub use fix::FixCommand;
 pub use format::FormatCommand;
+pub use hook::{HookCommand, HOOK_MARKER};
+pub use init::InitCommand;
+pub use report::ReportCommand;
+pub use suggest::SuggestCommand;
+pub use watch::{affected_tools, merge_runs, WatchCommand, WatchFilter};
@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+
+use crate::cli::{CheckArgs, ReportArgs, Verbosity};
+use crate::commands::CheckCommand;
+use crate::config::SirenConfig;
+use crate::detection::ProjectDetector;
+use crate::errors::SirenError;
+use crate::output::report::{Report, ReportFormat};
+use crate::output::OutputFormatter;
+use crate::tools::ToolRegistry;
+use colored::*;
+
+/// Command handler for the report command
+pub struct ReportCommand<D, R, O>
+where
+    D: ProjectDetector,
+    R: ToolRegistry + Clone,
+    O: OutputFormatter,
+{
+    detector: D,
+    tool_registry: R,
+    output_formatter: O,
+    verbosity: Verbosity,
+}
+
+impl<D, R, O> ReportCommand<D, R, O>
+where
+    D: ProjectDetector,
+    R: ToolRegistry + Clone,
+    O: OutputFormatter,
+{
+    /// Create a new report command handler
+    pub fn new(detector: D, tool_registry: R, output_formatter: O, verbosity: Verbosity) -> Self {
+        Self {
+            detector,
+            tool_registry,
+            output_formatter,
+            verbosity,
+        }
+    }
+
+    /// Execute the report command
+    ///
+    /// Runs a full check and writes the rendered report to `args.output`, or to
+    /// stdout when no output file is given.
+    pub async fn execute(
+        self,
+        args: ReportArgs,
+        paths: Vec<PathBuf>,
+        git_modified_only: bool,
+        config: &SirenConfig,
+    ) -> Result<(), SirenError> {
+        // Validate the format before spending time on a full run
+        let format = args
+            .format
+            .parse::<ReportFormat>()
+            .map_err(SirenError::InvalidArgument)?;
+
+        // Keep stdout clean when the report itself goes there
+        let run_verbosity = if args.output.is_some() {
+            self.verbosity
+        } else {
+            Verbosity::Quiet
+        };
+
+        let check_command = CheckCommand::new(
+            self.detector,
+            self.tool_registry,
+            self.output_formatter,
+            run_verbosity,
+        );
+
+        let check_args = CheckArgs {
+            strict: false,
+            tools: None,
+            tool_types: None,
+            format: "pretty".to_string(),
+            auto_fix: false,
+            no_cache: false,
+            watch: false,
+            write_baseline: false,
+            no_baseline: false,
+            diff_base: None,
+            changed_lines: false,
+            staged: false,
+            paths: args.paths.clone(),
+        };
+
+        let run = check_command
+            .run(check_args, paths, git_modified_only, config)
+            .await?;
+
+        let rendered = Report::new(&run.project_info, &run.results).render(format);
+
+        match &args.output {
+            Some(output_path) => {
+                std::fs::write(output_path, rendered)?;
+
+                if self.verbosity >= Verbosity::Normal {
+                    println!(
+                        "📊 Report written to {}",
+                        output_path.display().to_string().bright_white().bold()
+                    );
+                }
+            }
+            None => print!("{}", rendered),
+        }
+
+        Ok(())
+    }
+}
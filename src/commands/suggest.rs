@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+
+use crate::cli::{CheckArgs, SuggestArgs, Verbosity};
+use crate::commands::CheckCommand;
+use crate::config::{SirenConfig, CONFIG_FILE_NAME};
+use crate::detection::ProjectDetector;
+use crate::errors::SirenError;
+use crate::output::OutputFormatter;
+use crate::suggestions::{Priority, Suggestion, SuggestionEngine};
+use crate::tools::{ToolInfo, ToolRegistry};
+use colored::*;
+
+/// Command handler for the suggest command
+pub struct SuggestCommand<D, R, O>
+where
+    D: ProjectDetector,
+    R: ToolRegistry + Clone,
+    O: OutputFormatter,
+{
+    detector: D,
+    tool_registry: R,
+    output_formatter: O,
+    verbosity: Verbosity,
+}
+
+impl<D, R, O> SuggestCommand<D, R, O>
+where
+    D: ProjectDetector,
+    R: ToolRegistry + Clone,
+    O: OutputFormatter,
+{
+    /// Create a new suggest command handler
+    pub fn new(detector: D, tool_registry: R, output_formatter: O, verbosity: Verbosity) -> Self {
+        Self {
+            detector,
+            tool_registry,
+            output_formatter,
+            verbosity,
+        }
+    }
+
+    /// Execute the suggest command
+    ///
+    /// Runs a full check, feeds the results to the suggestion engine and prints
+    /// the highest ranked suggestions. Returns everything the engine produced.
+    pub async fn execute(
+        self,
+        args: SuggestArgs,
+        paths: Vec<PathBuf>,
+        git_modified_only: bool,
+        config: &SirenConfig,
+    ) -> Result<Vec<Suggestion>, SirenError> {
+        let all_paths = if !args.paths.is_empty() {
+            args.paths.clone()
+        } else {
+            paths
+        };
+
+        // Use the first path as project root or current dir if empty
+        let project_root = all_paths
+            .first()
+            .map(|p| p.as_path())
+            .unwrap_or_else(|| Path::new("."));
+        let has_siren_config = project_root.join(CONFIG_FILE_NAME).exists();
+
+        let registry = self.tool_registry.clone();
+
+        // The check itself is only a means to an end, keep it quiet
+        let run_verbosity = if self.verbosity >= Verbosity::Verbose {
+            self.verbosity
+        } else {
+            Verbosity::Quiet
+        };
+
+        let check_command = CheckCommand::new(
+            self.detector,
+            self.tool_registry,
+            self.output_formatter,
+            run_verbosity,
+        );
+
+        let check_args = CheckArgs {
+            strict: false,
+            tools: None,
+            tool_types: None,
+            format: "pretty".to_string(),
+            auto_fix: false,
+            no_cache: false,
+            watch: false,
+            write_baseline: false,
+            no_baseline: false,
+            diff_base: None,
+            changed_lines: false,
+            staged: false,
+            paths: Vec::new(),
+        };
+
+        let run = check_command
+            .run(check_args, all_paths, git_modified_only, config)
+            .await?;
+
+        // Only probe tools for the detected languages, availability checks are slow
+        let mut tool_info: Vec<ToolInfo> = Vec::new();
+        for language in &run.project_info.languages {
+            for tool in registry.get_tools_for_language(*language) {
+                if tool_info.iter().any(|t| t.name == tool.name()) {
+                    continue;
+                }
+                tool_info.push(ToolInfo {
+                    name: tool.name().to_string(),
+                    tool_type: tool.tool_type(),
+                    languages: tool.languages(),
+                    available: tool.is_available(),
+                    version: None,
+                    description: tool.description().to_string(),
+                });
+            }
+        }
+
+        let suggestions = SuggestionEngine::new(&run.project_info, &run.results)
+            .with_tools(tool_info)
+            .with_siren_config(has_siren_config)
+            .suggest();
+
+        print_suggestions(&suggestions, args.max_suggestions);
+
+        Ok(suggestions)
+    }
+}
+
+/// Print the top suggestions
+fn print_suggestions(suggestions: &[Suggestion], max_suggestions: usize) {
+    if suggestions.is_empty() {
+        println!(
+            "\n✨ {}",
+            "Nothing to suggest, your setup looks great!".green()
+        );
+        return;
+    }
+
+    println!("\n💫 {}\n", "Suggestions".bright_magenta().bold());
+
+    for (i, suggestion) in suggestions.iter().take(max_suggestions).enumerate() {
+        let priority = match suggestion.priority {
+            Priority::High => suggestion.priority.to_string().bright_red().bold(),
+            Priority::Medium => suggestion.priority.to_string().bright_yellow(),
+            Priority::Low => suggestion.priority.to_string().bright_blue(),
+        };
+
+        println!("  {}. [{}] {}", i + 1, priority, suggestion.title);
+        println!("     {} {}", "→".cyan(), suggestion.command.bright_white());
+    }
+
+    let hidden = suggestions.len().saturating_sub(max_suggestions);
+    if hidden > 0 {
+        println!(
+            "\n  {} more suggestions (use --max-suggestions to see them)",
+            hidden
+        );
+    }
+}
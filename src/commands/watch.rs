@@ -0,0 +1,305 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use colored::*;
+use ignore::gitignore::Gitignore;
+use log::debug;
+use notify::{Event, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+use super::check::parse_fail_level;
+use crate::cli::{CheckArgs, Verbosity};
+use crate::commands::{CheckCommand, CheckRun, CheckStatus};
+use crate::config::SirenConfig;
+use crate::detection::ProjectDetector;
+use crate::errors::SirenError;
+use crate::models::ToolType;
+use crate::output::OutputFormatter;
+use crate::tools::{LintTool, ToolRegistry};
+use crate::utils::path_manager::PathManager;
+
+/// How long the file system has to stay quiet before a re-run starts
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Directories whose churn never warrants a re-run
+const IGNORED_DIRS: [&str; 2] = [".git", ".siren"];
+
+/// Command handler for `check --watch`
+pub struct WatchCommand<D, R, O>
+where
+    D: ProjectDetector,
+    R: ToolRegistry + Clone,
+    O: OutputFormatter,
+{
+    check: CheckCommand<D, R, O>,
+    tool_registry: R,
+    verbosity: Verbosity,
+}
+
+impl<D, R, O> WatchCommand<D, R, O>
+where
+    D: ProjectDetector,
+    R: ToolRegistry + Clone,
+    O: OutputFormatter,
+{
+    /// Create a new watch command handler
+    pub fn new(detector: D, tool_registry: R, output_formatter: O, verbosity: Verbosity) -> Self {
+        Self {
+            check: CheckCommand::new(detector, tool_registry.clone(), output_formatter, verbosity),
+            tool_registry,
+            verbosity,
+        }
+    }
+
+    /// Run a full check, then re-run affected tools whenever files change
+    ///
+    /// Only returns when the watcher stops delivering events; interrupting the
+    /// process is the normal way out.
+    pub async fn execute(
+        &self,
+        args: CheckArgs,
+        paths: Vec<PathBuf>,
+        git_modified_only: bool,
+        config: &SirenConfig,
+    ) -> Result<CheckStatus, SirenError> {
+        let fail_level = parse_fail_level(&config.general.fail_level)?;
+
+        let all_paths = if !args.paths.is_empty() {
+            args.paths.clone()
+        } else {
+            paths.clone()
+        };
+
+        // Watch the same roots a check would lint
+        let mut path_manager = PathManager::new();
+        path_manager.collect_files(&all_paths, git_modified_only)?;
+        let roots: Vec<PathBuf> = path_manager
+            .get_all_files()
+            .iter()
+            .map(|root| absolute(root))
+            .collect();
+        let filter = WatchFilter::new(&roots);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            if let Ok(event) = event {
+                for path in event.paths {
+                    let _ = tx.send(path);
+                }
+            }
+        })
+        .map_err(watch_error)?;
+
+        for root in &roots {
+            let mode = if root.is_dir() {
+                RecursiveMode::Recursive
+            } else {
+                RecursiveMode::NonRecursive
+            };
+            watcher.watch(root, mode).map_err(watch_error)?;
+        }
+
+        self.clear_screen();
+        let mut run = self
+            .check
+            .run(args.clone(), paths.clone(), git_modified_only, config)
+            .await?;
+        let mut status = self.check.print_run(&run, config, fail_level);
+        self.print_waiting();
+
+        while let Some(path) = rx.recv().await {
+            // Collect everything that changes until things settle down
+            let mut changed = BTreeSet::new();
+            changed.insert(path);
+            while let Ok(Some(path)) = tokio::time::timeout(DEBOUNCE, rx.recv()).await {
+                changed.insert(path);
+            }
+
+            let changed: Vec<PathBuf> = changed
+                .into_iter()
+                .filter(|path| filter.is_relevant(path))
+                .collect();
+            let mut tools = affected_tools(&self.tool_registry.get_all_tools(), &changed);
+            if let Some(selected) = &args.tools {
+                tools.retain(|tool| selected.contains(tool));
+            }
+            if tools.is_empty() {
+                continue;
+            }
+
+            self.clear_screen();
+            if self.verbosity >= Verbosity::Normal {
+                println!(
+                    "  {} {} changed, re-running {}\n",
+                    "↻".bright_cyan().bold(),
+                    describe_changes(&changed),
+                    tools.join(", ").bright_magenta()
+                );
+            }
+
+            let mut cycle_args = args.clone();
+            cycle_args.tools = Some(tools.clone());
+            match self
+                .check
+                .run(cycle_args, paths.clone(), git_modified_only, config)
+                .await
+            {
+                Ok(fresh) => {
+                    run = merge_runs(run, fresh, &tools);
+                    status = self.check.print_run(&run, config, fail_level);
+                }
+                Err(e) => println!("{} {}", "✖".red(), e),
+            }
+            self.print_waiting();
+        }
+
+        Ok(status)
+    }
+
+    /// Redraw from the top so each run replaces the last one
+    fn clear_screen(&self) {
+        if self.verbosity >= Verbosity::Normal {
+            print!("\x1B[2J\x1B[H");
+        }
+    }
+
+    fn print_waiting(&self) {
+        if self.verbosity >= Verbosity::Normal {
+            println!(
+                "\n  {} {}",
+                "👁".bright_cyan(),
+                "Watching for changes... (Ctrl+C to stop)".bright_white()
+            );
+        }
+    }
+}
+
+/// Decides which file system events are worth a re-run
+///
+/// Mirrors `collect_files_with_gitignore`: anything matched by a `.gitignore`
+/// under the watched roots is skipped, as is everything inside `.git`. The
+/// `.siren` directory is skipped too, or writing the cache would trigger
+/// another run.
+pub struct WatchFilter {
+    gitignores: Vec<Gitignore>,
+}
+
+impl WatchFilter {
+    /// Load the `.gitignore` files that apply to the given roots
+    pub fn new(roots: &[PathBuf]) -> Self {
+        let mut files = BTreeSet::new();
+
+        for root in roots {
+            // Ignore files in the parents apply to the roots as well
+            for dir in root.ancestors().skip(1) {
+                files.insert(dir.join(".gitignore"));
+                if dir.join(".git").exists() {
+                    break;
+                }
+            }
+
+            if root.is_dir() {
+                let walker = ignore::WalkBuilder::new(root)
+                    .hidden(false)
+                    .filter_entry(|entry| entry.file_name() != ".git")
+                    .build();
+                for entry in walker.filter_map(Result::ok) {
+                    if entry.file_name() == ".gitignore" {
+                        files.insert(entry.into_path());
+                    }
+                }
+            }
+        }
+
+        let gitignores = files
+            .into_iter()
+            .filter(|file| file.is_file())
+            .map(|file| Gitignore::new(file).0)
+            .collect();
+
+        Self { gitignores }
+    }
+
+    /// Whether a change to this path should trigger a re-run
+    pub fn is_relevant(&self, path: &Path) -> bool {
+        let ignored_dir = path.components().any(|component| match component {
+            Component::Normal(name) => IGNORED_DIRS.iter().any(|dir| name == *dir),
+            _ => false,
+        });
+        if ignored_dir {
+            return false;
+        }
+
+        !self.gitignores.iter().any(|gitignore| {
+            path.starts_with(gitignore.path())
+                && gitignore
+                    .matched_path_or_any_parents(path, path.is_dir())
+                    .is_ignore()
+        })
+    }
+}
+
+/// Names of the check tools that can handle any of the changed files
+pub fn affected_tools(tools: &[Arc<dyn LintTool>], changed: &[PathBuf]) -> Vec<String> {
+    tools
+        .iter()
+        .filter(|tool| matches!(tool.tool_type(), ToolType::Linter | ToolType::TypeChecker))
+        .filter(|tool| changed.iter().any(|path| tool.can_handle(path)))
+        .map(|tool| tool.name().to_string())
+        .collect()
+}
+
+/// Replace the results of the re-run tools, keeping everything else
+pub fn merge_runs(previous: CheckRun, fresh: CheckRun, rerun: &[String]) -> CheckRun {
+    let was_rerun = |name: &String| rerun.contains(name);
+
+    let mut results: Vec<_> = previous
+        .results
+        .into_iter()
+        .filter(|result| !was_rerun(&result.tool_name))
+        .chain(fresh.results)
+        .collect();
+    results.sort_by(|a, b| a.tool_name.cmp(&b.tool_name));
+
+    let mut failed_tools: Vec<_> = previous
+        .failed_tools
+        .into_iter()
+        .filter(|name| !was_rerun(name))
+        .chain(fresh.failed_tools)
+        .collect();
+    failed_tools.sort();
+
+    let mut suppressed = previous.suppressed;
+    suppressed.remove_tools(rerun);
+    suppressed.merge(&fresh.suppressed);
+
+    CheckRun {
+        project_info: fresh.project_info,
+        results,
+        failed_tools,
+        suppressed,
+    }
+}
+
+fn describe_changes(changed: &[PathBuf]) -> String {
+    match changed {
+        [path] => crate::output::make_relative_path(path)
+            .display()
+            .to_string(),
+        _ => format!("{} files", changed.len()),
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::env::current_dir()
+        .map(|dir| dir.join(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn watch_error(e: notify::Error) -> SirenError {
+    debug!("File watcher error: {}", e);
+    SirenError::Io(io::Error::other(format!("File watcher error: {}", e)))
+}
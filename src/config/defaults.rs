@@ -1,6 +1,7 @@
-use crate::config::{GeneralConfig, LanguageConfig, SirenConfig, StyleConfig};
-use crate::models::{Language, ToolConfig, ToolType};
+use crate::config::{GeneralConfig, LanguageConfig, SirenConfig, ToolConfig};
+use crate::models::{Language, ProjectInfo};
 use std::collections::HashMap;
+use std::path::Path;
 
 /// Create a default configuration for a new project
 pub fn create_default_config() -> SirenConfig {
@@ -19,93 +20,357 @@ pub fn create_default_config() -> SirenConfig {
     config
         .languages
         .insert(Language::TypeScript, create_typescript_config());
+    config.languages.insert(Language::Css, create_css_config());
+    config.languages.insert(Language::Go, create_go_config());
+    config
+        .languages
+        .insert(Language::Ruby, create_ruby_config());
+    config
+        .languages
+        .insert(Language::Shell, create_shell_config());
+    config.languages.insert(Language::C, create_c_config());
+    config.languages.insert(Language::Cpp, create_cpp_config());
+    config
+        .languages
+        .insert(Language::Java, create_java_config());
+    config
+        .languages
+        .insert(Language::Docker, create_docker_config());
+    config
+        .languages
+        .insert(Language::Makefile, create_makefile_config());
+    config.languages.insert(Language::Php, create_php_config());
+    config
+        .languages
+        .insert(Language::CSharp, create_csharp_config());
+    config
+        .languages
+        .insert(Language::Swift, create_swift_config());
+    config
+        .languages
+        .insert(Language::Markdown, create_markdown_config());
+    config
+        .languages
+        .insert(Language::Yaml, create_yaml_config());
+    config
+        .languages
+        .insert(Language::Toml, create_toml_config());
 
     // Add default tool configs
     add_default_rust_tools(&mut config.tools);
     add_default_python_tools(&mut config.tools);
     add_default_javascript_tools(&mut config.tools);
+    add_default_css_tools(&mut config.tools);
+    add_default_go_tools(&mut config.tools);
+    add_default_ruby_tools(&mut config.tools);
+    add_default_shell_tools(&mut config.tools);
+    add_default_cpp_tools(&mut config.tools);
+    add_default_java_tools(&mut config.tools);
+    add_default_docker_tools(&mut config.tools);
+    add_default_makefile_tools(&mut config.tools);
+    add_default_php_tools(&mut config.tools);
+    add_default_csharp_tools(&mut config.tools);
+    add_default_swift_tools(&mut config.tools);
+    add_default_markdown_tools(&mut config.tools);
+    add_default_yaml_tools(&mut config.tools);
+    add_default_toml_tools(&mut config.tools);
 
     config
 }
 
+/// Create a configuration tailored to a detected project
+///
+/// Starts from [`create_default_config`] and keeps only the language and tool
+/// sections relevant to the detected languages. Tools with an existing config
+/// file in the project get a section pointing at that file. When `team` is set,
+/// the stricter shared profile from [`apply_team_profile`] is applied on top.
+pub fn create_project_config(
+    project_info: &ProjectInfo,
+    project_root: &Path,
+    team: bool,
+) -> SirenConfig {
+    let mut config = create_default_config();
+
+    // Only keep languages that were actually detected
+    config
+        .languages
+        .retain(|language, _| project_info.languages.contains(language));
+
+    // Only keep tools that belong to a detected language
+    let mut relevant_tools = HashMap::new();
+    for language in &project_info.languages {
+        add_default_tools_for_language(*language, &mut relevant_tools);
+    }
+    config
+        .tools
+        .retain(|name, _| relevant_tools.contains_key(name));
+
+    // Point tools at the configuration files we found in the project
+    for detected in &project_info.detected_tools {
+        let config_file = detected
+            .config_path
+            .strip_prefix(project_root)
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|_| detected.config_path.clone());
+
+        config
+            .tools
+            .entry(detected.name.clone())
+            .or_default()
+            .config_file = Some(config_file);
+    }
+
+    if team {
+        apply_team_profile(&mut config);
+    }
+
+    config
+}
+
+/// Tighten a configuration into a stricter profile meant to be shared by a team
+///
+/// Fails on warnings, drops per-language ignore lists so everyone sees the same
+/// issues, and enables stricter modes for tools that support them.
+pub fn apply_team_profile(config: &mut SirenConfig) {
+    config.general = create_ci_config();
+
+    for language_config in config.languages.values_mut() {
+        language_config.ignore_rules = None;
+    }
+
+    if let Some(mypy) = config.tools.get_mut("mypy") {
+        mypy.extra_args
+            .get_or_insert_with(Vec::new)
+            .push("--strict".to_string());
+    }
+
+    if let Some(eslint) = config.tools.get_mut("eslint") {
+        eslint
+            .extra_args
+            .get_or_insert_with(Vec::new)
+            .extend(["--max-warnings".to_string(), "0".to_string()]);
+    }
+}
+
+/// Add the default tools for a single language
+fn add_default_tools_for_language(language: Language, tools: &mut HashMap<String, ToolConfig>) {
+    match language {
+        Language::Rust => add_default_rust_tools(tools),
+        Language::Python => add_default_python_tools(tools),
+        Language::JavaScript | Language::TypeScript => add_default_javascript_tools(tools),
+        Language::Css => add_default_css_tools(tools),
+        Language::Go => add_default_go_tools(tools),
+        Language::Ruby => add_default_ruby_tools(tools),
+        Language::Shell => add_default_shell_tools(tools),
+        Language::C | Language::Cpp => add_default_cpp_tools(tools),
+        Language::Java => add_default_java_tools(tools),
+        Language::Docker => add_default_docker_tools(tools),
+        Language::Makefile => add_default_makefile_tools(tools),
+        Language::Php => add_default_php_tools(tools),
+        Language::CSharp => add_default_csharp_tools(tools),
+        Language::Swift => add_default_swift_tools(tools),
+        Language::Markdown => add_default_markdown_tools(tools),
+        Language::Yaml => add_default_yaml_tools(tools),
+        Language::Toml => add_default_toml_tools(tools),
+        _ => {}
+    }
+}
+
 /// Create default Rust language config
 fn create_rust_config() -> LanguageConfig {
     LanguageConfig {
-        enabled: true,
-        line_length: 100,
-        ignore_rules: vec![],
-        additional_extensions: vec![],
+        line_length: Some(100),
+        ignore_rules: None,
+        enable_rules: None,
     }
 }
 
 /// Create default Python language config
 fn create_python_config() -> LanguageConfig {
     LanguageConfig {
-        enabled: true,
-        line_length: 88,                                            // Black default
-        ignore_rules: vec!["E203".to_string(), "W503".to_string()], // Common ruff/flake8 ignores
-        additional_extensions: vec![],
+        line_length: Some(88), // Black default
+        ignore_rules: Some(vec!["E203".to_string(), "W503".to_string()]), // Common ruff/flake8 ignores
+        enable_rules: None,
     }
 }
 
 /// Create default JavaScript language config
 fn create_javascript_config() -> LanguageConfig {
     LanguageConfig {
-        enabled: true,
-        line_length: 80,
-        ignore_rules: vec![],
-        additional_extensions: vec![],
+        line_length: Some(80),
+        ignore_rules: None,
+        enable_rules: None,
     }
 }
 
 /// Create default TypeScript language config
 fn create_typescript_config() -> LanguageConfig {
     LanguageConfig {
-        enabled: true,
-        line_length: 80,
-        ignore_rules: vec![],
-        additional_extensions: vec![],
+        line_length: Some(80),
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default CSS language config
+fn create_css_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(80),
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default Go language config
+fn create_go_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: None, // gofmt does not wrap lines
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default Ruby language config
+fn create_ruby_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(120), // RuboCop Layout/LineLength default
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default shell language config
+fn create_shell_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: None, // shfmt does not wrap lines
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default C language config
+fn create_c_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(80), // clang-format LLVM style default
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default C++ language config
+fn create_cpp_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(80), // clang-format LLVM style default
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default Java language config
+fn create_java_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(100), // Google Java Style column limit
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default Dockerfile language config
+fn create_docker_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: None, // hadolint has no line length rule
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default Makefile language config
+fn create_makefile_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: None, // checkmake limits target bodies, not lines
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default PHP language config
+fn create_php_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(120), // PSR-12 soft limit
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default C# language config
+fn create_csharp_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: None, // dotnet format follows .editorconfig
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default Swift language config
+fn create_swift_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(100), // swift-format lineLength default
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default Markdown language config
+fn create_markdown_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(80), // markdownlint MD013 default
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default YAML language config
+fn create_yaml_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(80), // yamllint line-length default
+        ignore_rules: None,
+        enable_rules: None,
+    }
+}
+
+/// Create default TOML language config
+fn create_toml_config() -> LanguageConfig {
+    LanguageConfig {
+        line_length: Some(80), // taplo column_width default
+        ignore_rules: None,
+        enable_rules: None,
     }
 }
 
 /// Add default Rust tools
 fn add_default_rust_tools(tools: &mut HashMap<String, ToolConfig>) {
     // rustfmt
-    tools.insert(
-        "rustfmt".to_string(),
-        ToolConfig {
-            enabled: true,
-            extra_args: vec![],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: false,
-        },
-    );
+    tools.insert("rustfmt".to_string(), ToolConfig::default());
 
     // clippy
     tools.insert(
         "clippy".to_string(),
         ToolConfig {
-            enabled: true,
-            extra_args: vec!["--".to_string(), "-D".to_string(), "warnings".to_string()],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: false,
+            extra_args: Some(vec![
+                "--".to_string(),
+                "-D".to_string(),
+                "warnings".to_string(),
+            ]),
+            ..ToolConfig::default()
         },
     );
 
-    // cargo fix
+    // clippy fix
     tools.insert(
-        "cargo-fix".to_string(),
+        "clippy-fix".to_string(),
         ToolConfig {
-            enabled: true,
-            extra_args: vec![],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: true,
+            auto_fix: Some(true),
+            ..ToolConfig::default()
         },
     );
 }
@@ -113,72 +378,190 @@ fn add_default_rust_tools(tools: &mut HashMap<String, ToolConfig>) {
 /// Add default Python tools
 fn add_default_python_tools(tools: &mut HashMap<String, ToolConfig>) {
     // black
+    tools.insert("black".to_string(), ToolConfig::default());
+
+    // ruff
+    tools.insert("ruff".to_string(), ToolConfig::default());
+
+    // pylint
+    tools.insert("pylint".to_string(), ToolConfig::default());
+
+    // mypy
     tools.insert(
-        "black".to_string(),
+        "mypy".to_string(),
         ToolConfig {
-            enabled: true,
-            extra_args: vec![],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: false,
+            extra_args: Some(vec!["--ignore-missing-imports".to_string()]),
+            ..ToolConfig::default()
         },
     );
+}
 
-    // ruff
+/// Add default JavaScript tools
+fn add_default_javascript_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // prettier
+    tools.insert("prettier".to_string(), ToolConfig::default());
+
+    // eslint
+    tools.insert("eslint".to_string(), ToolConfig::default());
+
+    // tsc
+    tools.insert("tsc".to_string(), ToolConfig::default());
+}
+
+/// Add default CSS tools
+fn add_default_css_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // stylelint
+    tools.insert("stylelint".to_string(), ToolConfig::default());
+
+    // stylelint fix
     tools.insert(
-        "ruff".to_string(),
+        "stylelint-fix".to_string(),
         ToolConfig {
-            enabled: true,
-            extra_args: vec![],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: false,
+            auto_fix: Some(true),
+            ..ToolConfig::default()
         },
     );
+}
 
-    // mypy
+/// Add default Go tools
+fn add_default_go_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // gofmt
+    tools.insert("gofmt".to_string(), ToolConfig::default());
+
+    // go vet
+    tools.insert("go-vet".to_string(), ToolConfig::default());
+
+    // golangci-lint
+    tools.insert("golangci-lint".to_string(), ToolConfig::default());
+}
+
+/// Add default Ruby tools
+fn add_default_ruby_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // rubocop
+    tools.insert("rubocop".to_string(), ToolConfig::default());
+
+    // rubocop fix
     tools.insert(
-        "mypy".to_string(),
+        "rubocop-fix".to_string(),
         ToolConfig {
-            enabled: true,
-            extra_args: vec!["--ignore-missing-imports".to_string()],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: false,
+            auto_fix: Some(true),
+            ..ToolConfig::default()
         },
     );
+
+    // sorbet
+    tools.insert("sorbet".to_string(), ToolConfig::default());
 }
 
-/// Add default JavaScript tools
-fn add_default_javascript_tools(tools: &mut HashMap<String, ToolConfig>) {
-    // prettier
+/// Add default shell tools
+fn add_default_shell_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // shellcheck
+    tools.insert("shellcheck".to_string(), ToolConfig::default());
+
+    // shfmt
+    tools.insert("shfmt".to_string(), ToolConfig::default());
+}
+
+/// Add default C/C++ tools
+fn add_default_cpp_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // clang-format
+    tools.insert("clang-format".to_string(), ToolConfig::default());
+
+    // clang-tidy
+    tools.insert("clang-tidy".to_string(), ToolConfig::default());
+
+    // clang-tidy fix
     tools.insert(
-        "prettier".to_string(),
+        "clang-tidy-fix".to_string(),
         ToolConfig {
-            enabled: true,
-            extra_args: vec![],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: false,
+            auto_fix: Some(true),
+            ..ToolConfig::default()
         },
     );
+}
 
-    // eslint
+/// Add default Java tools
+///
+/// PMD is left out: it only runs with a project ruleset, and one is added to
+/// the project config when detected.
+fn add_default_java_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // google-java-format
+    tools.insert("google-java-format".to_string(), ToolConfig::default());
+
+    // checkstyle
+    tools.insert("checkstyle".to_string(), ToolConfig::default());
+}
+
+/// Add default Dockerfile tools
+fn add_default_docker_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // hadolint
+    tools.insert("hadolint".to_string(), ToolConfig::default());
+}
+
+/// Add default Makefile tools
+fn add_default_makefile_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // checkmake
+    tools.insert("checkmake".to_string(), ToolConfig::default());
+}
+
+/// Add default PHP tools
+fn add_default_php_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // php-cs-fixer
+    tools.insert("php-cs-fixer".to_string(), ToolConfig::default());
+
+    // php-cs-fixer fix
     tools.insert(
-        "eslint".to_string(),
+        "php-cs-fixer-fix".to_string(),
         ToolConfig {
-            enabled: true,
-            extra_args: vec![],
-            env_vars: HashMap::new(),
-            executable_path: None,
-            report_level: Some("error".to_string()),
-            auto_fix: false,
+            auto_fix: Some(true),
+            ..ToolConfig::default()
         },
     );
+
+    // php-cs-fixer check
+    tools.insert("php-cs-fixer-check".to_string(), ToolConfig::default());
+
+    // phpstan
+    tools.insert("phpstan".to_string(), ToolConfig::default());
+}
+
+/// Add default C# tools
+fn add_default_csharp_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // dotnet format
+    tools.insert("dotnet-format".to_string(), ToolConfig::default());
+
+    // dotnet format check
+    tools.insert("dotnet-format-check".to_string(), ToolConfig::default());
+}
+
+/// Add default Swift tools
+fn add_default_swift_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // swift-format lint
+    tools.insert("swift-format".to_string(), ToolConfig::default());
+
+    // swiftlint
+    tools.insert("swiftlint".to_string(), ToolConfig::default());
+}
+
+/// Add default Markdown tools
+fn add_default_markdown_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // markdownlint-cli2
+    tools.insert("markdownlint".to_string(), ToolConfig::default());
+}
+
+/// Add default YAML tools
+fn add_default_yaml_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // yamllint
+    tools.insert("yamllint".to_string(), ToolConfig::default());
+}
+
+/// Add default TOML tools
+fn add_default_toml_tools(tools: &mut HashMap<String, ToolConfig>) {
+    // taplo fmt
+    tools.insert("taplo".to_string(), ToolConfig::default());
+
+    // taplo lint
+    tools.insert("taplo-lint".to_string(), ToolConfig::default());
 }
 
 /// Create a strict config for CI environments
@@ -186,8 +569,5 @@ pub fn create_ci_config() -> GeneralConfig {
     GeneralConfig {
         fail_level: "warning".to_string(), // Fail on warnings in CI
         use_relative_paths: true,
-        default_paths: vec![".".to_string()],
-        auto_fix: false,          // Don't auto-fix in CI
-        git_modified_only: false, // Check everything in CI
     }
 }
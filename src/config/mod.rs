@@ -1,12 +1,17 @@
 //! Configuration management for Siren
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 use crate::errors::{ConfigError, SirenError};
 use crate::models::Language;
 
+pub mod defaults;
+
+/// Name of the project configuration file
+pub const CONFIG_FILE_NAME: &str = ".siren.toml";
+
 /// Configuration provider trait
 pub trait ConfigProvider {
     /// Load configuration from the given directory
@@ -131,6 +136,38 @@ pub struct SirenConfig {
     pub output: OutputConfig,
 }
 
+impl SirenConfig {
+    /// Serialize the configuration into a `.siren.toml` document
+    ///
+    /// Sections are written in a stable order so generated files diff cleanly.
+    /// The output section is omitted since its defaults are always applied.
+    pub fn to_toml_string(&self) -> Result<String, SirenError> {
+        /// Borrowed, ordered view of the parts of the config we write out
+        #[derive(Serialize)]
+        struct ConfigFile<'a> {
+            general: &'a GeneralConfig,
+            languages: BTreeMap<String, &'a LanguageConfig>,
+            tools: BTreeMap<&'a str, &'a ToolConfig>,
+        }
+
+        let file = ConfigFile {
+            general: &self.general,
+            languages: self
+                .languages
+                .iter()
+                .map(|(language, config)| (format!("{:?}", language), config))
+                .collect(),
+            tools: self
+                .tools
+                .iter()
+                .map(|(name, config)| (name.as_str(), config))
+                .collect(),
+        };
+
+        toml::to_string_pretty(&file).map_err(|e| ConfigError::SerializeError(e.to_string()).into())
+    }
+}
+
 /// TOML configuration provider
 pub struct TomlConfigProvider;
 
@@ -153,7 +190,7 @@ impl ConfigProvider for TomlConfigProvider {
         let mut current_dir = Some(base_dir);
 
         while let Some(dir) = current_dir {
-            let config_path = dir.join(".siren.toml");
+            let config_path = dir.join(CONFIG_FILE_NAME);
 
             if config_path.exists() {
                 // Found a config file, try to load it
@@ -54,14 +54,16 @@ impl DefaultProjectDetector {
             "jsx" => Some(Language::JavaScript),
             "tsx" => Some(Language::TypeScript),
             "html" | "htm" => Some(Language::Html),
-            "css" => Some(Language::Css),
+            "css" | "scss" | "sass" | "less" => Some(Language::Css),
+            "sh" | "bash" | "ksh" => Some(Language::Shell),
             "go" => Some(Language::Go),
             "rb" => Some(Language::Ruby),
             "java" => Some(Language::Java),
             "php" => Some(Language::Php),
+            "mk" => Some(Language::Makefile),
             "c" => Some(Language::C),
-            "cpp" | "cc" | "cxx" => Some(Language::Cpp),
-            "h" | "hpp" => Some(Language::Cpp),
+            "cpp" | "cc" | "cxx" | "c++" => Some(Language::Cpp),
+            "h" | "hpp" | "hh" | "hxx" => Some(Language::Cpp),
             "cs" => Some(Language::CSharp),
             "swift" => Some(Language::Swift),
             "md" | "markdown" => Some(Language::Markdown),
@@ -72,6 +74,22 @@ impl DefaultProjectDetector {
         }
     }
 
+    /// Detect the language of a file from its name or extension, falling back
+    /// to the shebang line for extensionless scripts
+    ///
+    /// Names are checked first so `Dockerfile.prod` is not mistaken for a
+    /// `.prod` file.
+    pub fn detect_language_from_path(&self, path: &Path) -> Option<Language> {
+        if let Some(language) = crate::utils::filename_language(path) {
+            return Some(language);
+        }
+
+        match path.extension() {
+            Some(ext) => self.detect_language_from_extension(ext.to_string_lossy().as_ref()),
+            None => crate::utils::shebang_language(path),
+        }
+    }
+
     /// Detect framework based on files in the project
     fn detect_frameworks(&self, dir: &Path) -> Vec<Framework> {
         let mut frameworks = Vec::new();
@@ -151,12 +169,8 @@ impl ProjectDetector for DefaultProjectDetector {
                 file_count += 1;
                 collected_files.push(path.clone());
 
-                if let Some(ext) = path.extension() {
-                    if let Some(lang) =
-                        self.detect_language_from_extension(ext.to_string_lossy().as_ref())
-                    {
-                        *languages.entry(lang).or_insert(0) += 1;
-                    }
+                if let Some(lang) = self.detect_language_from_path(path) {
+                    *languages.entry(lang).or_insert(0) += 1;
                 }
             } else if path.is_dir() {
                 // For directories, use the existing collect_files_with_gitignore function
@@ -166,12 +180,8 @@ impl ProjectDetector for DefaultProjectDetector {
                     file_count += 1;
                     collected_files.push(file_path.clone());
 
-                    if let Some(ext) = file_path.extension() {
-                        if let Some(lang) =
-                            self.detect_language_from_extension(ext.to_string_lossy().as_ref())
-                        {
-                            *languages.entry(lang).or_insert(0) += 1;
-                        }
+                    if let Some(lang) = self.detect_language_from_path(file_path) {
+                        *languages.entry(lang).or_insert(0) += 1;
                     }
                 }
 
@@ -279,12 +289,8 @@ impl ProjectDetector for DefaultProjectDetector {
             file_count += 1;
             collected_files.push(file_path.clone());
 
-            if let Some(ext) = file_path.extension() {
-                if let Some(lang) =
-                    self.detect_language_from_extension(ext.to_string_lossy().as_ref())
-                {
-                    *languages.entry(lang).or_insert(0) += 1;
-                }
+            if let Some(lang) = self.detect_language_from_path(file_path) {
+                *languages.entry(lang).or_insert(0) += 1;
             }
         }
 
@@ -302,12 +308,8 @@ impl ProjectDetector for DefaultProjectDetector {
                 file_count += 1;
                 collected_files.push(file_path.clone());
 
-                if let Some(ext) = file_path.extension() {
-                    if let Some(lang) =
-                        self.detect_language_from_extension(ext.to_string_lossy().as_ref())
-                    {
-                        *languages.entry(lang).or_insert(0) += 1;
-                    }
+                if let Some(lang) = self.detect_language_from_path(&file_path) {
+                    *languages.entry(lang).or_insert(0) += 1;
                 }
             }
         }
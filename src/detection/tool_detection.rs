@@ -38,6 +38,37 @@ pub fn detect_tools_in_paths(project_root: &Path, patterns: &[String]) -> Vec<De
         // Check for HTML tools
         detect_html_tools(project_root, &mut tools);
 
+        // Check for Go tools
+        detect_go_tools(project_root, &mut tools);
+
+        // Check for Ruby tools
+        detect_ruby_tools(project_root, &mut tools);
+
+        // Check for shell tools
+        detect_shell_tools(project_root, &mut tools);
+
+        // Check for C/C++ tools
+        detect_cpp_tools(project_root, &mut tools);
+
+        // Check for Java tools
+        detect_java_tools(project_root, &mut tools);
+
+        // Check for Dockerfile and Makefile tools
+        detect_docker_tools(project_root, &mut tools);
+        detect_make_tools(project_root, &mut tools);
+
+        // Check for PHP tools
+        detect_php_tools(project_root, &mut tools);
+
+        // Check for C# and Swift tools
+        detect_csharp_tools(project_root, &mut tools);
+        detect_swift_tools(project_root, &mut tools);
+
+        // Check for Markdown, YAML and TOML tools
+        detect_markdown_tools(project_root, &mut tools);
+        detect_yaml_tools(project_root, &mut tools);
+        detect_toml_tools(project_root, &mut tools);
+
         return tools;
     }
 
@@ -93,6 +124,37 @@ pub fn detect_tools_in_paths(project_root: &Path, patterns: &[String]) -> Vec<De
 
         // Check for HTML tools
         detect_html_tools(&dir, &mut tools);
+
+        // Check for Go tools
+        detect_go_tools(&dir, &mut tools);
+
+        // Check for Ruby tools
+        detect_ruby_tools(&dir, &mut tools);
+
+        // Check for shell tools
+        detect_shell_tools(&dir, &mut tools);
+
+        // Check for C/C++ tools
+        detect_cpp_tools(&dir, &mut tools);
+
+        // Check for Java tools
+        detect_java_tools(&dir, &mut tools);
+
+        // Check for Dockerfile and Makefile tools
+        detect_docker_tools(&dir, &mut tools);
+        detect_make_tools(&dir, &mut tools);
+
+        // Check for PHP tools
+        detect_php_tools(&dir, &mut tools);
+
+        // Check for C# and Swift tools
+        detect_csharp_tools(&dir, &mut tools);
+        detect_swift_tools(&dir, &mut tools);
+
+        // Check for Markdown, YAML and TOML tools
+        detect_markdown_tools(&dir, &mut tools);
+        detect_yaml_tools(&dir, &mut tools);
+        detect_toml_tools(&dir, &mut tools);
     }
 
     // Deduplicate tools (we might have found the same tool config multiple times)
@@ -375,7 +437,7 @@ fn detect_js_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
 /// Detect CSS linting/formatting tools
 fn detect_css_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
     // Check for .stylelintrc (various formats)
-    for ext in &[".json", ".yaml", ".yml", ".js", ""] {
+    for ext in &[".json", ".yaml", ".yml", ".js", ".cjs", ".mjs", ""] {
         let stylelint_config = dir.join(format!(".stylelintrc{}", ext));
         if stylelint_config.exists() {
             debug!("Detected stylelint configuration");
@@ -389,16 +451,22 @@ fn detect_css_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
         }
     }
 
-    // Check for stylelint.config.js
-    let alt_stylelint_config = dir.join("stylelint.config.js");
-    if alt_stylelint_config.exists() {
-        debug!("Detected stylelint configuration (stylelint.config.js)");
-        tools.push(DetectedTool {
-            name: "stylelint".to_string(),
-            config_path: alt_stylelint_config,
-            tool_type: ToolType::Linter,
-            languages: vec![Language::Css],
-        });
+    // Check for stylelint.config.js (and its module variants)
+    for ext in &["js", "cjs", "mjs"] {
+        let alt_stylelint_config = dir.join(format!("stylelint.config.{}", ext));
+        if alt_stylelint_config.exists() {
+            debug!(
+                "Detected stylelint configuration (stylelint.config.{})",
+                ext
+            );
+            tools.push(DetectedTool {
+                name: "stylelint".to_string(),
+                config_path: alt_stylelint_config,
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Css],
+            });
+            break;
+        }
     }
 }
 
@@ -428,3 +496,341 @@ fn detect_html_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
         });
     }
 }
+
+/// Detect Go linting/formatting tools
+fn detect_go_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // go.mod marks a module rather than configuring go vet, which has no
+    // config file of its own
+    // Check for .golangci.yml (various formats)
+    for ext in &["yml", "yaml", "toml", "json"] {
+        let golangci_config = dir.join(format!(".golangci.{}", ext));
+        if golangci_config.exists() {
+            debug!("Detected golangci-lint configuration");
+            tools.push(DetectedTool {
+                name: "golangci-lint".to_string(),
+                config_path: golangci_config,
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Go],
+            });
+            break; // golangci-lint only reads one config file
+        }
+    }
+}
+
+/// Detect Ruby linting/type checking tools
+fn detect_ruby_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for .rubocop.yml
+    let rubocop_config = dir.join(".rubocop.yml");
+    if rubocop_config.exists() {
+        debug!("Detected RuboCop configuration");
+        tools.push(DetectedTool {
+            name: "rubocop".to_string(),
+            config_path: rubocop_config,
+            tool_type: ToolType::Linter,
+            languages: vec![Language::Ruby],
+        });
+    }
+
+    // Check for sorbet/config, created by `srb init`
+    let sorbet_config = dir.join("sorbet").join("config");
+    if sorbet_config.exists() {
+        debug!("Detected Sorbet configuration");
+        tools.push(DetectedTool {
+            name: "sorbet".to_string(),
+            config_path: sorbet_config,
+            tool_type: ToolType::TypeChecker,
+            languages: vec![Language::Ruby],
+        });
+    }
+}
+
+/// Detect Markdown linting tools
+fn detect_markdown_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for .markdownlint.* and .markdownlint-cli2.* (various formats)
+    let candidates = [
+        ".markdownlint-cli2.jsonc",
+        ".markdownlint-cli2.yaml",
+        ".markdownlint-cli2.cjs",
+        ".markdownlint-cli2.mjs",
+        ".markdownlint.jsonc",
+        ".markdownlint.json",
+        ".markdownlint.yaml",
+        ".markdownlint.yml",
+        ".markdownlint.cjs",
+        ".markdownlint.mjs",
+    ];
+    if let Some(markdownlint_config) = candidates
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+    {
+        debug!("Detected markdownlint configuration");
+        tools.push(DetectedTool {
+            name: "markdownlint".to_string(),
+            config_path: markdownlint_config,
+            tool_type: ToolType::Linter,
+            languages: vec![Language::Markdown],
+        });
+    }
+}
+
+/// Detect YAML linting tools
+fn detect_yaml_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for .yamllint (various formats)
+    for name in &[".yamllint", ".yamllint.yaml", ".yamllint.yml"] {
+        let yamllint_config = dir.join(name);
+        if yamllint_config.exists() {
+            debug!("Detected yamllint configuration");
+            tools.push(DetectedTool {
+                name: "yamllint".to_string(),
+                config_path: yamllint_config,
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Yaml],
+            });
+            break; // yamllint only reads one config file
+        }
+    }
+}
+
+/// Detect TOML formatting/linting tools
+fn detect_toml_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for taplo.toml (or its hidden variant)
+    for name in &["taplo.toml", ".taplo.toml"] {
+        let taplo_config = dir.join(name);
+        if taplo_config.exists() {
+            debug!("Detected taplo configuration");
+            tools.push(DetectedTool {
+                name: "taplo".to_string(),
+                config_path: taplo_config,
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Toml],
+            });
+            break;
+        }
+    }
+}
+
+/// Detect shell script linting/formatting tools
+fn detect_shell_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for .shellcheckrc
+    let shellcheck_config = dir.join(".shellcheckrc");
+    if shellcheck_config.exists() {
+        debug!("Detected shellcheck configuration");
+        tools.push(DetectedTool {
+            name: "shellcheck".to_string(),
+            config_path: shellcheck_config,
+            tool_type: ToolType::Linter,
+            languages: vec![Language::Shell],
+        });
+    }
+
+    // Check for an .editorconfig with shell settings, which shfmt reads
+    let editorconfig = dir.join(".editorconfig");
+    if std::fs::read_to_string(&editorconfig).is_ok_and(|content| {
+        content.contains("*.sh") || content.contains("*.bash") || content.contains("shell_variant")
+    }) {
+        debug!("Detected shfmt configuration (.editorconfig)");
+        tools.push(DetectedTool {
+            name: "shfmt".to_string(),
+            config_path: editorconfig,
+            tool_type: ToolType::Formatter,
+            languages: vec![Language::Shell],
+        });
+    }
+}
+
+/// Detect C/C++ linting/formatting tools
+fn detect_cpp_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for .clang-format
+    let clang_format_config = dir.join(".clang-format");
+    if clang_format_config.exists() {
+        debug!("Detected clang-format configuration");
+        tools.push(DetectedTool {
+            name: "clang-format".to_string(),
+            config_path: clang_format_config,
+            tool_type: ToolType::Formatter,
+            languages: vec![Language::C, Language::Cpp],
+        });
+    }
+
+    // Check for .clang-tidy; the compilation database clang-tidy also needs
+    // is looked up per source file when it runs, not configured
+    let clang_tidy_config = dir.join(".clang-tidy");
+    if clang_tidy_config.exists() {
+        debug!("Detected clang-tidy configuration");
+        tools.push(DetectedTool {
+            name: "clang-tidy".to_string(),
+            config_path: clang_tidy_config,
+            tool_type: ToolType::Linter,
+            languages: vec![Language::C, Language::Cpp],
+        });
+    }
+}
+
+/// Detect Java linting/formatting tools
+fn detect_java_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for a Checkstyle configuration
+    for name in &["checkstyle.xml", "config/checkstyle/checkstyle.xml"] {
+        let checkstyle_config = dir.join(name);
+        if checkstyle_config.exists() {
+            debug!("Detected Checkstyle configuration");
+            tools.push(DetectedTool {
+                name: "checkstyle".to_string(),
+                config_path: checkstyle_config,
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Java],
+            });
+            break;
+        }
+    }
+
+    // Check for a PMD ruleset
+    for name in &[
+        "pmd.xml",
+        "pmd-ruleset.xml",
+        "config/pmd/pmd.xml",
+        "config/pmd/ruleset.xml",
+    ] {
+        let pmd_ruleset = dir.join(name);
+        if pmd_ruleset.exists() {
+            debug!("Detected PMD ruleset");
+            tools.push(DetectedTool {
+                name: "pmd".to_string(),
+                config_path: pmd_ruleset,
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Java],
+            });
+            break;
+        }
+    }
+
+    // Check for google-java-format wired into the build (Spotless or a plugin)
+    for name in &["build.gradle", "build.gradle.kts", "pom.xml"] {
+        let build_file = dir.join(name);
+        if std::fs::read_to_string(&build_file).is_ok_and(|content| {
+            content.contains("googleJavaFormat") || content.contains("google-java-format")
+        }) {
+            debug!("Detected google-java-format in {}", name);
+            tools.push(DetectedTool {
+                name: "google-java-format".to_string(),
+                config_path: build_file,
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Java],
+            });
+            break;
+        }
+    }
+}
+
+/// Detect Dockerfile linting tools
+fn detect_docker_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for .hadolint.yaml (or .yml)
+    for name in &[".hadolint.yaml", ".hadolint.yml"] {
+        let hadolint_config = dir.join(name);
+        if hadolint_config.exists() {
+            debug!("Detected hadolint configuration");
+            tools.push(DetectedTool {
+                name: "hadolint".to_string(),
+                config_path: hadolint_config,
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Docker],
+            });
+            break;
+        }
+    }
+}
+
+/// Detect Makefile linting tools
+fn detect_make_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for checkmake.ini
+    let checkmake_config = dir.join("checkmake.ini");
+    if checkmake_config.exists() {
+        debug!("Detected checkmake configuration");
+        tools.push(DetectedTool {
+            name: "checkmake".to_string(),
+            config_path: checkmake_config,
+            tool_type: ToolType::Linter,
+            languages: vec![Language::Makefile],
+        });
+    }
+}
+
+/// Detect PHP linting/formatting tools
+fn detect_php_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for a PHP-CS-Fixer config (or its .dist variant)
+    for name in &[".php-cs-fixer.php", ".php-cs-fixer.dist.php"] {
+        let php_cs_fixer_config = dir.join(name);
+        if php_cs_fixer_config.exists() {
+            debug!("Detected PHP-CS-Fixer configuration");
+            tools.push(DetectedTool {
+                name: "php-cs-fixer".to_string(),
+                config_path: php_cs_fixer_config,
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Php],
+            });
+            break;
+        }
+    }
+
+    // Check for a PHPStan config
+    for name in &["phpstan.neon", "phpstan.neon.dist", "phpstan.dist.neon"] {
+        let phpstan_config = dir.join(name);
+        if phpstan_config.exists() {
+            debug!("Detected PHPStan configuration");
+            tools.push(DetectedTool {
+                name: "phpstan".to_string(),
+                config_path: phpstan_config,
+                tool_type: ToolType::TypeChecker,
+                languages: vec![Language::Php],
+            });
+            break;
+        }
+    }
+}
+
+/// Detect C# formatting tools
+fn detect_csharp_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for an .editorconfig with C# settings, which dotnet format reads
+    let editorconfig = dir.join(".editorconfig");
+    if std::fs::read_to_string(&editorconfig).is_ok_and(|content| {
+        content.contains("*.cs") || content.contains("csharp_") || content.contains("dotnet_")
+    }) {
+        debug!("Detected dotnet format configuration (.editorconfig)");
+        tools.push(DetectedTool {
+            name: "dotnet-format".to_string(),
+            config_path: editorconfig,
+            tool_type: ToolType::Formatter,
+            languages: vec![Language::CSharp],
+        });
+    }
+}
+
+/// Detect Swift linting tools
+fn detect_swift_tools(dir: &Path, tools: &mut Vec<DetectedTool>) {
+    // Check for .swift-format
+    let swift_format_config = dir.join(".swift-format");
+    if swift_format_config.exists() {
+        debug!("Detected swift-format configuration");
+        tools.push(DetectedTool {
+            name: "swift-format".to_string(),
+            config_path: swift_format_config,
+            tool_type: ToolType::Linter,
+            languages: vec![Language::Swift],
+        });
+    }
+
+    // Check for .swiftlint.yml
+    for name in &[".swiftlint.yml", ".swiftlint.yaml"] {
+        let swiftlint_config = dir.join(name);
+        if swiftlint_config.exists() {
+            debug!("Detected SwiftLint configuration");
+            tools.push(DetectedTool {
+                name: "swiftlint".to_string(),
+                config_path: swiftlint_config,
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Swift],
+            });
+            break;
+        }
+    }
+}
@@ -20,6 +20,43 @@ pub enum SirenError {
     /// I/O errors
     #[error("I/O error: {0}")]
     Io(#[from] io::Error),
+
+    /// Invalid command line argument
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+impl SirenError {
+    /// Process exit code for this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SirenError::Config(_) | SirenError::InvalidArgument(_) => exit_code::CONFIG_ERROR,
+            SirenError::Detection(DetectionError::InvalidDirectory(_)) => exit_code::CONFIG_ERROR,
+            SirenError::Tool(_) => exit_code::TOOL_FAILED,
+            SirenError::Detection(_) | SirenError::Io(_) => exit_code::INTERNAL_ERROR,
+        }
+    }
+}
+
+/// Process exit codes
+///
+/// Command line usage errors reported by the argument parser also exit with
+/// [`CONFIG_ERROR`](exit_code::CONFIG_ERROR).
+pub mod exit_code {
+    /// No issues at or above the fail level
+    pub const SUCCESS: i32 = 0;
+
+    /// Issues at or above the fail level were found
+    pub const ISSUES_FOUND: i32 = 1;
+
+    /// Invalid configuration, arguments or paths
+    pub const CONFIG_ERROR: i32 = 2;
+
+    /// A tool failed to execute
+    pub const TOOL_FAILED: i32 = 3;
+
+    /// Any other error (I/O, project detection)
+    pub const INTERNAL_ERROR: i32 = 4;
 }
 
 /// Configuration related errors
@@ -33,6 +70,22 @@ pub enum ConfigError {
     #[error("Failed to parse config: {0}")]
     ParseError(String),
 
+    /// Error serializing configuration
+    #[error("Failed to serialize config: {0}")]
+    SerializeError(String),
+
+    /// Invalid value for a configuration key
+    #[error("Invalid value '{value}' for {key}: {message}")]
+    InvalidValue {
+        key: String,
+        value: String,
+        message: String,
+    },
+
+    /// Configuration file already exists
+    #[error("Configuration file already exists at {0} (use --force to overwrite)")]
+    AlreadyExists(PathBuf),
+
     /// I/O error
     #[error("I/O error: {0}")]
     Io(#[from] io::Error),
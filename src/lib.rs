@@ -4,6 +4,8 @@
 //! maintaining code quality a delightful experience.
 
 pub mod app;
+pub mod baseline;
+pub mod cache;
 pub mod cli;
 pub mod commands;
 pub mod config;
@@ -12,5 +14,7 @@ pub mod errors;
 pub mod models;
 pub mod output;
 pub mod runner;
+pub mod suggestions;
+pub mod suppression;
 pub mod tools;
 pub mod utils;
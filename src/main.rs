@@ -1,22 +1,12 @@
-mod app;
-mod cli;
-mod commands;
-mod config;
-mod detection;
-mod errors;
-mod models;
-mod output;
-mod runner;
-mod tools;
-mod utils;
-
 use clap::Parser;
-use cli::{Cli, Commands, FixArgs, FormatArgs, Verbosity};
 use colored::Colorize;
-use errors::{DetectionError, SirenError, ToolError};
 use log::{debug, info, LevelFilter};
+use siren::cli::{Cli, Commands, FixArgs, FormatArgs, Verbosity};
+use siren::errors::{exit_code, DetectionError, SirenError, ToolError};
+use siren::output::OutputFormatter;
+use siren::tools::ToolRegistry;
+use siren::{app, cli, config, detection, models, output, tools, utils};
 use std::path::PathBuf;
-use tools::ToolRegistry;
 
 #[tokio::main]
 async fn main() -> Result<(), SirenError> {
@@ -27,8 +17,32 @@ async fn main() -> Result<(), SirenError> {
     // Parse command line arguments
     let cli = Cli::parse();
 
+    // Pick the output formatter from the requested format
+    let mut output_format = match &cli.command {
+        Some(Commands::Check(args)) => args.format.as_str(),
+        _ => "pretty",
+    };
+
+    // In CI on GitHub Actions, annotate the pull request by default
+    if cli.ci && output_format == "pretty" && output::GithubFormatter::is_github_actions() {
+        output_format = "github";
+    }
+    let output_formatter = match output::create_formatter(output_format) {
+        Ok(formatter) => formatter,
+        Err(e) => {
+            print_friendly_error(&e, Verbosity::Normal);
+            std::process::exit(e.exit_code());
+        }
+    };
+
+    // Machine-readable output on stdout must not be mixed with anything else
+    let machine_output = match &cli.command {
+        Some(Commands::Report(args)) => args.output.is_none(),
+        _ => output_formatter.is_machine_readable(),
+    };
+
     // Convert verbosity flag
-    let verbosity = if cli.quiet {
+    let verbosity = if cli.quiet || machine_output {
         Verbosity::Quiet
     } else {
         Verbosity::from(cli.verbose)
@@ -65,8 +79,6 @@ async fn main() -> Result<(), SirenError> {
         }
     }
 
-    let output_formatter = output::PrettyFormatter::new();
-
     // Create the Siren app
     let app = app::SirenApp::new(
         detector,
@@ -74,7 +86,8 @@ async fn main() -> Result<(), SirenError> {
         tool_registry.clone(),
         output_formatter,
     )
-    .with_verbosity(verbosity);
+    .with_verbosity(verbosity)
+    .with_fail_level(cli.fail_level.clone());
 
     // Get the base directory (current dir or first arg)
     let base_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
@@ -97,6 +110,13 @@ async fn main() -> Result<(), SirenError> {
         tool_types: None,
         format: "pretty".to_string(),
         auto_fix: false,
+        no_cache: false,
+        watch: false,
+        write_baseline: false,
+        no_baseline: false,
+        diff_base: None,
+        changed_lines: false,
+        staged: false,
         paths: Vec::new(),
     })) {
         Commands::Check(mut args) => {
@@ -109,9 +129,16 @@ async fn main() -> Result<(), SirenError> {
                 // Tools already logged at debug level above, no need to duplicate here
             }
 
-            if let Err(e) = app.check(args, expanded_paths, cli.git_modified).await {
-                print_friendly_error(&e, verbosity);
-                std::process::exit(1);
+            match app.check(args, expanded_paths, cli.git_modified).await {
+                Ok(status) => {
+                    if status.exit_code() != exit_code::SUCCESS {
+                        std::process::exit(status.exit_code());
+                    }
+                }
+                Err(e) => {
+                    print_friendly_error(&e, verbosity);
+                    std::process::exit(e.exit_code());
+                }
             }
         }
         Commands::Format(mut format_args) => {
@@ -131,7 +158,7 @@ async fn main() -> Result<(), SirenError> {
                 .await
             {
                 print_friendly_error(&e, verbosity);
-                std::process::exit(1);
+                std::process::exit(e.exit_code());
             }
         }
         Commands::Fix(mut fix_args) => {
@@ -149,7 +176,7 @@ async fn main() -> Result<(), SirenError> {
 
             if let Err(e) = app.fix(args_copy, expanded_paths, cli.git_modified).await {
                 print_friendly_error(&e, verbosity);
-                std::process::exit(1);
+                std::process::exit(e.exit_code());
             }
         }
         Commands::FormatAndFix(mut format_and_fix_args) => {
@@ -170,7 +197,7 @@ async fn main() -> Result<(), SirenError> {
                 .await
             {
                 print_friendly_error(&e, verbosity);
-                std::process::exit(1);
+                std::process::exit(e.exit_code());
             }
 
             // Then run fix
@@ -184,7 +211,7 @@ async fn main() -> Result<(), SirenError> {
 
             if let Err(e) = app.fix(fix_args, expanded_paths, cli.git_modified).await {
                 print_friendly_error(&e, verbosity);
-                std::process::exit(1);
+                std::process::exit(e.exit_code());
             }
         }
         Commands::Detect(mut detect_args) => {
@@ -194,12 +221,14 @@ async fn main() -> Result<(), SirenError> {
 
             if let Err(e) = app.detect(detect_args, expanded_paths) {
                 print_friendly_error(&e, verbosity);
-                std::process::exit(1);
+                std::process::exit(e.exit_code());
             }
         }
         Commands::Init(args) => {
-            println!("🚀 Init command with team={}", args.team);
-            // TODO: Implement init
+            if let Err(e) = app.init(args, expanded_paths) {
+                print_friendly_error(&e, verbosity);
+                std::process::exit(e.exit_code());
+            }
         }
         Commands::ListTools(args) => {
             // Use the app to get the filtered tools
@@ -210,8 +239,21 @@ async fn main() -> Result<(), SirenError> {
                     "python" => Some(models::Language::Python),
                     "javascript" => Some(models::Language::JavaScript),
                     "typescript" => Some(models::Language::TypeScript),
+                    "css" => Some(models::Language::Css),
+                    "go" => Some(models::Language::Go),
+                    "ruby" => Some(models::Language::Ruby),
+                    "shell" => Some(models::Language::Shell),
+                    "c" => Some(models::Language::C),
+                    "cpp" | "c++" => Some(models::Language::Cpp),
+                    "java" => Some(models::Language::Java),
+                    "docker" | "dockerfile" => Some(models::Language::Docker),
+                    "make" | "makefile" => Some(models::Language::Makefile),
+                    "php" => Some(models::Language::Php),
+                    "csharp" | "c#" => Some(models::Language::CSharp),
+                    "swift" => Some(models::Language::Swift),
                     "markdown" => Some(models::Language::Markdown),
                     "toml" => Some(models::Language::Toml),
+                    "yaml" => Some(models::Language::Yaml),
                     _ => None,
                 };
 
@@ -443,16 +485,29 @@ async fn main() -> Result<(), SirenError> {
                 }
             }
         }
-        Commands::Report(args) => {
-            println!("📊 Report command with format={}", args.format);
-            // TODO: Implement report
+        Commands::Report(mut args) => {
+            // Also expand any glob patterns in command-specific paths
+            args.paths = utils::expand_glob_patterns(&base_dir, &args.paths);
+
+            if let Err(e) = app.report(args, expanded_paths, cli.git_modified).await {
+                print_friendly_error(&e, verbosity);
+                std::process::exit(e.exit_code());
+            }
         }
-        Commands::Suggest(args) => {
-            println!(
-                "💫 Suggest command with max_suggestions={}",
-                args.max_suggestions
-            );
-            // TODO: Implement suggest
+        Commands::Suggest(mut args) => {
+            // Also expand any glob patterns in command-specific paths
+            args.paths = utils::expand_glob_patterns(&base_dir, &args.paths);
+
+            if let Err(e) = app.suggest(args, expanded_paths, cli.git_modified).await {
+                print_friendly_error(&e, verbosity);
+                std::process::exit(e.exit_code());
+            }
+        }
+        Commands::Hook(args) => {
+            if let Err(e) = app.hook(args) {
+                print_friendly_error(&e, verbosity);
+                std::process::exit(e.exit_code());
+            }
         }
     }
 
@@ -606,5 +661,8 @@ fn print_friendly_error(error: &SirenError, verbosity: Verbosity) {
             // that work well with lifetimes in this context
             output::terminal::error_panel(title, &message, None);
         }
+        SirenError::InvalidArgument(message) => {
+            output::terminal::error_panel("Invalid Argument", message, None);
+        }
     }
 }
@@ -52,6 +52,9 @@ pub enum Language {
     /// Makefile
     Makefile,
 
+    /// Shell scripts (sh, bash, ksh)
+    Shell,
+
     /// Java programming language
     Java,
 
@@ -121,6 +121,23 @@ impl std::fmt::Display for IssueSeverity {
     }
 }
 
+impl std::str::FromStr for IssueSeverity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(IssueSeverity::Error),
+            "warning" => Ok(IssueSeverity::Warning),
+            "info" => Ok(IssueSeverity::Info),
+            "style" => Ok(IssueSeverity::Style),
+            other => Err(format!(
+                "Unknown severity '{}' (expected error, warning, info or style)",
+                other
+            )),
+        }
+    }
+}
+
 /// Tool information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolInfo {
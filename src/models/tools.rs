@@ -47,6 +47,10 @@ pub struct ToolConfig {
     #[serde(default)]
     pub auto_fix: bool,
 
+    /// Whether fixes that may change behaviour are allowed (`fix --unsafe-fixes`)
+    #[serde(default)]
+    pub unsafe_fixes: bool,
+
     /// Whether to run in check mode (don't modify files)
     #[serde(default)]
     pub check: bool,
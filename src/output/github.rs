@@ -0,0 +1,184 @@
+//! GitHub Actions workflow-command annotations
+
+use std::collections::HashSet;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use super::report::SeverityCounts;
+use super::{make_relative_path, OutputFormatter};
+use crate::config::OutputConfig;
+use crate::models::{IssueSeverity, LintIssue, LintResult, ProjectInfo};
+use crate::suppression::SuppressedCounts;
+
+/// Environment variable GitHub Actions sets when running a workflow
+pub const GITHUB_ACTIONS_ENV: &str = "GITHUB_ACTIONS";
+
+/// Environment variable pointing at the job's Markdown step summary file
+pub const GITHUB_STEP_SUMMARY_ENV: &str = "GITHUB_STEP_SUMMARY";
+
+/// Formatter that emits GitHub Actions annotations
+///
+/// Every issue becomes a `::error`, `::warning` or `::notice` workflow command
+/// so that it shows up inline on pull requests. The summary is Markdown and
+/// doubles as the job summary, see [`append_step_summary`].
+#[derive(Clone, Default)]
+pub struct GithubFormatter;
+
+impl GithubFormatter {
+    /// Create a new GithubFormatter
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether we are running inside a GitHub Actions workflow
+    pub fn is_github_actions() -> bool {
+        env::var_os(GITHUB_ACTIONS_ENV).is_some()
+    }
+
+    /// Format a single issue as a workflow command
+    pub fn format_annotation(&self, tool_name: &str, issue: &LintIssue) -> String {
+        let mut properties = Vec::new();
+
+        if let Some(file) = &issue.file {
+            let path = make_relative_path(file)
+                .to_string_lossy()
+                .replace('\\', "/");
+            properties.push(format!("file={}", escape_property(&path)));
+
+            if let Some(line) = issue.line {
+                properties.push(format!("line={}", line));
+            }
+            if let Some(column) = issue.column {
+                properties.push(format!("col={}", column));
+            }
+        }
+
+        let title = match &issue.code {
+            Some(code) => format!("{}/{}", tool_name, code),
+            None => tool_name.to_string(),
+        };
+        properties.push(format!("title={}", escape_property(&title)));
+
+        format!(
+            "::{} {}::{}",
+            command_for(issue.severity),
+            properties.join(","),
+            escape_data(&issue.message)
+        )
+    }
+
+    /// Build the Markdown step summary
+    pub fn step_summary(&self, results: &[LintResult]) -> String {
+        let mut totals = SeverityCounts::default();
+        let mut files = HashSet::new();
+        let mut tools = Vec::new();
+
+        for result in results {
+            let mut counts = SeverityCounts::default();
+            for issue in &result.issues {
+                counts.add(issue.severity);
+                totals.add(issue.severity);
+                if let Some(file) = &issue.file {
+                    files.insert(file);
+                }
+            }
+            tools.push((result.tool_name.as_str(), counts));
+        }
+
+        let mut out = String::from("## 🧜‍♀️ Siren\n\n");
+
+        if totals.total() == 0 {
+            out.push_str("✅ No issues found.\n");
+            return out;
+        }
+
+        out.push_str(&format!(
+            "**{}** issues in **{}** files: {} errors, {} warnings, {} info, {} style.\n\n",
+            totals.total(),
+            files.len(),
+            totals.errors,
+            totals.warnings,
+            totals.info,
+            totals.style
+        ));
+
+        out.push_str("| Tool | Errors | Warnings | Info | Style |\n");
+        out.push_str("|---|---:|---:|---:|---:|\n");
+        for (name, counts) in tools {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                name, counts.errors, counts.warnings, counts.info, counts.style
+            ));
+        }
+
+        out
+    }
+}
+
+impl OutputFormatter for GithubFormatter {
+    fn format_detection(&self, _project_info: &ProjectInfo) -> String {
+        String::new()
+    }
+
+    fn format_results(&self, results: &[LintResult], _config: &OutputConfig) -> String {
+        results
+            .iter()
+            .flat_map(|result| {
+                result
+                    .issues
+                    .iter()
+                    .map(|issue| self.format_annotation(&result.tool_name, issue))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn format_summary(&self, results: &[LintResult], suppressed: &SuppressedCounts) -> String {
+        let mut summary = self.step_summary(results);
+        if !suppressed.is_empty() {
+            summary.push_str(&format!(
+                "\n🔇 {} issues suppressed by inline `siren: ignore` comments.\n",
+                suppressed.total()
+            ));
+        }
+
+        summary
+    }
+
+    fn job_summary(&self, results: &[LintResult], suppressed: &SuppressedCounts) -> Option<String> {
+        Some(self.format_summary(results, suppressed))
+    }
+}
+
+/// Append Markdown to the file `$GITHUB_STEP_SUMMARY` points at, if any
+pub fn append_step_summary(summary: &str) {
+    // Failing to write the step summary must not fail the check itself
+    if let Some(path) = env::var_os(GITHUB_STEP_SUMMARY_ENV) {
+        if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+            let _ = writeln!(file, "{}", summary);
+        }
+    }
+}
+
+/// Workflow command for a severity
+fn command_for(severity: IssueSeverity) -> &'static str {
+    match severity {
+        IssueSeverity::Error => "error",
+        IssueSeverity::Warning => "warning",
+        IssueSeverity::Info | IssueSeverity::Style => "notice",
+    }
+}
+
+/// Escape a workflow command message
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
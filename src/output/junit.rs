@@ -0,0 +1,187 @@
+//! JUnit XML output for CI test report viewers
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::{make_relative_path, OutputFormatter};
+use crate::config::OutputConfig;
+use crate::models::{LintIssue, LintResult, ProjectInfo};
+use crate::suppression::SuppressedCounts;
+
+/// Formatter that emits a JUnit XML report
+///
+/// Each tool becomes a `<testsuite>` and each file a `<testcase>`; files with
+/// issues fail with the issue list as the failure body. Tools only report the
+/// files they found issues in, so a clean tool gets a single passing testcase
+/// named after itself.
+#[derive(Clone, Default)]
+pub struct JunitFormatter;
+
+impl JunitFormatter {
+    /// Create a new JunitFormatter
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputFormatter for JunitFormatter {
+    fn format_detection(&self, _project_info: &ProjectInfo) -> String {
+        String::new()
+    }
+
+    fn format_results(&self, results: &[LintResult], _config: &OutputConfig) -> String {
+        let suites: Vec<Suite> = results.iter().map(Suite::new).collect();
+
+        let tests: usize = suites.iter().map(|s| s.cases.len()).sum();
+        let failures: usize = suites.iter().map(|s| s.failures()).sum();
+        let time: Duration = results.iter().map(|r| r.execution_time).sum();
+
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str(&format!(
+            "<testsuites name=\"siren\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"{:.3}\">\n",
+            tests,
+            failures,
+            time.as_secs_f64()
+        ));
+
+        for suite in &suites {
+            suite.write(&mut out);
+        }
+
+        out.push_str("</testsuites>");
+        out
+    }
+
+    fn format_summary(&self, _results: &[LintResult], _suppressed: &SuppressedCounts) -> String {
+        String::new()
+    }
+
+    fn is_machine_readable(&self) -> bool {
+        true
+    }
+}
+
+/// A tool's results, grouped by file
+struct Suite<'a> {
+    name: &'a str,
+    time: Duration,
+    cases: Vec<(String, Vec<&'a LintIssue>)>,
+}
+
+impl<'a> Suite<'a> {
+    fn new(result: &'a LintResult) -> Self {
+        let mut by_file: BTreeMap<String, Vec<&LintIssue>> = BTreeMap::new();
+        for issue in &result.issues {
+            let name = match &issue.file {
+                Some(file) => make_relative_path(file)
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+                None => result.tool_name.clone(),
+            };
+            by_file.entry(name).or_default().push(issue);
+        }
+
+        if by_file.is_empty() {
+            by_file.insert(result.tool_name.clone(), Vec::new());
+        }
+
+        Self {
+            name: &result.tool_name,
+            time: result.execution_time,
+            cases: by_file.into_iter().collect(),
+        }
+    }
+
+    fn failures(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|(_, issues)| !issues.is_empty())
+            .count()
+    }
+
+    fn write(&self, out: &mut String) {
+        // Tools run once over all files, so spread their time evenly
+        let case_time = self.time.as_secs_f64() / self.cases.len() as f64;
+
+        out.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{:.3}\">\n",
+            escape_xml(self.name),
+            self.cases.len(),
+            self.failures(),
+            self.time.as_secs_f64()
+        ));
+
+        for (file, issues) in &self.cases {
+            out.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\"",
+                escape_xml(self.name),
+                escape_xml(file),
+                case_time
+            ));
+
+            if issues.is_empty() {
+                out.push_str("/>\n");
+                continue;
+            }
+
+            let worst = issues
+                .iter()
+                .map(|i| i.severity)
+                .min()
+                .map(|s| s.to_string().to_lowercase())
+                .unwrap_or_default();
+            out.push_str(">\n");
+            out.push_str(&format!(
+                "      <failure message=\"{} issue{} found\" type=\"{}\">",
+                issues.len(),
+                if issues.len() == 1 { "" } else { "s" },
+                worst
+            ));
+
+            let body = issues
+                .iter()
+                .map(|issue| format_issue(issue))
+                .collect::<Vec<_>>()
+                .join("\n");
+            out.push_str(&escape_xml(&body));
+
+            out.push_str("</failure>\n    </testcase>\n");
+        }
+
+        out.push_str("  </testsuite>\n");
+    }
+}
+
+/// One line of a failure body
+fn format_issue(issue: &LintIssue) -> String {
+    let location = match (issue.line, issue.column) {
+        (Some(line), Some(column)) => format!("{}:{} ", line, column),
+        (Some(line), None) => format!("{} ", line),
+        _ => String::new(),
+    };
+    let code = issue
+        .code
+        .as_deref()
+        .map(|c| format!("{}: ", c))
+        .unwrap_or_default();
+
+    format!("{}[{}] {}{}", location, issue.severity, code, issue.message)
+}
+
+/// Escape text for XML, dropping characters XML 1.0 cannot represent
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\t' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
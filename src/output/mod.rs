@@ -1,14 +1,26 @@
 //! Output formatting for Siren
 
+pub mod github;
+pub mod junit;
+pub mod report;
+pub mod sarif;
 pub mod terminal;
 
+pub use github::GithubFormatter;
+pub use junit::JunitFormatter;
+pub use sarif::SarifFormatter;
+
 use crate::config::OutputConfig;
+use crate::errors::SirenError;
 use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ProjectInfo, ToolType};
+use crate::suppression::SuppressedCounts;
 use colored::Colorize;
 use log::debug;
 use std::collections::BTreeMap;
 use std::env;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
 use terminal::{language_emoji, tool_emoji};
 
 /// Trait for formatting output
@@ -20,7 +32,113 @@ pub trait OutputFormatter {
     fn format_results(&self, results: &[LintResult], config: &OutputConfig) -> String;
 
     /// Format a summary of lint results
-    fn format_summary(&self, results: &[LintResult]) -> String;
+    ///
+    /// `suppressed` counts the issues hidden by inline `siren: ignore` comments.
+    fn format_summary(&self, results: &[LintResult], suppressed: &SuppressedCounts) -> String;
+
+    /// Whether the output is meant for machines rather than humans
+    ///
+    /// Machine-readable formatters own stdout: only `format_results` is
+    /// printed, and nothing else may be mixed into it.
+    fn is_machine_readable(&self) -> bool {
+        false
+    }
+
+    /// Markdown for the CI job's step summary, for formats that have one
+    ///
+    /// Unlike `format_summary`, this is written out on every run, including
+    /// runs that found nothing.
+    fn job_summary(
+        &self,
+        _results: &[LintResult],
+        _suppressed: &SuppressedCounts,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl<T: OutputFormatter + ?Sized> OutputFormatter for Arc<T> {
+    fn format_detection(&self, project_info: &ProjectInfo) -> String {
+        (**self).format_detection(project_info)
+    }
+
+    fn format_results(&self, results: &[LintResult], config: &OutputConfig) -> String {
+        (**self).format_results(results, config)
+    }
+
+    fn format_summary(&self, results: &[LintResult], suppressed: &SuppressedCounts) -> String {
+        (**self).format_summary(results, suppressed)
+    }
+
+    fn is_machine_readable(&self) -> bool {
+        (**self).is_machine_readable()
+    }
+
+    fn job_summary(&self, results: &[LintResult], suppressed: &SuppressedCounts) -> Option<String> {
+        (**self).job_summary(results, suppressed)
+    }
+}
+
+/// A formatter chosen at runtime
+pub type SharedFormatter = Arc<dyn OutputFormatter + Send + Sync>;
+
+/// Output formats selectable with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colorful terminal output
+    Pretty,
+
+    /// JSON array of lint results
+    Json,
+
+    /// SARIF 2.1.0 log
+    Sarif,
+
+    /// GitHub Actions workflow-command annotations
+    Github,
+
+    /// JUnit XML report
+    Junit,
+}
+
+impl OutputFormat {
+    /// Create the formatter for this format
+    pub fn create_formatter(self) -> SharedFormatter {
+        match self {
+            OutputFormat::Pretty => Arc::new(PrettyFormatter::new()),
+            OutputFormat::Json => Arc::new(JsonFormatter::new()),
+            OutputFormat::Sarif => Arc::new(SarifFormatter::new()),
+            OutputFormat::Github => Arc::new(GithubFormatter::new()),
+            OutputFormat::Junit => Arc::new(JunitFormatter::new()),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "github" => Ok(Self::Github),
+            "junit" => Ok(Self::Junit),
+            other => Err(format!(
+                "Unknown output format '{}' (expected pretty, json, sarif, github or junit)",
+                other
+            )),
+        }
+    }
+}
+
+/// Create the formatter for a `--format` value
+pub fn create_formatter(format: &str) -> Result<SharedFormatter, SirenError> {
+    let format = format
+        .parse::<OutputFormat>()
+        .map_err(SirenError::InvalidArgument)?;
+
+    Ok(format.create_formatter())
 }
 
 /// Default implementation that uses pretty formatting with colors
@@ -61,7 +179,7 @@ impl PrettyFormatter {
 }
 
 // Helper function to convert absolute paths to relative paths
-fn make_relative_path(path: &Path) -> PathBuf {
+pub(crate) fn make_relative_path(path: &Path) -> PathBuf {
     if path.is_absolute() {
         if let Ok(current_dir) = env::current_dir() {
             if let Ok(relative) = path.strip_prefix(&current_dir) {
@@ -395,7 +513,7 @@ impl OutputFormatter for PrettyFormatter {
         output
     }
 
-    fn format_summary(&self, results: &[LintResult]) -> String {
+    fn format_summary(&self, results: &[LintResult], suppressed: &SuppressedCounts) -> String {
         // Create counters
         let mut error_count = 0;
         let mut warning_count = 0;
@@ -749,17 +867,40 @@ impl OutputFormatter for PrettyFormatter {
             }
         }
 
+        // Mention what inline comments hid, so nothing disappears silently
+        if !suppressed.is_empty() {
+            let per_tool = suppressed
+                .by_tool()
+                .iter()
+                .map(|(tool, count)| format!("{} {}", tool, count))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            output.push('\n');
+            output.push_str("  ");
+            output.push('🔇');
+            output.push(' ');
+            output.push_str(
+                &format!("{} issues suppressed inline", suppressed.total())
+                    .bright_white()
+                    .to_string(),
+            );
+            output.push(' ');
+            output.push_str(&format!("({})", per_tool).bright_black().to_string());
+            output.push('\n');
+        }
+
         output
     }
 }
 
 /// JSON formatter
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct JsonFormatter;
 
 impl JsonFormatter {
     /// Create a new JsonFormatter
-    fn _new() -> Self {
+    pub fn new() -> Self {
         Self {}
     }
 }
@@ -773,7 +914,7 @@ impl OutputFormatter for JsonFormatter {
         serde_json::to_string_pretty(results).unwrap_or_else(|_| "[]".to_string())
     }
 
-    fn format_summary(&self, results: &[LintResult]) -> String {
+    fn format_summary(&self, results: &[LintResult], suppressed: &SuppressedCounts) -> String {
         // Count issues by severity
         let mut error_count = 0;
         let mut warning_count = 0;
@@ -799,8 +940,13 @@ impl OutputFormatter for JsonFormatter {
             "style": style_count,
             "info": info_count,
             "tools_run": results.len(),
+            "suppressed": suppressed.total(),
         });
 
         serde_json::to_string_pretty(&summary).unwrap_or_else(|_| "{}".to_string())
     }
+
+    fn is_machine_readable(&self) -> bool {
+        true
+    }
 }
@@ -0,0 +1,566 @@
+//! Shareable reports rendered from a full check run
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::make_relative_path;
+use crate::models::{IssueSeverity, LintIssue, LintResult, ProjectInfo};
+
+/// Formats supported by the report command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Standalone HTML page
+    Html,
+
+    /// Markdown document
+    Markdown,
+
+    /// JSON document
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "html" | "htm" => Ok(Self::Html),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            other => Err(format!(
+                "Unknown report format '{}' (expected html, markdown or json)",
+                other
+            )),
+        }
+    }
+}
+
+/// Issue counts broken down by severity
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct SeverityCounts {
+    /// Number of errors
+    pub errors: usize,
+
+    /// Number of warnings
+    pub warnings: usize,
+
+    /// Number of informational issues
+    pub info: usize,
+
+    /// Number of style issues
+    pub style: usize,
+}
+
+impl SeverityCounts {
+    /// Count a single issue
+    pub fn add(&mut self, severity: IssueSeverity) {
+        match severity {
+            IssueSeverity::Error => self.errors += 1,
+            IssueSeverity::Warning => self.warnings += 1,
+            IssueSeverity::Info => self.info += 1,
+            IssueSeverity::Style => self.style += 1,
+        }
+    }
+
+    /// Total number of issues
+    pub fn total(&self) -> usize {
+        self.errors + self.warnings + self.info + self.style
+    }
+
+    /// Counts paired with their severity, most severe first
+    fn entries(&self) -> [(IssueSeverity, usize); 4] {
+        [
+            (IssueSeverity::Error, self.errors),
+            (IssueSeverity::Warning, self.warnings),
+            (IssueSeverity::Info, self.info),
+            (IssueSeverity::Style, self.style),
+        ]
+    }
+}
+
+/// Per-tool breakdown of a check run
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolSummary {
+    /// Tool name
+    pub name: String,
+
+    /// Tool version, if known
+    pub version: Option<String>,
+
+    /// Issue counts for this tool
+    pub counts: SeverityCounts,
+
+    /// Time the tool took to run
+    pub execution_time: Duration,
+}
+
+/// A single issue as it appears in a report
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportIssue {
+    /// Tool that reported the issue
+    pub tool: String,
+
+    /// The issue itself
+    #[serde(flatten)]
+    pub issue: LintIssue,
+}
+
+/// Per-file breakdown of a check run
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSummary {
+    /// File path, relative to the working directory where possible
+    pub path: PathBuf,
+
+    /// Issue counts for this file
+    pub counts: SeverityCounts,
+
+    /// Issues in this file, ordered by line
+    pub issues: Vec<ReportIssue>,
+}
+
+/// Aggregated data behind every report format
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    /// When the report was generated (RFC 3339)
+    pub generated_at: String,
+
+    /// Detected project information
+    pub project: ProjectInfo,
+
+    /// Issue counts across all tools
+    pub totals: SeverityCounts,
+
+    /// Breakdown by tool
+    pub tools: Vec<ToolSummary>,
+
+    /// Breakdown by file, most issues first
+    pub files: Vec<FileSummary>,
+
+    /// Issues that are not tied to a file
+    pub general_issues: Vec<ReportIssue>,
+}
+
+impl Report {
+    /// Build a report from a set of results
+    pub fn new(project_info: &ProjectInfo, results: &[LintResult]) -> Self {
+        let mut totals = SeverityCounts::default();
+        let mut tools = Vec::new();
+        let mut files: BTreeMap<PathBuf, FileSummary> = BTreeMap::new();
+        let mut general_issues = Vec::new();
+
+        for result in results {
+            let mut counts = SeverityCounts::default();
+
+            for issue in &result.issues {
+                counts.add(issue.severity);
+                totals.add(issue.severity);
+
+                let report_issue = ReportIssue {
+                    tool: result.tool_name.clone(),
+                    issue: issue.clone(),
+                };
+
+                match &issue.file {
+                    Some(file) => {
+                        let path = make_relative_path(file);
+                        let entry = files.entry(path.clone()).or_insert_with(|| FileSummary {
+                            path,
+                            counts: SeverityCounts::default(),
+                            issues: Vec::new(),
+                        });
+                        entry.counts.add(issue.severity);
+                        entry.issues.push(report_issue);
+                    }
+                    None => general_issues.push(report_issue),
+                }
+            }
+
+            tools.push(ToolSummary {
+                name: result.tool_name.clone(),
+                version: result.tool.as_ref().and_then(|t| t.version.clone()),
+                counts,
+                execution_time: result.execution_time,
+            });
+        }
+
+        // Sort issues within each file by position
+        let mut files: Vec<FileSummary> = files.into_values().collect();
+        for file in &mut files {
+            file.issues
+                .sort_by_key(|i| (i.issue.line.unwrap_or(0), i.issue.column.unwrap_or(0)));
+        }
+
+        // Files with the most issues first, then alphabetically
+        files.sort_by(|a, b| {
+            b.counts
+                .total()
+                .cmp(&a.counts.total())
+                .then_with(|| a.path.cmp(&b.path))
+        });
+
+        tools.sort_by(|a, b| {
+            b.counts
+                .total()
+                .cmp(&a.counts.total())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Self {
+            generated_at: chrono::Local::now().to_rfc3339(),
+            project: project_info.clone(),
+            totals,
+            tools,
+            files,
+            general_issues,
+        }
+    }
+
+    /// Render the report in the requested format
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Html => self.render_html(),
+            ReportFormat::Markdown => self.render_markdown(),
+            ReportFormat::Json => self.render_json(),
+        }
+    }
+
+    /// Render the report as pretty-printed JSON
+    pub fn render_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Render the report as a Markdown document
+    pub fn render_markdown(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# 🧜‍♀️ Siren Report\n\n");
+        out.push_str(&format!("_Generated {}_\n\n", self.generated_at));
+
+        if !self.project.languages.is_empty() {
+            let languages = self
+                .project
+                .languages
+                .iter()
+                .map(|l| {
+                    format!(
+                        "{:?} ({} files)",
+                        l,
+                        self.project.file_counts.get(l).unwrap_or(&0)
+                    )
+                })
+                .collect::<Vec<_>>();
+            out.push_str(&format!("**Languages:** {}\n\n", languages.join(", ")));
+        }
+
+        // Severity overview
+        out.push_str("## Summary\n\n");
+        out.push_str(&format!(
+            "**{} issues** across {} files from {} tools.\n\n",
+            self.totals.total(),
+            self.files.len(),
+            self.tools.len()
+        ));
+        out.push_str("| Severity | Count | |\n|---|---:|---|\n");
+        for (severity, count) in self.totals.entries() {
+            out.push_str(&format!(
+                "| {} | {} | `{}` |\n",
+                severity,
+                count,
+                text_bar(count, self.totals.total(), 20)
+            ));
+        }
+        out.push('\n');
+
+        // Per-tool breakdown
+        out.push_str("## Tools\n\n");
+        out.push_str("| Tool | Version | Errors | Warnings | Info | Style | Time |\n");
+        out.push_str("|---|---|---:|---:|---:|---:|---:|\n");
+        for tool in &self.tools {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {:.2}s |\n",
+                escape_markdown(&tool.name),
+                escape_markdown(tool.version.as_deref().unwrap_or("-")),
+                tool.counts.errors,
+                tool.counts.warnings,
+                tool.counts.info,
+                tool.counts.style,
+                tool.execution_time.as_secs_f64()
+            ));
+        }
+        out.push('\n');
+
+        // Per-file breakdown
+        if !self.files.is_empty() {
+            out.push_str("## Files\n\n");
+            for file in &self.files {
+                out.push_str(&format!(
+                    "### `{}` ({} issues)\n\n",
+                    file.path.display(),
+                    file.counts.total()
+                ));
+                out.push_str("| Line | Severity | Tool | Code | Message |\n");
+                out.push_str("|---:|---|---|---|---|\n");
+                for entry in &file.issues {
+                    out.push_str(&format!(
+                        "| {} | {} | {} | {} | {} |\n",
+                        location(&entry.issue),
+                        entry.issue.severity,
+                        escape_markdown(&entry.tool),
+                        escape_markdown(entry.issue.code.as_deref().unwrap_or("")),
+                        escape_markdown(&entry.issue.message)
+                    ));
+                }
+                out.push('\n');
+            }
+        }
+
+        if !self.general_issues.is_empty() {
+            out.push_str("## Other issues\n\n");
+            for entry in &self.general_issues {
+                out.push_str(&format!(
+                    "- **{}** ({}): {}\n",
+                    entry.issue.severity,
+                    escape_markdown(&entry.tool),
+                    escape_markdown(&entry.issue.message)
+                ));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Render the report as a standalone HTML page
+    pub fn render_html(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        out.push_str("<meta charset=\"utf-8\">\n");
+        out.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+        out.push_str("<title>Siren Report</title>\n");
+        out.push_str("<style>\n");
+        out.push_str(HTML_STYLE);
+        out.push_str("</style>\n</head>\n<body>\n");
+
+        // Header
+        out.push_str("<header>\n<h1>🧜‍♀️ Siren Report</h1>\n");
+        out.push_str(&format!(
+            "<p class=\"muted\">Generated {}</p>\n",
+            escape_html(&self.generated_at)
+        ));
+        if !self.project.languages.is_empty() {
+            out.push_str("<p>");
+            for language in &self.project.languages {
+                out.push_str(&format!(
+                    "<span class=\"pill\">{:?} · {} files</span> ",
+                    language,
+                    self.project.file_counts.get(language).unwrap_or(&0)
+                ));
+            }
+            out.push_str("</p>\n");
+        }
+        out.push_str("</header>\n");
+
+        // Summary cards and severity chart
+        out.push_str("<section>\n<h2>Summary</h2>\n<div class=\"cards\">\n");
+        out.push_str(&format!(
+            "<div class=\"card\"><strong>{}</strong><span>issues</span></div>\n",
+            self.totals.total()
+        ));
+        out.push_str(&format!(
+            "<div class=\"card\"><strong>{}</strong><span>files affected</span></div>\n",
+            self.files.len()
+        ));
+        out.push_str(&format!(
+            "<div class=\"card\"><strong>{}</strong><span>tools run</span></div>\n",
+            self.tools.len()
+        ));
+        out.push_str("</div>\n");
+        out.push_str(&severity_chart(&self.totals));
+        out.push_str("</section>\n");
+
+        // Per-tool breakdown
+        out.push_str("<section>\n<h2>Tools</h2>\n<table>\n");
+        out.push_str("<thead><tr><th>Tool</th><th>Version</th><th>Issues</th><th>Severity</th><th class=\"num\">Time</th></tr></thead>\n<tbody>\n");
+        for tool in &self.tools {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td class=\"muted\">{}</td><td class=\"num\">{}</td><td>{}</td><td class=\"num\">{:.2}s</td></tr>\n",
+                escape_html(&tool.name),
+                escape_html(tool.version.as_deref().unwrap_or("-")),
+                tool.counts.total(),
+                stacked_bar(&tool.counts),
+                tool.execution_time.as_secs_f64()
+            ));
+        }
+        out.push_str("</tbody>\n</table>\n</section>\n");
+
+        // Per-file breakdown
+        if !self.files.is_empty() {
+            out.push_str("<section>\n<h2>Files</h2>\n");
+            for file in &self.files {
+                out.push_str(&format!(
+                    "<details>\n<summary><code>{}</code> <span class=\"count\">{}</span> {}</summary>\n",
+                    escape_html(&file.path.display().to_string()),
+                    file.counts.total(),
+                    stacked_bar(&file.counts)
+                ));
+                out.push_str("<table>\n<thead><tr><th class=\"num\">Line</th><th>Severity</th><th>Tool</th><th>Code</th><th>Message</th></tr></thead>\n<tbody>\n");
+                for entry in &file.issues {
+                    out.push_str(&issue_row(&entry.tool, &entry.issue));
+                }
+                out.push_str("</tbody>\n</table>\n</details>\n");
+            }
+            out.push_str("</section>\n");
+        }
+
+        if !self.general_issues.is_empty() {
+            out.push_str("<section>\n<h2>Other issues</h2>\n<table>\n<tbody>\n");
+            for entry in &self.general_issues {
+                out.push_str(&issue_row(&entry.tool, &entry.issue));
+            }
+            out.push_str("</tbody>\n</table>\n</section>\n");
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+/// CSS class used for a severity
+fn severity_class(severity: IssueSeverity) -> &'static str {
+    match severity {
+        IssueSeverity::Error => "error",
+        IssueSeverity::Warning => "warning",
+        IssueSeverity::Info => "info",
+        IssueSeverity::Style => "style",
+    }
+}
+
+/// Render the horizontal severity bar chart
+fn severity_chart(counts: &SeverityCounts) -> String {
+    let max = counts.entries().iter().map(|(_, c)| *c).max().unwrap_or(0);
+    let mut out = String::from("<div class=\"chart\">\n");
+
+    for (severity, count) in counts.entries() {
+        let width = (count * 100).checked_div(max).unwrap_or(0);
+        out.push_str(&format!(
+            "<div class=\"row\"><span class=\"label\">{}</span><div class=\"track\"><div class=\"bar {}\" style=\"width: {}%\"></div></div><span class=\"num\">{}</span></div>\n",
+            severity,
+            severity_class(severity),
+            width,
+            count
+        ));
+    }
+
+    out.push_str("</div>\n");
+    out
+}
+
+/// Render a small stacked bar showing the severity mix
+fn stacked_bar(counts: &SeverityCounts) -> String {
+    let total = counts.total();
+    if total == 0 {
+        return "<span class=\"muted\">clean</span>".to_string();
+    }
+
+    let mut out = String::from("<div class=\"stack\">");
+    for (severity, count) in counts.entries() {
+        if count > 0 {
+            out.push_str(&format!(
+                "<div class=\"bar {}\" style=\"width: {}%\" title=\"{} {}\"></div>",
+                severity_class(severity),
+                count * 100 / total,
+                count,
+                severity
+            ));
+        }
+    }
+    out.push_str("</div>");
+    out
+}
+
+/// Render a table row for a single issue
+fn issue_row(tool: &str, issue: &LintIssue) -> String {
+    format!(
+        "<tr><td class=\"num\">{}</td><td><span class=\"sev {}\">{}</span></td><td>{}</td><td><code>{}</code></td><td>{}</td></tr>\n",
+        location(issue),
+        severity_class(issue.severity),
+        issue.severity,
+        escape_html(tool),
+        escape_html(issue.code.as_deref().unwrap_or("")),
+        escape_html(&issue.message)
+    )
+}
+
+/// Line and column of an issue as `line:col`
+fn location(issue: &LintIssue) -> String {
+    match (issue.line, issue.column) {
+        (Some(line), Some(column)) => format!("{}:{}", line, column),
+        (Some(line), None) => line.to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+/// Plain text bar for Markdown output
+fn text_bar(count: usize, total: usize, width: usize) -> String {
+    let filled = (count * width).checked_div(total).unwrap_or(0);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Escape text for inclusion in HTML
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape text for inclusion in a Markdown table cell
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Embedded stylesheet so the HTML report is a single self-contained file
+const HTML_STYLE: &str = r#"
+:root { --bg: #0d0b14; --panel: #171325; --text: #e8e6f0; --muted: #8b87a0; --accent: #ff5fd2;
+        --error: #ff4d6d; --warning: #ffb347; --style: #c77dff; --info: #4cc9f0; }
+* { box-sizing: border-box; }
+body { margin: 0 auto; max-width: 1100px; padding: 2rem; background: var(--bg); color: var(--text);
+       font-family: -apple-system, "Segoe UI", Roboto, sans-serif; line-height: 1.5; }
+h1 { color: var(--accent); margin-bottom: 0; }
+h2 { border-bottom: 1px solid #2c2640; padding-bottom: .3rem; }
+code { font-family: ui-monospace, "SF Mono", Menlo, monospace; }
+.muted { color: var(--muted); }
+.pill { display: inline-block; background: var(--panel); border-radius: 999px; padding: .1rem .7rem; margin: .1rem; }
+.cards { display: flex; gap: 1rem; margin-bottom: 1.5rem; }
+.card { flex: 1; background: var(--panel); border-radius: 8px; padding: 1rem; text-align: center; }
+.card strong { display: block; font-size: 2rem; color: var(--accent); }
+.chart .row { display: flex; align-items: center; gap: .75rem; margin: .3rem 0; }
+.chart .label { width: 5rem; }
+.chart .track { flex: 1; background: var(--panel); border-radius: 4px; height: 1.1rem; }
+.chart .bar { height: 100%; border-radius: 4px; }
+.stack { display: inline-flex; width: 160px; height: .7rem; border-radius: 4px; overflow: hidden; background: var(--panel); vertical-align: middle; }
+.bar.error { background: var(--error); } .bar.warning { background: var(--warning); }
+.bar.style { background: var(--style); } .bar.info { background: var(--info); }
+.sev { font-weight: 600; } .sev.error { color: var(--error); } .sev.warning { color: var(--warning); }
+.sev.style { color: var(--style); } .sev.info { color: var(--info); }
+table { width: 100%; border-collapse: collapse; margin: .5rem 0 1rem; }
+th, td { text-align: left; padding: .35rem .6rem; border-bottom: 1px solid #2c2640; vertical-align: top; }
+th { color: var(--muted); font-weight: 500; }
+.num { text-align: right; white-space: nowrap; }
+details { background: var(--panel); border-radius: 8px; padding: .5rem 1rem; margin: .5rem 0; }
+summary { cursor: pointer; }
+.count { color: var(--accent); font-weight: 600; margin: 0 .5rem; }
+"#;
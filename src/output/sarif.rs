@@ -0,0 +1,144 @@
+//! SARIF 2.1.0 output for code scanning dashboards
+
+use std::collections::BTreeSet;
+
+use serde_json::{json, Value};
+
+use super::{make_relative_path, OutputFormatter};
+use crate::config::OutputConfig;
+use crate::models::{IssueSeverity, LintIssue, LintResult, ProjectInfo};
+use crate::suppression::SuppressedCounts;
+
+/// SARIF schema location
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF specification version
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Formatter that emits a SARIF 2.1.0 log with one run per tool
+///
+/// Only `format_results` produces output; detection info and summaries are
+/// empty so that stdout is a single valid SARIF document.
+#[derive(Clone, Default)]
+pub struct SarifFormatter;
+
+impl SarifFormatter {
+    /// Create a new SarifFormatter
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Build the SARIF log for a set of results
+    pub fn to_sarif(&self, results: &[LintResult]) -> Value {
+        let runs: Vec<Value> = results.iter().map(sarif_run).collect();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": runs,
+        })
+    }
+}
+
+impl OutputFormatter for SarifFormatter {
+    fn format_detection(&self, _project_info: &ProjectInfo) -> String {
+        String::new()
+    }
+
+    fn format_results(&self, results: &[LintResult], _config: &OutputConfig) -> String {
+        serde_json::to_string_pretty(&self.to_sarif(results)).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_summary(&self, _results: &[LintResult], _suppressed: &SuppressedCounts) -> String {
+        String::new()
+    }
+
+    fn is_machine_readable(&self) -> bool {
+        true
+    }
+}
+
+/// Build a SARIF run for a single tool
+fn sarif_run(result: &LintResult) -> Value {
+    let mut driver = json!({
+        "name": result.tool_name,
+        "rules": sarif_rules(&result.issues),
+    });
+
+    if let Some(info) = &result.tool {
+        driver["name"] = json!(info.name);
+        if let Some(version) = &info.version {
+            driver["version"] = json!(version);
+        }
+        if !info.description.is_empty() {
+            driver["shortDescription"] = json!({ "text": info.description });
+        }
+        driver["properties"] = json!({
+            "toolType": format!("{:?}", info.tool_type),
+            "languages": info
+                .languages
+                .iter()
+                .map(|l| format!("{:?}", l))
+                .collect::<Vec<_>>(),
+        });
+    }
+
+    json!({
+        "tool": { "driver": driver },
+        "results": result.issues.iter().map(sarif_result).collect::<Vec<_>>(),
+    })
+}
+
+/// Build the rule descriptors referenced by a tool's issues
+fn sarif_rules(issues: &[LintIssue]) -> Vec<Value> {
+    let codes: BTreeSet<&str> = issues.iter().filter_map(|i| i.code.as_deref()).collect();
+
+    codes
+        .into_iter()
+        .map(|code| json!({ "id": code }))
+        .collect()
+}
+
+/// Build a SARIF result for a single issue
+fn sarif_result(issue: &LintIssue) -> Value {
+    let mut result = json!({
+        "level": sarif_level(issue.severity),
+        "message": { "text": issue.message },
+    });
+
+    if let Some(code) = &issue.code {
+        result["ruleId"] = json!(code);
+    }
+
+    if let Some(file) = &issue.file {
+        let uri = make_relative_path(file)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        let mut physical_location = json!({
+            "artifactLocation": { "uri": uri },
+        });
+
+        // SARIF regions are 1-based, a line of 0 means "unknown"
+        if let Some(line) = issue.line.filter(|l| *l > 0) {
+            let mut region = json!({ "startLine": line });
+            if let Some(column) = issue.column.filter(|c| *c > 0) {
+                region["startColumn"] = json!(column);
+            }
+            physical_location["region"] = region;
+        }
+
+        result["locations"] = json!([{ "physicalLocation": physical_location }]);
+    }
+
+    result
+}
+
+/// Map an issue severity onto a SARIF level
+pub fn sarif_level(severity: IssueSeverity) -> &'static str {
+    match severity {
+        IssueSeverity::Error => "error",
+        IssueSeverity::Warning => "warning",
+        IssueSeverity::Info | IssueSeverity::Style => "note",
+    }
+}
@@ -76,6 +76,7 @@ pub fn language_emoji(language: &crate::models::Language) -> &'static str {
         Language::Php => "🐘",
         Language::Docker => "🐳",
         Language::Makefile => "🔨",
+        Language::Shell => "🐚",
         Language::Unknown => "📄",
     }
 }
@@ -181,6 +182,7 @@ pub struct NeonDisplay {
     render_thread: Option<thread::JoinHandle<()>>,
     running: Arc<Mutex<bool>>,
     issues_count: usize,
+    visible: bool,
 }
 
 impl Default for NeonDisplay {
@@ -357,6 +359,21 @@ impl NeonDisplay {
             render_thread: Some(render_thread),
             running,
             issues_count: 0,
+            visible: true,
+        }
+    }
+
+    /// Create a status display that tracks state but never draws anything
+    ///
+    /// Used when stdout has to stay clean, e.g. for quiet mode or when a
+    /// machine-readable document is written to stdout.
+    pub fn hidden() -> Self {
+        Self {
+            spinner_states: Arc::new(Mutex::new(Vec::new())),
+            render_thread: None,
+            running: Arc::new(Mutex::new(false)),
+            issues_count: 0,
+            visible: false,
         }
     }
 
@@ -424,7 +441,9 @@ impl NeonDisplay {
 
             // Add a small delay to make the status change more visible
             drop(states);
-            std::thread::sleep(std::time::Duration::from_millis(300));
+            if self.visible {
+                std::thread::sleep(std::time::Duration::from_millis(300));
+            }
         }
     }
 
@@ -442,6 +461,10 @@ impl NeonDisplay {
             let _ = handle.join();
         }
 
+        if !self.visible {
+            return;
+        }
+
         // Display elegant footer with enhanced neon styling
         let now = chrono::Local::now();
 
@@ -0,0 +1,418 @@
+//! Suggestion engine that turns detection and lint results into actionable advice
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use crate::models::{Language, LintResult, ProjectInfo, ToolType};
+use crate::output::make_relative_path;
+use crate::tools::ToolInfo;
+
+/// Files holding at least this many issues are called out as hotspots
+const HOTSPOT_MIN_ISSUES: usize = 5;
+
+/// Share of autofixable issues above which fixing is a high priority
+const AUTOFIX_HIGH_RATIO: f64 = 0.5;
+
+/// How urgent a suggestion is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Big win for little effort
+    High,
+
+    /// Worth doing soon
+    Medium,
+
+    /// Nice to have
+    Low,
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Priority::High => write!(f, "High"),
+            Priority::Medium => write!(f, "Medium"),
+            Priority::Low => write!(f, "Low"),
+        }
+    }
+}
+
+/// The kind of improvement a suggestion is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuggestionKind {
+    /// Many issues can be fixed automatically
+    Autofix,
+
+    /// A language has files but no linter ran on them
+    UncoveredLanguage,
+
+    /// A tool runs with its defaults because it has no config file
+    MissingToolConfig,
+
+    /// A single file concentrates a large share of the issues
+    Hotspot,
+
+    /// The project has no Siren configuration
+    MissingSirenConfig,
+}
+
+/// A single piece of actionable advice
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    /// What the suggestion is about
+    pub kind: SuggestionKind,
+
+    /// How urgent it is
+    pub priority: Priority,
+
+    /// One-line summary
+    pub title: String,
+
+    /// Ready-to-run command that acts on the suggestion
+    pub command: String,
+
+    /// Number of files or issues affected, used to rank within a priority
+    pub impact: usize,
+}
+
+/// Builds ranked suggestions from a project's detection and lint results
+pub struct SuggestionEngine<'a> {
+    project_info: &'a ProjectInfo,
+    results: &'a [LintResult],
+    tools: Vec<ToolInfo>,
+    has_siren_config: bool,
+}
+
+impl<'a> SuggestionEngine<'a> {
+    /// Create a new engine for a project and the results of a check run
+    pub fn new(project_info: &'a ProjectInfo, results: &'a [LintResult]) -> Self {
+        Self {
+            project_info,
+            results,
+            tools: Vec::new(),
+            has_siren_config: false,
+        }
+    }
+
+    /// Provide information about the registered tools
+    pub fn with_tools(mut self, tools: Vec<ToolInfo>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Set whether the project already has a `.siren.toml`
+    pub fn with_siren_config(mut self, has_siren_config: bool) -> Self {
+        self.has_siren_config = has_siren_config;
+        self
+    }
+
+    /// Produce all suggestions, most important first
+    pub fn suggest(&self) -> Vec<Suggestion> {
+        let mut suggestions = Vec::new();
+
+        self.suggest_autofix(&mut suggestions);
+        self.suggest_uncovered_languages(&mut suggestions);
+        self.suggest_missing_tool_configs(&mut suggestions);
+        self.suggest_hotspots(&mut suggestions);
+
+        if !self.has_siren_config {
+            suggestions.push(Suggestion {
+                kind: SuggestionKind::MissingSirenConfig,
+                priority: Priority::Low,
+                title: "No .siren.toml found; generate one to share tool settings with your team"
+                    .to_string(),
+                command: "siren init".to_string(),
+                impact: 0,
+            });
+        }
+
+        suggestions.sort_by(|a, b| {
+            a.priority
+                .cmp(&b.priority)
+                .then_with(|| b.impact.cmp(&a.impact))
+                .then_with(|| a.title.cmp(&b.title))
+        });
+
+        suggestions
+    }
+
+    /// Tools whose issues are mostly autofixable
+    fn suggest_autofix(&self, suggestions: &mut Vec<Suggestion>) {
+        for result in self.results {
+            let total = result.issues.len();
+            let fixable = result.issues.iter().filter(|i| i.fix_available).count();
+            if fixable == 0 {
+                continue;
+            }
+
+            let ratio = fixable as f64 / total as f64;
+            suggestions.push(Suggestion {
+                kind: SuggestionKind::Autofix,
+                priority: if ratio >= AUTOFIX_HIGH_RATIO {
+                    Priority::High
+                } else {
+                    Priority::Medium
+                },
+                title: format!(
+                    "{}% of {} issues ({} of {}) are autofixable",
+                    (ratio * 100.0).round() as usize,
+                    result.tool_name,
+                    fixable,
+                    total
+                ),
+                command: format!("siren fix --tools {}", result.tool_name),
+                impact: fixable,
+            });
+        }
+    }
+
+    /// Languages with files that no linter looked at
+    fn suggest_uncovered_languages(&self, suggestions: &mut Vec<Suggestion>) {
+        let ran: HashSet<&str> = self.results.iter().map(|r| r.tool_name.as_str()).collect();
+
+        let covered: HashSet<Language> = self
+            .tools
+            .iter()
+            .filter(|t| ran.contains(t.name.as_str()))
+            .flat_map(|t| t.languages.iter().copied())
+            .collect();
+
+        for language in &self.project_info.languages {
+            let file_count = self
+                .project_info
+                .file_counts
+                .get(language)
+                .copied()
+                .unwrap_or(0);
+            if file_count == 0 || covered.contains(language) {
+                continue;
+            }
+
+            let mut linters = self
+                .tools
+                .iter()
+                .filter(|t| is_checker(t.tool_type) && t.languages.contains(language))
+                .collect::<Vec<_>>();
+            linters.sort_by(|a, b| b.available.cmp(&a.available).then(a.name.cmp(&b.name)));
+
+            // Prefer a linter that is installed, then one we know how to install
+            let Some(linter) = linters
+                .iter()
+                .find(|t| t.available || install_command(&t.name).is_some())
+                .or(linters.first())
+            else {
+                // Nothing we could recommend for this language yet
+                continue;
+            };
+
+            let (title, command) = if linter.available {
+                (
+                    format!(
+                        "Enable {} for the {} {:?} files that have no linter",
+                        linter.name, file_count, language
+                    ),
+                    format!("siren check --tools {}", linter.name),
+                )
+            } else {
+                // Without a known install command, point at the run to make
+                // once the tool is installed
+                (
+                    format!(
+                        "Install {} to lint the {} {:?} files that have no linter",
+                        linter.name, file_count, language
+                    ),
+                    install_command(&linter.name)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("siren check --tools {}", linter.name)),
+                )
+            };
+
+            suggestions.push(Suggestion {
+                kind: SuggestionKind::UncoveredLanguage,
+                priority: Priority::High,
+                title,
+                command,
+                impact: file_count,
+            });
+        }
+    }
+
+    /// Available tools running without a project config file
+    fn suggest_missing_tool_configs(&self, suggestions: &mut Vec<Suggestion>) {
+        let configured: HashSet<&str> = self
+            .project_info
+            .detected_tools
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+
+        for tool in &self.tools {
+            if !tool.available
+                || !is_checker(tool.tool_type)
+                || configured.contains(config_owner(&tool.name))
+            {
+                continue;
+            }
+
+            let file_count: usize = tool
+                .languages
+                .iter()
+                .filter(|l| self.project_info.languages.contains(l))
+                .filter_map(|l| self.project_info.file_counts.get(l))
+                .sum();
+            if file_count == 0 {
+                continue;
+            }
+
+            let Some(command) = config_command(&tool.name) else {
+                continue;
+            };
+
+            suggestions.push(Suggestion {
+                kind: SuggestionKind::MissingToolConfig,
+                priority: Priority::Medium,
+                title: format!(
+                    "{} is available but no {} config was detected",
+                    tool.name, tool.name
+                ),
+                command: command.to_string(),
+                impact: file_count,
+            });
+        }
+    }
+
+    /// The file with the most issues, when it stands out
+    fn suggest_hotspots(&self, suggestions: &mut Vec<Suggestion>) {
+        let mut per_file: HashMap<PathBuf, usize> = HashMap::new();
+        for issue in self.results.iter().flat_map(|r| &r.issues) {
+            if let Some(file) = &issue.file {
+                *per_file.entry(make_relative_path(file)).or_default() += 1;
+            }
+        }
+
+        let total: usize = per_file.values().sum();
+        let hotspot = per_file
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)));
+
+        if let Some((file, count)) = hotspot {
+            if count < HOTSPOT_MIN_ISSUES {
+                return;
+            }
+
+            suggestions.push(Suggestion {
+                kind: SuggestionKind::Hotspot,
+                priority: Priority::Low,
+                title: format!(
+                    "{} has {} issues ({}% of all); start there",
+                    file.display(),
+                    count,
+                    count * 100 / total
+                ),
+                command: format!("siren check {}", file.display()),
+                impact: count,
+            });
+        }
+    }
+}
+
+/// Whether a tool reports issues (as opposed to formatting or fixing)
+fn is_checker(tool_type: ToolType) -> bool {
+    matches!(tool_type, ToolType::Linter | ToolType::TypeChecker)
+}
+
+/// Name detection reports for a checker that shares another tool's config
+fn config_owner(tool_name: &str) -> &str {
+    match tool_name {
+        "taplo-lint" => "taplo",
+        "php-cs-fixer-check" => "php-cs-fixer",
+        "dotnet-format-check" => "dotnet-format",
+        _ => tool_name,
+    }
+}
+
+/// Command that installs a known tool
+pub fn install_command(tool_name: &str) -> Option<&'static str> {
+    match tool_name {
+        "clippy" => Some("rustup component add clippy"),
+        "rustfmt" => Some("rustup component add rustfmt"),
+        "ruff" => Some("pip install ruff"),
+        "pylint" => Some("pip install pylint"),
+        "mypy" => Some("pip install mypy"),
+        "black" => Some("pip install black"),
+        "djlint" => Some("pip install djlint"),
+        "eslint" => Some("npm install --save-dev eslint"),
+        "prettier" => Some("npm install --save-dev prettier"),
+        "golangci-lint" => {
+            Some("go install github.com/golangci/golangci-lint/v2/cmd/golangci-lint@latest")
+        }
+        "rubocop" => Some("gem install rubocop"),
+        "sorbet" => Some("gem install sorbet sorbet-runtime"),
+        "tsc" => Some("npm install --save-dev typescript"),
+        "stylelint" => Some("npm install --save-dev stylelint stylelint-config-standard"),
+        "markdownlint" => Some("npm install --save-dev markdownlint-cli2"),
+        "yamllint" => Some("pip install yamllint"),
+        "taplo" | "taplo-lint" => Some("cargo install taplo-cli --locked"),
+        "shellcheck" => Some("pip install shellcheck-py"),
+        "shfmt" => Some("go install mvdan.cc/sh/v3/cmd/shfmt@latest"),
+        "clang-format" => Some("pip install clang-format"),
+        "clang-tidy" => Some("pip install clang-tidy"),
+        "google-java-format" => Some("brew install google-java-format"),
+        "checkstyle" => Some("brew install checkstyle"),
+        "pmd" => Some("brew install pmd"),
+        "hadolint" => Some("brew install hadolint"),
+        "checkmake" => Some("go install github.com/mrtazz/checkmake/cmd/checkmake@latest"),
+        "php-cs-fixer" | "php-cs-fixer-check" => {
+            Some("composer require --dev friendsofphp/php-cs-fixer")
+        }
+        "phpstan" => Some("composer require --dev phpstan/phpstan"),
+        "swift-format" => Some("brew install swift-format"),
+        "swiftlint" => Some("brew install swiftlint"),
+        _ => None,
+    }
+}
+
+/// Command that bootstraps a config file for a known tool
+pub fn config_command(tool_name: &str) -> Option<&'static str> {
+    match tool_name {
+        "clippy" => Some("touch clippy.toml"),
+        "ruff" => Some("printf '[lint]\\nselect = [\"E\", \"F\", \"I\"]\\n' > ruff.toml"),
+        "pylint" => Some("pylint --generate-rcfile > .pylintrc"),
+        "mypy" => Some("printf '[mypy]\\nignore_missing_imports = True\\n' > mypy.ini"),
+        "eslint" => Some("npm init @eslint/config@latest"),
+        "djlint" => Some("printf '{\\n  \"profile\": \"django\"\\n}\\n' > .djlintrc"),
+        "golangci-lint" => Some("printf 'version: \"2\"\\n' > .golangci.yml"),
+        "rubocop" => Some("printf 'AllCops:\\n  NewCops: enable\\n' > .rubocop.yml"),
+        "sorbet" => Some("bundle exec srb init"),
+        "stylelint" => Some(
+            "printf '{\\n  \"extends\": \"stylelint-config-standard\"\\n}\\n' > .stylelintrc.json",
+        ),
+        "markdownlint" => Some("printf '{\\n  \"default\": true\\n}\\n' > .markdownlint.jsonc"),
+        "yamllint" => Some("printf 'extends: default\\n' > .yamllint"),
+        "taplo-lint" => Some("touch taplo.toml"),
+        "shellcheck" => Some("printf 'external-sources=true\\n' > .shellcheckrc"),
+        "clang-tidy" => Some("clang-tidy --dump-config > .clang-tidy"),
+        "checkstyle" => Some(concat!(
+            "curl -fsSLo checkstyle.xml https://raw.githubusercontent.com/",
+            "checkstyle/checkstyle/master/src/main/resources/google_checks.xml"
+        )),
+        "pmd" => Some(concat!(
+            "printf '<?xml version=\"1.0\"?>\\n",
+            "<ruleset name=\"project\" xmlns=\"http://pmd.sourceforge.net/ruleset/2.0.0\">\\n",
+            "  <description>Project rules</description>\\n",
+            "  <rule ref=\"rulesets/java/quickstart.xml\"/>\\n",
+            "</ruleset>\\n' > pmd.xml"
+        )),
+        "hadolint" => Some("printf 'failure-threshold: warning\\n' > .hadolint.yaml"),
+        "checkmake" => Some("printf '[maxbodylength]\\nmaxBodyLength = 10\\n' > checkmake.ini"),
+        "php-cs-fixer-check" => Some(concat!(
+            "printf '<?php\\n\\nreturn (new PhpCsFixer\\\\Config())\\n",
+            "    ->setRules([\"@PER-CS\" => true])\\n",
+            "    ->setFinder(PhpCsFixer\\\\Finder::create()->in(__DIR__));\\n' > .php-cs-fixer.dist.php"
+        )),
+        "phpstan" => Some("printf 'parameters:\\n  level: 5\\n' > phpstan.neon"),
+        "dotnet-format-check" => Some("dotnet new editorconfig"),
+        "swift-format" => Some("swift-format dump-configuration > .swift-format"),
+        "swiftlint" => Some("printf 'excluded:\\n  - .build\\n' > .swiftlint.yml"),
+        _ => None,
+    }
+}
@@ -0,0 +1,244 @@
+//! Inline suppression comments that work the same for every tool
+//!
+//! Tools each have their own way of silencing a finding (`# noqa`,
+//! `# type: ignore`, `// eslint-disable-line`, ...). Siren adds one more that
+//! is applied after the tools have run, so a single comment can cover every
+//! tool reporting on a line:
+//!
+//! ```text
+//! x = eval(data)  # siren: ignore[ruff:S307, pylint:W0123] -- trusted input
+//! // siren-ignore-next-line clippy::unwrap_used -- checked above
+//! ```
+//!
+//! Rules are `tool`, `tool:code` or a bare `code`; leaving the list out
+//! ignores everything on the line. A reason goes after ` -- `, otherwise its
+//! words would be read as rules. `siren-ignore` and `siren: ignore` are
+//! interchangeable, and both take a `-next-line` suffix.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::models::{LintIssue, LintResult};
+
+static DIRECTIVE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"siren(?::[ \t]*|-)ignore(-next-line)?(?:\[([^\]]*)\]|[ \t]+([^\n]*)|\b)").unwrap()
+});
+
+static RULE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[\w.@/-]+(?:::?[\w.@/-]+)*$").unwrap());
+
+/// One rule inside a suppression comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// A bare name, matching either a tool or a code
+    Name(String),
+
+    /// A tool, optionally narrowed down to one of its codes
+    Tool { tool: String, code: Option<String> },
+}
+
+impl Rule {
+    /// Parse `tool`, `tool:code`, `tool::code` or a bare code
+    pub fn parse(rule: &str) -> Self {
+        match rule.split_once(':') {
+            Some((tool, code)) => {
+                let code = code.trim_start_matches(':');
+                Rule::Tool {
+                    tool: tool.to_string(),
+                    code: (!code.is_empty()).then(|| code.to_string()),
+                }
+            }
+            None => Rule::Name(rule.to_string()),
+        }
+    }
+
+    /// Whether the rule covers an issue reported by a tool
+    pub fn matches(&self, tool_name: &str, issue: &LintIssue) -> bool {
+        let codes = issue_codes(issue);
+        match self {
+            Rule::Name(name) => same(name, tool_name) || codes.iter().any(|code| same(name, code)),
+            Rule::Tool { tool, code } => {
+                same(tool, tool_name)
+                    && code.as_ref().is_none_or(|code| {
+                        let qualified = format!("{}::{}", tool, code);
+                        codes.iter().any(|c| same(code, c) || same(&qualified, c))
+                    })
+            }
+        }
+    }
+}
+
+/// Suppression comments found in one file, by the line they apply to
+#[derive(Debug, Clone, Default)]
+pub struct FileSuppressions {
+    /// Rules per 1-based line; an empty rule list covers everything
+    lines: HashMap<usize, Vec<Vec<Rule>>>,
+}
+
+impl FileSuppressions {
+    /// Find every suppression comment in a file's content
+    pub fn parse(content: &str) -> Self {
+        let mut lines: HashMap<usize, Vec<Vec<Rule>>> = HashMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            for capture in DIRECTIVE.captures_iter(line) {
+                let target = if capture.get(1).is_some() {
+                    index + 2
+                } else {
+                    index + 1
+                };
+
+                let rules = match (capture.get(2), capture.get(3)) {
+                    (Some(list), _) => list
+                        .as_str()
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|rule| !rule.is_empty())
+                        .map(Rule::parse)
+                        .collect(),
+                    // A free-form list ends at the `--` before a reason, or at
+                    // the first word that is not a rule, e.g. the `*/` or
+                    // `-->` closing the comment
+                    (None, Some(list)) => list
+                        .as_str()
+                        .split(|c: char| c.is_whitespace() || c == ',')
+                        .filter(|rule| !rule.is_empty())
+                        .take_while(|rule| *rule != "--" && RULE.is_match(rule))
+                        .map(Rule::parse)
+                        .collect(),
+                    (None, None) => Vec::new(),
+                };
+
+                lines.entry(target).or_default().push(rules);
+            }
+        }
+
+        Self { lines }
+    }
+
+    /// Whether an issue on the given line is suppressed
+    pub fn suppresses(&self, tool_name: &str, issue: &LintIssue) -> bool {
+        let Some(directives) = issue.line.and_then(|line| self.lines.get(&line)) else {
+            return false;
+        };
+
+        directives.iter().any(|rules| {
+            rules.is_empty() || rules.iter().any(|rule| rule.matches(tool_name, issue))
+        })
+    }
+
+    /// Whether the file has no suppression comments at all
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+/// How many issues each tool had suppressed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SuppressedCounts {
+    by_tool: BTreeMap<String, usize>,
+}
+
+impl SuppressedCounts {
+    /// Record suppressed issues for a tool
+    pub fn add(&mut self, tool_name: &str, count: usize) {
+        if count > 0 {
+            *self.by_tool.entry(tool_name.to_string()).or_default() += count;
+        }
+    }
+
+    /// Suppressed issues per tool, sorted by tool name
+    pub fn by_tool(&self) -> &BTreeMap<String, usize> {
+        &self.by_tool
+    }
+
+    /// Total number of suppressed issues
+    pub fn total(&self) -> usize {
+        self.by_tool.values().sum()
+    }
+
+    /// Whether nothing was suppressed
+    pub fn is_empty(&self) -> bool {
+        self.by_tool.is_empty()
+    }
+
+    /// Drop the counts for some tools, e.g. before their results are replaced
+    pub fn remove_tools(&mut self, tools: &[String]) {
+        self.by_tool.retain(|tool, _| !tools.contains(tool));
+    }
+
+    /// Add up the counts from another run
+    pub fn merge(&mut self, other: &SuppressedCounts) {
+        for (tool, count) in &other.by_tool {
+            self.add(tool, *count);
+        }
+    }
+}
+
+/// Remove every issue covered by a suppression comment in its source file
+pub fn apply_suppressions(results: &mut [LintResult]) -> SuppressedCounts {
+    let mut files: HashMap<PathBuf, FileSuppressions> = HashMap::new();
+    let mut counts = SuppressedCounts::default();
+
+    for result in results.iter_mut() {
+        let before = result.issues.len();
+        let tool_name = result.tool_name.clone();
+
+        result.issues.retain(|issue| {
+            let Some(file) = &issue.file else {
+                return true;
+            };
+            let suppressions = files
+                .entry(file.clone())
+                .or_insert_with(|| load_suppressions(file));
+            !suppressions.suppresses(&tool_name, issue)
+        });
+
+        let suppressed = before - result.issues.len();
+        if suppressed > 0 {
+            counts.add(&tool_name, suppressed);
+            result.success = result.issues.is_empty();
+        }
+    }
+
+    counts
+}
+
+fn load_suppressions(file: &Path) -> FileSuppressions {
+    fs::read_to_string(file)
+        .map(|content| FileSuppressions::parse(&content))
+        .unwrap_or_default()
+}
+
+/// Codes an issue can be matched by: its code, plus a `[rule]` prefix that
+/// some tools put in front of the message instead
+fn issue_codes(issue: &LintIssue) -> Vec<&str> {
+    let mut codes = Vec::new();
+    if let Some(code) = &issue.code {
+        codes.push(code.as_str());
+    }
+    if let Some(rest) = issue.message.strip_prefix('[') {
+        if let Some((name, _)) = rest.split_once(']') {
+            codes.push(name);
+        }
+    }
+    codes
+}
+
+/// Compare names ignoring case and `-` versus `_`, as clippy and eslint do
+fn same(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.chars().zip(b.chars()).all(|(a, b)| {
+            let normalize = |c: char| {
+                if c == '-' {
+                    '_'
+                } else {
+                    c.to_ascii_lowercase()
+                }
+            };
+            normalize(a) == normalize(b)
+        })
+}
@@ -0,0 +1,183 @@
+//! clang-format formatter for C and C++
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Dry-run diagnostics:
+/// `file:line:col: warning: code should be clang-formatted [-Wclang-format-violations]`
+static VIOLATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(.+?):(\d+):(\d+): (?:warning|error): .*\[-Wclang-format-violations\]$").unwrap()
+});
+
+/// clang-format formatter for C and C++
+pub struct ClangFormat {
+    base: ToolBase,
+}
+
+impl Default for ClangFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClangFormat {
+    /// Create a new clang-format formatter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "clang-format".to_string(),
+                description: "Formatter for C and C++ code".to_string(),
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::C, Language::Cpp],
+            },
+        }
+    }
+
+    /// Parse the output of `clang-format --dry-run`
+    ///
+    /// clang-format reports every replacement it would make; these are folded
+    /// into one issue per file, pointing at the first one.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let mut issues: Vec<LintIssue> = Vec::new();
+
+        for capture in output.lines().filter_map(|line| VIOLATION.captures(line)) {
+            let file = PathBuf::from(&capture[1]);
+            if issues
+                .iter()
+                .any(|issue| issue.file.as_ref() == Some(&file))
+            {
+                continue;
+            }
+
+            issues.push(LintIssue {
+                severity: IssueSeverity::Style,
+                message: "File needs formatting".to_string(),
+                file: Some(file),
+                line: capture[2].parse().ok(),
+                column: capture[3].parse().ok(),
+                code: None,
+                fix_available: true,
+            });
+        }
+
+        issues
+    }
+}
+
+impl LintTool for ClangFormat {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_c_family(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let sources = super::c_family_files(files);
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !sources.is_empty() {
+            let mut command = Command::new("clang-format");
+
+            // Report what would change; `--style=file` picks up the nearest
+            // .clang-format for each file
+            command.args(["--dry-run", "--style=file"]);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&sources);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute clang-format: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            // Without --Werror a failure means clang-format could not run at
+            // all, usually because of an invalid .clang-format
+            if !output.status.success() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: stderr,
+                });
+            }
+
+            issues = self.parse_output(&stderr);
+
+            // Rewrite only the files that need it, unless only checking
+            if !config.check && !issues.is_empty() {
+                let mut command = Command::new("clang-format");
+                command.args(["-i", "--style=file"]);
+                for arg in &config.extra_args {
+                    command.arg(arg);
+                }
+                command.args(issues.iter().filter_map(|issue| issue.file.as_ref()));
+
+                utils::log_command(&command);
+
+                let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                    name: self.name().to_string(),
+                    message: format!("Failed to execute clang-format: {}", e),
+                })?;
+
+                if !output.status.success() {
+                    return Err(ToolError::ToolFailed {
+                        name: self.name().to_string(),
+                        code: output.status.code().unwrap_or(-1),
+                        message: String::from_utf8_lossy(&output.stderr).to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(LintResult {
+            success: issues.is_empty(),
+            ..tool_result(self, issues, stdout, stderr, start)
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("clang-format")
+    }
+
+    fn version(&self) -> Option<String> {
+        super::llvm_tool_version("clang-format")
+    }
+}
@@ -0,0 +1,298 @@
+//! clang-tidy linter and fixer for C and C++
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Diagnostics: `file:line:col: warning: message [check-name]`
+static DIAGNOSTIC: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(.+?):(\d+):(\d+): (warning|error|note): (.*?)(?: \[([^\[\]]+)\])?$").unwrap()
+});
+
+/// clang-tidy linter for C and C++
+pub struct ClangTidy {
+    base: ToolBase,
+}
+
+/// clang-tidy with `--fix`, run by `siren fix`
+pub struct ClangTidyFixer {
+    base: ToolBase,
+}
+
+impl Default for ClangTidy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for ClangTidyFixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClangTidy {
+    /// Create a new clang-tidy linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "clang-tidy".to_string(),
+                description: "Clang-based linter for C and C++".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::C, Language::Cpp],
+            },
+        }
+    }
+
+    /// Parse the output of `clang-tidy`
+    ///
+    /// The check name becomes the issue code. Notes are not reported on their
+    /// own, but a `FIX-IT applied` note marks the warning before it as fixed.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let mut issues: Vec<LintIssue> = Vec::new();
+
+        for capture in output.lines().filter_map(|line| DIAGNOSTIC.captures(line)) {
+            let message = &capture[5];
+
+            if &capture[4] == "note" {
+                if message.starts_with("FIX-IT applied") {
+                    if let Some(issue) = issues.last_mut() {
+                        issue.fix_available = true;
+                    }
+                }
+                continue;
+            }
+
+            issues.push(LintIssue {
+                severity: if &capture[4] == "error" {
+                    IssueSeverity::Error
+                } else {
+                    IssueSeverity::Warning
+                },
+                message: message.to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: capture[3].parse().ok(),
+                code: capture.get(6).map(|check| check.as_str().to_string()),
+                fix_available: false,
+            });
+        }
+
+        issues
+    }
+}
+
+impl ClangTidyFixer {
+    /// Create a new clang-tidy fixer
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "clang-tidy-fix".to_string(),
+                description: "Applies clang-tidy suggested fixes".to_string(),
+                tool_type: ToolType::Fixer,
+                languages: vec![Language::C, Language::Cpp],
+            },
+        }
+    }
+}
+
+/// Whether a file is compiled on its own, as opposed to a header
+fn is_translation_unit(file_path: &Path) -> bool {
+    file_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| matches!(ext, "c" | "cc" | "cpp" | "cxx" | "c++"))
+}
+
+/// Run clang-tidy on the given paths, applying fixes when `fix` is set
+///
+/// Sources are grouped by the compilation database that covers them and
+/// clang-tidy runs once per database with `-p`. Sources without one are still
+/// checked, with a bare `--` so clang-tidy uses default compiler flags instead
+/// of complaining. Headers are only checked through the sources including
+/// them. Returns the combined stdout and stderr.
+fn run_clang_tidy(
+    name: &str,
+    files: &[PathBuf],
+    config: &ModelsToolConfig,
+    fix: bool,
+) -> Result<Option<(String, String)>, ToolError> {
+    let mut databases: BTreeMap<Option<PathBuf>, Vec<PathBuf>> = BTreeMap::new();
+    for source in super::c_family_files(files)
+        .into_iter()
+        .filter(|file| is_translation_unit(file))
+    {
+        databases
+            .entry(super::compilation_database(&source))
+            .or_default()
+            .push(source);
+    }
+    if databases.is_empty() {
+        return Ok(None);
+    }
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+
+    for (database, sources) in databases {
+        let mut command = Command::new("clang-tidy");
+        command.arg("--quiet");
+
+        if let Some(database) = &database {
+            command.arg("-p").arg(database);
+        }
+        if fix {
+            command.arg("--fix");
+        }
+
+        for arg in &config.extra_args {
+            command.arg(arg);
+        }
+        command.args(&sources);
+        if database.is_none() {
+            command.arg("--");
+        }
+
+        utils::log_command(&command);
+
+        let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+            name: name.to_string(),
+            message: format!("Failed to execute clang-tidy: {}", e),
+        })?;
+
+        let run_stdout = String::from_utf8_lossy(&output.stdout);
+        let run_stderr = String::from_utf8_lossy(&output.stderr);
+
+        // clang-tidy also exits non-zero when a source fails to compile, which
+        // shows up as `clang-diagnostic-error` issues; only fail without any
+        if !output.status.success() && !run_stdout.lines().any(|line| DIAGNOSTIC.is_match(line)) {
+            return Err(ToolError::ToolFailed {
+                name: name.to_string(),
+                code: output.status.code().unwrap_or(-1),
+                message: run_stderr.to_string(),
+            });
+        }
+
+        stdout.push_str(&run_stdout);
+        stderr.push_str(&run_stderr);
+    }
+
+    Ok(Some((stdout, stderr)))
+}
+
+impl LintTool for ClangTidy {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        is_translation_unit(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // With auto-fix on, report only what was left unfixed
+        let output = run_clang_tidy(self.name(), files, config, config.auto_fix)?;
+        let issues = output
+            .as_ref()
+            .map(|(stdout, _)| self.parse_output(stdout))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|issue| !config.auto_fix || !issue.fix_available)
+            .collect();
+
+        let (stdout, stderr) = output.unwrap_or_default();
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("clang-tidy")
+    }
+
+    fn version(&self) -> Option<String> {
+        super::llvm_tool_version("clang-tidy")
+    }
+
+    fn cross_file(&self) -> bool {
+        // Translation units see the headers they include
+        true
+    }
+}
+
+impl LintTool for ClangTidyFixer {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        is_translation_unit(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Report the warnings that were fixed
+        let output = run_clang_tidy(self.name(), files, config, true)?;
+        let issues = output
+            .as_ref()
+            .map(|(stdout, _)| ClangTidy::new().parse_output(stdout))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|issue| issue.fix_available)
+            .collect();
+
+        let (stdout, stderr) = output.unwrap_or_default();
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("clang-tidy")
+    }
+
+    fn version(&self) -> Option<String> {
+        super::llvm_tool_version("clang-tidy")
+    }
+}
@@ -0,0 +1,66 @@
+//! C and C++ tools
+
+use std::path::{Path, PathBuf};
+
+use crate::utils;
+
+mod clang_format;
+mod clang_tidy;
+
+pub use clang_format::ClangFormat;
+pub use clang_tidy::{ClangTidy, ClangTidyFixer};
+
+/// Whether a file is C or C++ source or a header
+fn is_c_family(file_path: &Path) -> bool {
+    file_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| {
+            matches!(
+                ext,
+                "c" | "h" | "cc" | "cpp" | "cxx" | "c++" | "hh" | "hpp" | "hxx" | "h++" | "ipp"
+            )
+        })
+}
+
+/// The C and C++ files among `files`, with directories expanded
+///
+/// clang-format and clang-tidy only accept files, so directories are walked
+/// here, skipping whatever .gitignore excludes (build trees in particular).
+fn c_family_files(files: &[PathBuf]) -> Vec<PathBuf> {
+    utils::walk_files(files, is_c_family)
+}
+
+/// Find the compilation database for a path
+///
+/// Walks up from the path and returns the first directory holding a
+/// `compile_commands.json`, looking in each directory itself and in its
+/// `build/` subdirectory, where CMake and Meson usually write it.
+pub fn compilation_database(path: &Path) -> Option<PathBuf> {
+    let has_database = |dir: &Path| dir.join("compile_commands.json").is_file();
+    let dir = utils::find_ancestor_with(path, |dir| {
+        has_database(dir) || has_database(&dir.join("build"))
+    })?;
+
+    Some(if has_database(&dir) {
+        dir
+    } else {
+        dir.join("build")
+    })
+}
+
+/// Version of an LLVM tool, from a line like
+/// `Ubuntu clang-format version 17.0.6 (1ubuntu1)` or `LLVM version 17.0.6`
+fn llvm_tool_version(executable: &str) -> Option<String> {
+    let output = std::process::Command::new(executable)
+        .arg("--version")
+        .output()
+        .ok()?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| {
+            let (_, rest) = line.split_once("version ")?;
+            rest.split_whitespace().next().map(String::from)
+        })
+}
@@ -0,0 +1,309 @@
+//! dotnet format formatter for C#
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// File name `dotnet format` uses when `--report` is given a directory
+const REPORT_FILE: &str = "format-report.json";
+
+/// Exit code of `--verify-no-changes` when files would be changed
+const CHANGES_FOUND: i32 = 2;
+
+/// dotnet format formatter for C#
+pub struct DotnetFormat {
+    base: ToolBase,
+}
+
+impl Default for DotnetFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// dotnet format in verify mode, run by `siren check`
+pub struct DotnetFormatCheck {
+    base: ToolBase,
+}
+
+impl Default for DotnetFormatCheck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DotnetFormat {
+    /// Create a new dotnet format formatter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "dotnet-format".to_string(),
+                description: "Formats C# code and applies .editorconfig style rules".to_string(),
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::CSharp],
+            },
+        }
+    }
+
+    /// Parse the JSON report written by `dotnet format --report`
+    ///
+    /// The report lists every file with the changes made (or needed) in it.
+    /// Whitespace, import ordering and IDE style fixes are style issues;
+    /// anything else comes from an analyzer and is a warning.
+    pub fn parse_output(&self, report: &str) -> Vec<LintIssue> {
+        let Ok(Value::Array(documents)) = serde_json::from_str::<Value>(report) else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for document in &documents {
+            let file = document
+                .get("FilePath")
+                .and_then(Value::as_str)
+                .map(PathBuf::from);
+
+            for change in document
+                .get("FileChanges")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let code = change.get("DiagnosticId").and_then(Value::as_str);
+                let severity = match code {
+                    Some("WHITESPACE" | "FINALNEWLINE" | "ENDOFLINE" | "CHARSET" | "IMPORTS") => {
+                        IssueSeverity::Style
+                    }
+                    Some(id) if id.starts_with("IDE") => IssueSeverity::Style,
+                    _ => IssueSeverity::Warning,
+                };
+
+                issues.push(LintIssue {
+                    severity,
+                    message: change
+                        .get("FormatDescription")
+                        .and_then(Value::as_str)
+                        .unwrap_or("File needs formatting")
+                        .to_string(),
+                    file: file.clone(),
+                    line: change
+                        .get("LineNumber")
+                        .and_then(Value::as_u64)
+                        .map(|n| n as usize),
+                    column: change
+                        .get("CharNumber")
+                        .and_then(Value::as_u64)
+                        .map(|n| n as usize),
+                    code: code.map(String::from),
+                    fix_available: true,
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+impl DotnetFormatCheck {
+    /// Create a new dotnet format checker
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "dotnet-format-check".to_string(),
+                description: "Reports C# code that dotnet format would change".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::CSharp],
+            },
+        }
+    }
+}
+
+/// Run dotnet format over each project, rewriting files when `fix` is set
+fn run_dotnet_format(
+    name: &str,
+    files: &[PathBuf],
+    config: &ModelsToolConfig,
+    fix: bool,
+) -> Result<(Vec<LintIssue>, String, String), ToolError> {
+    let mut issues = Vec::new();
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+
+    let report_dir = std::env::temp_dir().join(format!("siren-{}-{}", name, std::process::id()));
+
+    for (root, targets) in super::csharp_paths_by_project(files) {
+        let mut command = Command::new("dotnet");
+        command.current_dir(&root);
+        command.arg("format");
+
+        // Format the project's solution (or project) so its analyzers and
+        // .editorconfig apply; loose files only get whitespace fixes
+        match super::workspace_file(&root) {
+            Some(workspace) => {
+                command.arg(workspace);
+            }
+            None => {
+                command.args(["whitespace", ".", "--folder"]);
+            }
+        }
+
+        if !fix {
+            command.arg("--verify-no-changes");
+        }
+        command.arg("--report").arg(&report_dir);
+
+        for arg in &config.extra_args {
+            command.arg(arg);
+        }
+
+        // Limit the run to our paths unless the whole project was asked for
+        let base = root.canonicalize().unwrap_or_else(|_| root.clone());
+        let includes: Vec<&Path> = targets
+            .iter()
+            .map(|target| target.strip_prefix(&base).unwrap_or(target))
+            .collect();
+        if !includes.iter().any(|path| path.as_os_str().is_empty()) {
+            command.arg("--include").args(&includes);
+        }
+
+        // Don't pick up the report of the previous project
+        let _ = std::fs::remove_file(report_dir.join(REPORT_FILE));
+        let _ = std::fs::create_dir_all(&report_dir);
+
+        utils::log_command(&command);
+
+        let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+            name: name.to_string(),
+            message: format!("Failed to execute dotnet format: {}", e),
+        })?;
+
+        let project_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let project_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+        let code = output.status.code().unwrap_or(-1);
+        if code != 0 && code != CHANGES_FOUND {
+            let _ = std::fs::remove_dir_all(&report_dir);
+            return Err(ToolError::ToolFailed {
+                name: name.to_string(),
+                code,
+                message: project_stderr,
+            });
+        }
+
+        if let Ok(report) = std::fs::read_to_string(report_dir.join(REPORT_FILE)) {
+            issues.extend(DotnetFormat::new().parse_output(&report));
+        }
+        stdout.push_str(&project_stdout);
+        stderr.push_str(&project_stderr);
+    }
+
+    let _ = std::fs::remove_dir_all(&report_dir);
+
+    Ok((issues, stdout, stderr))
+}
+
+impl LintTool for DotnetFormat {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_csharp_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Rewrite files unless only checking
+        let (issues, stdout, stderr) =
+            run_dotnet_format(self.name(), files, config, !config.check)?;
+
+        Ok(LintResult {
+            success: issues.is_empty(),
+            ..tool_result(self, issues, stdout, stderr, start)
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        // dotnet format ships with the .NET 6+ SDK
+        utils::command_exists("dotnet")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "8.0.100+1f6ba6c2e8cad6ad27bbd4e1ffb2fa0ccd2dc1a5"
+        let output = utils::get_command_version("dotnet", &["format", "--version"])?;
+        output
+            .split(['+', '-'])
+            .next()
+            .map(|version| version.trim().to_string())
+    }
+}
+
+impl LintTool for DotnetFormatCheck {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_csharp_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Only report what would change
+        let (issues, stdout, stderr) = run_dotnet_format(self.name(), files, config, false)?;
+
+        Ok(LintResult {
+            success: issues.is_empty(),
+            ..tool_result(self, issues, stdout, stderr, start)
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        DotnetFormat::new().is_available()
+    }
+
+    fn version(&self) -> Option<String> {
+        DotnetFormat::new().version()
+    }
+}
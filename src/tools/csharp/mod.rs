@@ -0,0 +1,64 @@
+//! C#-specific tools
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::utils;
+
+mod dotnet_format;
+
+pub use dotnet_format::{DotnetFormat, DotnetFormatCheck};
+
+/// Whether a file is C# source
+fn is_csharp_file(file_path: &Path) -> bool {
+    file_path.extension().is_some_and(|ext| ext == "cs")
+}
+
+/// The solution or project file in a directory, preferring a solution
+///
+/// `dotnet format` refuses to guess when a directory holds several, so the
+/// first one by name is picked.
+fn workspace_file(dir: &Path) -> Option<PathBuf> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    entries.sort();
+
+    ["sln", "csproj"].iter().find_map(|extension| {
+        entries
+            .iter()
+            .find(|path| path.extension().is_some_and(|ext| ext == *extension))
+            .cloned()
+    })
+}
+
+/// Find the .NET project a path belongs to by walking up to the nearest
+/// directory with a `*.sln` or `*.csproj`
+pub fn project_root(path: &Path) -> Option<PathBuf> {
+    utils::find_ancestor_with(path, |dir| workspace_file(dir).is_some())
+}
+
+/// Group C# files and directories by .NET project
+///
+/// Paths are made absolute since `dotnet format` runs from inside each
+/// project. Paths outside of any project are grouped under the current
+/// directory.
+fn csharp_paths_by_project(files: &[PathBuf]) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+    let mut projects: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+
+    for path in files
+        .iter()
+        .filter(|path| path.is_dir() || is_csharp_file(path))
+    {
+        let Ok(absolute) = path.canonicalize() else {
+            continue;
+        };
+        let root = project_root(&absolute).unwrap_or_else(|| PathBuf::from("."));
+        projects.entry(root).or_default().push(absolute);
+    }
+
+    projects
+}
@@ -0,0 +1,16 @@
+//! CSS-specific tools
+
+use std::path::Path;
+
+mod stylelint;
+
+pub use stylelint::{Stylelint, StylelintFixer};
+
+/// Whether a file is a stylesheet
+fn is_stylesheet(file_path: &Path) -> bool {
+    if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
+        matches!(ext, "css" | "scss" | "sass" | "less")
+    } else {
+        false
+    }
+}
@@ -0,0 +1,307 @@
+//! Stylelint linter and fixer for CSS, SCSS, Sass and Less
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::javascript::{node_command, node_tool_available};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Stylelint linter for stylesheets
+pub struct Stylelint {
+    base: ToolBase,
+}
+
+/// Stylelint autofix, run by `siren fix`
+pub struct StylelintFixer {
+    base: ToolBase,
+}
+
+impl Default for Stylelint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for StylelintFixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stylelint {
+    /// Create a new Stylelint linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "stylelint".to_string(),
+                description: "Linter for CSS, SCSS, Sass and Less".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Css],
+            },
+        }
+    }
+
+    /// Parse the output of `stylelint --formatter json`
+    ///
+    /// Each checked file reports its `warnings`, whose text ends with the
+    /// rule name in parentheses; that suffix becomes the issue code instead.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let Ok(json) = serde_json::from_str::<Value>(output) else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for file in json.as_array().into_iter().flatten() {
+            let path = file
+                .get("source")
+                .and_then(Value::as_str)
+                .map(PathBuf::from);
+
+            for warning in file
+                .get("warnings")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let severity = match warning.get("severity").and_then(Value::as_str) {
+                    Some("error") => IssueSeverity::Error,
+                    _ => IssueSeverity::Warning,
+                };
+
+                let rule = warning.get("rule").and_then(Value::as_str);
+                let text = warning
+                    .get("text")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Unknown issue");
+                let message = rule
+                    .and_then(|rule| text.strip_suffix(&format!(" ({})", rule)))
+                    .unwrap_or(text);
+
+                let position =
+                    |key: &str| warning.get(key).and_then(Value::as_u64).map(|n| n as usize);
+
+                issues.push(LintIssue {
+                    severity,
+                    message: message.to_string(),
+                    file: path.clone(),
+                    line: position("line"),
+                    column: position("column"),
+                    code: rule.map(String::from),
+                    fix_available: warning.get("fix").is_some(),
+                });
+            }
+
+            // Config problems are reported once per file rather than as warnings
+            for invalid in file
+                .get("invalidOptionWarnings")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                issues.push(LintIssue {
+                    severity: IssueSeverity::Error,
+                    message: invalid
+                        .get("text")
+                        .and_then(Value::as_str)
+                        .unwrap_or("Invalid option")
+                        .to_string(),
+                    file: None,
+                    line: None,
+                    column: None,
+                    code: None,
+                    fix_available: false,
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+impl StylelintFixer {
+    /// Create a new Stylelint fixer
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "stylelint-fix".to_string(),
+                description: "Automatic fixer for Stylelint problems".to_string(),
+                tool_type: ToolType::Fixer,
+                languages: vec![Language::Css],
+            },
+        }
+    }
+}
+
+/// Run Stylelint on the given paths, fixing what it can when `fix` is set
+///
+/// Returns the issues that remain along with stdout and stderr.
+fn run_stylelint(
+    name: &str,
+    files: &[PathBuf],
+    config: &ModelsToolConfig,
+    fix: bool,
+) -> Result<(Vec<LintIssue>, String, String), ToolError> {
+    // Stylelint takes globs rather than directories
+    let patterns: Vec<String> = files
+        .iter()
+        .filter_map(|path| {
+            if path.is_dir() {
+                Some(format!("{}/**/*.{{css,scss,sass,less}}", path.display()))
+            } else if super::is_stylesheet(path) {
+                Some(path.display().to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if patterns.is_empty() {
+        return Ok((Vec::new(), String::new(), String::new()));
+    }
+
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let mut command = node_command("stylelint", &current_dir);
+    command.args(["--formatter", "json", "--allow-empty-input"]);
+
+    if fix {
+        command.arg("--fix");
+    }
+
+    for arg in &config.extra_args {
+        command.arg(arg);
+    }
+    command.args(&patterns);
+
+    utils::log_command(&command);
+
+    let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+        name: name.to_string(),
+        message: format!("Failed to execute stylelint: {}", e),
+    })?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    // Stylelint 16 writes the report to stderr, older releases to stdout
+    let report = if stdout.trim_start().starts_with('[') {
+        &stdout
+    } else {
+        &stderr
+    };
+    let issues = Stylelint::new().parse_output(report);
+
+    // Exit code 2 only means problems were found; anything else without a
+    // report is a broken configuration or invocation
+    let code = output.status.code().unwrap_or(-1);
+    if code != 0 && code != 2 && issues.is_empty() {
+        return Err(ToolError::ToolFailed {
+            name: name.to_string(),
+            code,
+            message: stderr,
+        });
+    }
+
+    Ok((issues, stdout, stderr))
+}
+
+fn stylelint_version() -> Option<String> {
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let mut command = node_command("stylelint", &current_dir);
+    command.arg("--version");
+    utils::log_command(&command);
+
+    let output = command.output().ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+impl LintTool for Stylelint {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_stylesheet(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let (issues, stdout, stderr) = run_stylelint(self.name(), files, config, config.auto_fix)?;
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        node_tool_available("stylelint")
+    }
+
+    fn version(&self) -> Option<String> {
+        stylelint_version()
+    }
+}
+
+impl LintTool for StylelintFixer {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_stylesheet(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Whatever is still reported after --fix needs a manual change
+        let (issues, stdout, stderr) = run_stylelint(self.name(), files, config, true)?;
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        node_tool_available("stylelint")
+    }
+
+    fn version(&self) -> Option<String> {
+        stylelint_version()
+    }
+}
@@ -0,0 +1,166 @@
+//! hadolint linter for Dockerfiles
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// hadolint linter for Dockerfiles
+pub struct Hadolint {
+    base: ToolBase,
+}
+
+impl Default for Hadolint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hadolint {
+    /// Create a new hadolint linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "hadolint".to_string(),
+                description: "Dockerfile linter".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Docker],
+            },
+        }
+    }
+
+    /// Parse the output of `hadolint --format json`
+    ///
+    /// Codes are hadolint's own `DL3008` rules or, for shell in `RUN`
+    /// instructions, ShellCheck's `SC2086`.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let Ok(json) = serde_json::from_str::<Value>(output) else {
+            return Vec::new();
+        };
+
+        json.as_array()
+            .into_iter()
+            .flatten()
+            .map(|finding| {
+                let number =
+                    |key: &str| finding.get(key).and_then(Value::as_u64).map(|n| n as usize);
+
+                let severity = match finding.get("level").and_then(Value::as_str) {
+                    Some("error") => IssueSeverity::Error,
+                    Some("warning") => IssueSeverity::Warning,
+                    Some("info") => IssueSeverity::Info,
+                    _ => IssueSeverity::Style,
+                };
+
+                LintIssue {
+                    severity,
+                    message: finding
+                        .get("message")
+                        .and_then(Value::as_str)
+                        .unwrap_or("Unknown issue")
+                        .to_string(),
+                    file: finding
+                        .get("file")
+                        .and_then(Value::as_str)
+                        .map(PathBuf::from),
+                    line: number("line"),
+                    column: number("column"),
+                    code: finding
+                        .get("code")
+                        .and_then(Value::as_str)
+                        .map(String::from),
+                    fix_available: false,
+                }
+            })
+            .collect()
+    }
+}
+
+impl LintTool for Hadolint {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_dockerfile(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let dockerfiles = super::dockerfiles(files);
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !dockerfiles.is_empty() {
+            let mut command = Command::new("hadolint");
+
+            // hadolint picks up .hadolint.yaml from the working directory.
+            // Findings are judged by siren's fail level, so hadolint's own
+            // failure threshold is switched off.
+            command.args(["--format", "json", "--no-fail"]);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&dockerfiles);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute hadolint: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            // With --no-fail, any other exit code is a bad config or option
+            if !output.status.success() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: stderr,
+                });
+            }
+
+            issues = self.parse_output(&stdout);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("hadolint")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "Haskell Dockerfile Linter 2.12.0"
+        let output = utils::get_command_version("hadolint", &["--version"])?;
+        output.split_whitespace().last().map(String::from)
+    }
+}
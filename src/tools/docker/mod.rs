@@ -0,0 +1,22 @@
+//! Dockerfile tools
+
+use std::path::{Path, PathBuf};
+
+use crate::models::Language;
+use crate::utils;
+
+mod hadolint;
+
+pub use hadolint::Hadolint;
+
+/// Whether a file is a Dockerfile, going by its name
+fn is_dockerfile(file_path: &Path) -> bool {
+    utils::filename_language(file_path) == Some(Language::Docker)
+}
+
+/// The Dockerfiles among `files`, with directories expanded
+///
+/// hadolint only accepts files, so directories are walked here.
+fn dockerfiles(files: &[PathBuf]) -> Vec<PathBuf> {
+    utils::walk_files(files, is_dockerfile)
+}
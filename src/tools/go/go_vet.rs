@@ -0,0 +1,220 @@
+//! go vet linter for Go
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// A position as reported by go vet: `file.go:line:col`
+static POSITION: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(.+?\.go):(\d+)(?::(\d+))?").unwrap());
+
+/// Build and type errors, which are reported as plain text even with `-json`
+static BUILD_ERROR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^(?:vet: )?([^\s"{}].*?\.go):(\d+):(\d+): (.+)$"#).unwrap());
+
+/// go vet linter for Go
+pub struct GoVet {
+    base: ToolBase,
+}
+
+impl Default for GoVet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GoVet {
+    /// Create a new go vet linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "go-vet".to_string(),
+                description: "Reports suspicious constructs in Go code".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Go],
+            },
+        }
+    }
+
+    /// Parse the output of `go vet -json`
+    ///
+    /// The output is a stream of JSON objects, one per package, each preceded
+    /// by a `# package` comment line:
+    /// `{"pkg": {"analyzer": [{"posn": "file.go:1:2", "message": "..."}]}}`.
+    /// Relative positions are resolved against `root`, the directory go vet
+    /// ran in.
+    pub fn parse_output(&self, output: &str, root: &Path) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+
+        let mut json = String::new();
+        for line in output.lines() {
+            if line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(capture) = BUILD_ERROR.captures(line) {
+                issues.push(LintIssue {
+                    severity: IssueSeverity::Error,
+                    message: capture[4].to_string(),
+                    file: Some(root.join(&capture[1])),
+                    line: capture[2].parse().ok(),
+                    column: capture[3].parse().ok(),
+                    code: None,
+                    fix_available: false,
+                });
+                continue;
+            }
+
+            json.push_str(line);
+            json.push('\n');
+        }
+
+        for packages in serde_json::Deserializer::from_str(&json)
+            .into_iter::<Value>()
+            .map_while(Result::ok)
+        {
+            let Some(packages) = packages.as_object() else {
+                continue;
+            };
+
+            for analyzers in packages.values().filter_map(Value::as_object) {
+                for (analyzer, diagnostics) in analyzers {
+                    // An analyzer that could not run reports `{"error": "..."}`
+                    if let Some(error) = diagnostics.get("error").and_then(Value::as_str) {
+                        issues.push(LintIssue {
+                            severity: IssueSeverity::Error,
+                            message: error.to_string(),
+                            file: None,
+                            line: None,
+                            column: None,
+                            code: Some(analyzer.clone()),
+                            fix_available: false,
+                        });
+                        continue;
+                    }
+
+                    for diagnostic in diagnostics.as_array().into_iter().flatten() {
+                        let message = diagnostic
+                            .get("message")
+                            .and_then(Value::as_str)
+                            .unwrap_or("Unknown issue")
+                            .to_string();
+                        let position = diagnostic
+                            .get("posn")
+                            .and_then(Value::as_str)
+                            .and_then(|posn| POSITION.captures(posn));
+
+                        issues.push(LintIssue {
+                            severity: IssueSeverity::Warning,
+                            message,
+                            file: position.as_ref().map(|p| root.join(&p[1])),
+                            line: position.as_ref().and_then(|p| p[2].parse().ok()),
+                            column: position
+                                .as_ref()
+                                .and_then(|p| p.get(3))
+                                .and_then(|c| c.as_str().parse().ok()),
+                            code: Some(analyzer.clone()),
+                            fix_available: diagnostic.get("suggested_fixes").is_some(),
+                        });
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+impl LintTool for GoVet {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path.extension().is_some_and(|ext| ext == "go")
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        // go vet checks packages, one module at a time
+        for (root, packages) in super::package_patterns(files) {
+            let mut command = Command::new("go");
+            command.current_dir(&root);
+            command.args(["vet", "-json"]);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&packages);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute go vet: {}", e),
+            })?;
+
+            let module_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let module_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            let module_issues = self.parse_output(&module_stderr, &root);
+
+            // A failure without anything to show means go vet itself broke
+            if !output.status.success() && module_issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: module_stderr,
+                });
+            }
+
+            issues.extend(module_issues);
+            stdout.push_str(&module_stdout);
+            stderr.push_str(&module_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("go")
+    }
+
+    fn version(&self) -> Option<String> {
+        super::go_version()
+    }
+
+    fn cross_file(&self) -> bool {
+        // Packages are analysed as a whole
+        true
+    }
+}
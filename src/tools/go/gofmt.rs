@@ -0,0 +1,159 @@
+//! gofmt formatter for Go
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Syntax errors: `file.go:line:col: message`
+static SYNTAX_ERROR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.+?\.go):(\d+):(\d+): (.+)$").unwrap());
+
+/// gofmt formatter for Go
+pub struct Gofmt {
+    base: ToolBase,
+}
+
+impl Default for Gofmt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Gofmt {
+    /// Create a new gofmt formatter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "gofmt".to_string(),
+                description: "The standard Go code formatter".to_string(),
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Go],
+            },
+        }
+    }
+
+    /// Parse the output of `gofmt -l`
+    ///
+    /// Every file on stdout needs formatting; syntax errors that kept gofmt
+    /// from reading a file are reported on stderr.
+    pub fn parse_output(&self, stdout: &str, stderr: &str) -> Vec<LintIssue> {
+        let mut issues: Vec<LintIssue> = stdout
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|file| LintIssue {
+                severity: IssueSeverity::Style,
+                message: "File needs formatting".to_string(),
+                file: Some(PathBuf::from(file)),
+                line: None,
+                column: None,
+                code: None,
+                fix_available: true,
+            })
+            .collect();
+
+        for capture in stderr
+            .lines()
+            .filter_map(|line| SYNTAX_ERROR.captures(line))
+        {
+            issues.push(LintIssue {
+                severity: IssueSeverity::Error,
+                message: capture[4].to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: capture[3].parse().ok(),
+                code: None,
+                fix_available: false,
+            });
+        }
+
+        issues
+    }
+}
+
+impl LintTool for Gofmt {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path.extension().is_some_and(|ext| ext == "go")
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // gofmt would walk vendored and ignored trees too, so directories are
+        // expanded here
+        let paths = utils::walk_files(files, |path| self.can_handle(path));
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !paths.is_empty() {
+            let mut command = Command::new("gofmt");
+
+            // List files that differ, and rewrite them unless only checking
+            command.arg("-l");
+            if !config.check {
+                command.arg("-w");
+            }
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&paths);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute gofmt: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            issues = self.parse_output(&stdout, &stderr);
+        }
+
+        Ok(LintResult {
+            success: issues.is_empty(),
+            ..tool_result(self, issues, stdout, stderr, start)
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("gofmt")
+    }
+
+    fn version(&self) -> Option<String> {
+        // gofmt has no version flag of its own; it ships with the toolchain
+        super::go_version()
+    }
+}
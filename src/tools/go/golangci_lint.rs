@@ -0,0 +1,188 @@
+//! golangci-lint linter for Go
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// golangci-lint linter for Go
+pub struct GolangciLint {
+    base: ToolBase,
+}
+
+impl Default for GolangciLint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GolangciLint {
+    /// Create a new golangci-lint linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "golangci-lint".to_string(),
+                description: "Fast Go linters runner".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Go],
+            },
+        }
+    }
+
+    /// Parse the output of `golangci-lint run --out-format json`
+    ///
+    /// File names are relative to `root`, the directory golangci-lint ran in.
+    /// The name of the linter that reported an issue becomes its code.
+    pub fn parse_output(&self, output: &str, root: &Path) -> Vec<LintIssue> {
+        let Ok(json) = serde_json::from_str::<Value>(output) else {
+            return Vec::new();
+        };
+
+        json.get("Issues")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|issue| {
+                let position = issue.get("Pos");
+                let position_number = |key: &str| {
+                    position
+                        .and_then(|pos| pos.get(key))
+                        .and_then(Value::as_u64)
+                        .filter(|n| *n > 0)
+                        .map(|n| n as usize)
+                };
+
+                let severity = match issue.get("Severity").and_then(Value::as_str) {
+                    Some("error") => IssueSeverity::Error,
+                    Some("info") => IssueSeverity::Info,
+                    _ => IssueSeverity::Warning,
+                };
+
+                LintIssue {
+                    severity,
+                    message: issue
+                        .get("Text")
+                        .and_then(Value::as_str)
+                        .unwrap_or("Unknown issue")
+                        .to_string(),
+                    file: position
+                        .and_then(|pos| pos.get("Filename"))
+                        .and_then(Value::as_str)
+                        .map(|file| root.join(file)),
+                    line: position_number("Line"),
+                    column: position_number("Column"),
+                    code: issue
+                        .get("FromLinter")
+                        .and_then(Value::as_str)
+                        .map(String::from),
+                    fix_available: issue.get("Replacement").is_some_and(|r| !r.is_null())
+                        || issue.get("SuggestedFixes").is_some_and(|f| !f.is_null()),
+                }
+            })
+            .collect()
+    }
+}
+
+impl LintTool for GolangciLint {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path.extension().is_some_and(|ext| ext == "go")
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        // golangci-lint loads whole packages and picks up .golangci.yml from
+        // the module, so it runs once per module root
+        for (root, packages) in super::package_patterns(files) {
+            let mut command = Command::new("golangci-lint");
+            command.current_dir(&root);
+            command.args(["run", "--out-format", "json"]);
+
+            if config.auto_fix {
+                command.arg("--fix");
+            }
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&packages);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute golangci-lint: {}", e),
+            })?;
+
+            let module_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let module_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            // Exit code 1 only means issues were found; anything else without
+            // a report is a broken configuration or build
+            let code = output.status.code().unwrap_or(-1);
+            if code != 0 && code != 1 && module_stdout.trim().is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code,
+                    message: module_stderr,
+                });
+            }
+
+            issues.extend(self.parse_output(&module_stdout, &root));
+            stdout.push_str(&module_stdout);
+            stderr.push_str(&module_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("golangci-lint")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "golangci-lint has version 1.55.2 built with go1.21.3 from ..."
+        let output = utils::get_command_version("golangci-lint", &["--version"])?;
+        let mut words = output.split_whitespace();
+        words
+            .position(|word| word == "version")
+            .and_then(|_| words.next())
+            .map(String::from)
+            .or(Some(output))
+    }
+
+    fn cross_file(&self) -> bool {
+        // Packages are analysed as a whole
+        true
+    }
+}
@@ -0,0 +1,77 @@
+//! Go-specific tools
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::utils;
+
+mod go_vet;
+mod gofmt;
+mod golangci_lint;
+
+pub use go_vet::GoVet;
+pub use gofmt::Gofmt;
+pub use golangci_lint::GolangciLint;
+
+/// Whether a path is something the Go tools should look at
+///
+/// Directories are kept so `siren check ./services` lints whole packages.
+fn is_go_path(path: &Path) -> bool {
+    path.is_dir() || path.extension().is_some_and(|ext| ext == "go")
+}
+
+/// Find the module a path belongs to by walking up to the nearest `go.mod`
+pub fn module_root(path: &Path) -> Option<PathBuf> {
+    utils::find_ancestor_with(path, |dir| dir.join("go.mod").is_file())
+}
+
+/// Turn files and directories into package patterns, grouped by module root
+///
+/// `go vet` and `golangci-lint` work on packages rather than files and must be
+/// run from inside the module, so a file becomes its directory's package and a
+/// directory becomes everything below it (`./dir/...`). Paths outside of any
+/// module are dropped.
+pub fn package_patterns(paths: &[PathBuf]) -> BTreeMap<PathBuf, Vec<String>> {
+    let mut modules: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+
+    for path in paths.iter().filter(|path| is_go_path(path)) {
+        let Some(root) = module_root(path) else {
+            continue;
+        };
+        let Ok(absolute) = path.canonicalize() else {
+            continue;
+        };
+
+        let (dir, recursive) = if absolute.is_dir() {
+            (absolute.as_path(), true)
+        } else {
+            match absolute.parent() {
+                Some(parent) => (parent, false),
+                None => continue,
+            }
+        };
+
+        let relative = dir.strip_prefix(&root).unwrap_or(dir);
+        let mut pattern = String::from(".");
+        for component in relative.components() {
+            pattern.push('/');
+            pattern.push_str(&component.as_os_str().to_string_lossy());
+        }
+        if recursive {
+            pattern.push_str("/...");
+        }
+
+        let patterns = modules.entry(root).or_default();
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+
+    modules
+}
+
+/// Version of the Go toolchain, e.g. `go1.22.1`
+fn go_version() -> Option<String> {
+    let output = crate::utils::get_command_version("go", &["version"])?;
+    output.split_whitespace().nth(2).map(String::from)
+}
@@ -0,0 +1,181 @@
+//! Checkstyle linter for Java
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Where Maven and Gradle projects usually keep their Checkstyle configuration
+const CHECKSTYLE_CONFIGS: &[&str] = &["checkstyle.xml", "config/checkstyle/checkstyle.xml"];
+
+/// Configuration bundled with Checkstyle, used when the project has none
+const BUNDLED_CONFIG: &str = "/google_checks.xml";
+
+/// Checkstyle linter for Java
+pub struct Checkstyle {
+    base: ToolBase,
+}
+
+impl Default for Checkstyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checkstyle {
+    /// Create a new Checkstyle linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "checkstyle".to_string(),
+                description: "Checks Java code against a coding standard".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Java],
+            },
+        }
+    }
+
+    /// Parse the output of `checkstyle -f xml`
+    ///
+    /// Codes are the check's short name (`MethodName` for
+    /// `com.puppycrawl.tools.checkstyle.checks.naming.MethodNameCheck`), as used
+    /// in Checkstyle configurations and suppressions.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let Some(start) = output.find("<?xml").or_else(|| output.find("<checkstyle")) else {
+            return Vec::new();
+        };
+        let Ok(document) = roxmltree::Document::parse(&output[start..]) else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for file in document
+            .root_element()
+            .children()
+            .filter(|node| node.has_tag_name("file"))
+        {
+            let path = file.attribute("name").map(PathBuf::from);
+
+            for error in file.children().filter(|node| node.has_tag_name("error")) {
+                let severity = match error.attribute("severity") {
+                    Some("error") => IssueSeverity::Error,
+                    Some("warning") => IssueSeverity::Warning,
+                    _ => IssueSeverity::Info,
+                };
+
+                let code = error.attribute("source").map(|source| {
+                    let check = source.rsplit('.').next().unwrap_or(source);
+                    check.strip_suffix("Check").unwrap_or(check).to_string()
+                });
+
+                issues.push(LintIssue {
+                    severity,
+                    message: error
+                        .attribute("message")
+                        .unwrap_or("Unknown issue")
+                        .to_string(),
+                    file: path.clone(),
+                    line: super::number_attribute(&error, "line"),
+                    column: super::number_attribute(&error, "column"),
+                    code,
+                    fix_available: false,
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+impl LintTool for Checkstyle {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_java_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        // Each module can have its own configuration
+        for (module, sources) in super::java_files_by_module(files) {
+            let checkstyle_config = super::find_config(&module, CHECKSTYLE_CONFIGS)
+                .unwrap_or_else(|| PathBuf::from(BUNDLED_CONFIG));
+
+            let mut command = Command::new("checkstyle");
+            command.current_dir(&module);
+            command.args(["-f", "xml", "-c"]).arg(&checkstyle_config);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&sources);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute checkstyle: {}", e),
+            })?;
+
+            let run_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let run_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            // The exit code is the number of errors found, so only a missing
+            // report means Checkstyle itself failed
+            if !output.status.success() && !run_stdout.contains("<checkstyle") {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: run_stderr,
+                });
+            }
+
+            issues.extend(self.parse_output(&run_stdout));
+            stdout.push_str(&run_stdout);
+            stderr.push_str(&run_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("checkstyle")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "Checkstyle version: 10.12.5"
+        let output = utils::get_command_version("checkstyle", &["--version"])?;
+        output
+            .rsplit(' ')
+            .next()
+            .map(|version| version.trim().to_string())
+    }
+}
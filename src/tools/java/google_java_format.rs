@@ -0,0 +1,192 @@
+//! google-java-format formatter for Java
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Syntax errors: `File.java:line:col: error: message`
+static SYNTAX_ERROR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.+?\.java):(\d+):(\d+): error: (.+)$").unwrap());
+
+/// google-java-format formatter for Java
+pub struct GoogleJavaFormat {
+    base: ToolBase,
+}
+
+impl Default for GoogleJavaFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GoogleJavaFormat {
+    /// Create a new google-java-format formatter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "google-java-format".to_string(),
+                description: "Formats Java code to comply with Google Java Style".to_string(),
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Java],
+            },
+        }
+    }
+
+    /// Parse the output of `google-java-format --dry-run`
+    ///
+    /// Every file on stdout needs formatting; syntax errors that kept the
+    /// formatter from parsing a file are reported on stderr.
+    pub fn parse_output(&self, stdout: &str, stderr: &str) -> Vec<LintIssue> {
+        let mut issues: Vec<LintIssue> = stdout
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|file| LintIssue {
+                severity: IssueSeverity::Style,
+                message: "File needs formatting".to_string(),
+                file: Some(PathBuf::from(file)),
+                line: None,
+                column: None,
+                code: None,
+                fix_available: true,
+            })
+            .collect();
+
+        for capture in stderr
+            .lines()
+            .filter_map(|line| SYNTAX_ERROR.captures(line))
+        {
+            issues.push(LintIssue {
+                severity: IssueSeverity::Error,
+                message: capture[4].to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: capture[3].parse().ok(),
+                code: None,
+                fix_available: false,
+            });
+        }
+
+        issues
+    }
+}
+
+impl LintTool for GoogleJavaFormat {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_java_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let sources = super::java_files(files);
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !sources.is_empty() {
+            // List files that differ first; `--replace` alone prints nothing
+            let mut command = Command::new("google-java-format");
+            command.arg("--dry-run");
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&sources);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute google-java-format: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            issues = self.parse_output(&stdout, &stderr);
+
+            // Rewrite the files that need it, unless only checking
+            let unformatted: Vec<&PathBuf> = issues
+                .iter()
+                .filter(|issue| issue.fix_available)
+                .filter_map(|issue| issue.file.as_ref())
+                .collect();
+            if !config.check && !unformatted.is_empty() {
+                let mut command = Command::new("google-java-format");
+                command.arg("--replace");
+                for arg in &config.extra_args {
+                    command.arg(arg);
+                }
+                command.args(&unformatted);
+
+                utils::log_command(&command);
+
+                let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                    name: self.name().to_string(),
+                    message: format!("Failed to execute google-java-format: {}", e),
+                })?;
+
+                if !output.status.success() {
+                    return Err(ToolError::ToolFailed {
+                        name: self.name().to_string(),
+                        code: output.status.code().unwrap_or(-1),
+                        message: String::from_utf8_lossy(&output.stderr).to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(LintResult {
+            success: issues.is_empty(),
+            ..tool_result(self, issues, stdout, stderr, start)
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("google-java-format")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "google-java-format: Version 1.19.2", printed on stderr
+        let output = Command::new("google-java-format")
+            .arg("--version")
+            .output()
+            .ok()?;
+        let text = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        text.lines()
+            .find_map(|line| line.split_once("Version "))
+            .map(|(_, version)| version.trim().to_string())
+    }
+}
@@ -0,0 +1,74 @@
+//! Java-specific tools
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::utils;
+
+mod checkstyle;
+mod google_java_format;
+mod pmd;
+
+pub use checkstyle::Checkstyle;
+pub use google_java_format::GoogleJavaFormat;
+pub use pmd::Pmd;
+
+/// Build files that mark the root of a Maven or Gradle module
+const BUILD_FILES: &[&str] = &["pom.xml", "build.gradle", "build.gradle.kts"];
+
+/// Whether a file is Java source
+fn is_java_file(file_path: &Path) -> bool {
+    file_path.extension().is_some_and(|ext| ext == "java")
+}
+
+/// Find the Maven or Gradle module a path belongs to
+///
+/// Walks up to the nearest directory with a `pom.xml`, `build.gradle` or
+/// `build.gradle.kts`, so in a multi-module build each module is its own root.
+pub fn module_root(path: &Path) -> Option<PathBuf> {
+    utils::find_ancestor_with(path, |dir| {
+        BUILD_FILES.iter().any(|file| dir.join(file).is_file())
+    })
+}
+
+/// The Java files among `files`, with directories expanded
+fn java_files(files: &[PathBuf]) -> Vec<PathBuf> {
+    utils::walk_files(files, is_java_file)
+}
+
+/// Group the Java files among `files` by module root
+///
+/// Files are made absolute since the tools run from inside each module.
+/// Files outside of any Maven or Gradle build are grouped under the current
+/// directory.
+fn java_files_by_module(files: &[PathBuf]) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+    let mut modules: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+
+    for file in java_files(files) {
+        let Ok(absolute) = file.canonicalize() else {
+            continue;
+        };
+        let root = module_root(&absolute).unwrap_or_else(|| PathBuf::from("."));
+        modules.entry(root).or_default().push(absolute);
+    }
+
+    modules
+}
+
+/// Find a tool's configuration file for a module
+///
+/// Each candidate is looked up in the module root and then in the directories
+/// above it, since multi-module builds usually share one file at the top.
+fn find_config(module: &Path, candidates: &[&str]) -> Option<PathBuf> {
+    module.ancestors().find_map(|dir| {
+        candidates
+            .iter()
+            .map(|candidate| dir.join(candidate))
+            .find(|path| path.is_file())
+    })
+}
+
+/// Read an integer attribute of an XML report element
+fn number_attribute(node: &roxmltree::Node, name: &str) -> Option<usize> {
+    node.attribute(name).and_then(|value| value.parse().ok())
+}
@@ -0,0 +1,196 @@
+//! PMD static analyzer for Java
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Where Maven and Gradle projects usually keep their PMD ruleset
+const PMD_RULESETS: &[&str] = &[
+    "pmd.xml",
+    "pmd-ruleset.xml",
+    "config/pmd/pmd.xml",
+    "config/pmd/ruleset.xml",
+];
+
+/// PMD static analyzer for Java
+///
+/// PMD is optional: it only runs for modules that have a ruleset, since its
+/// quickstart rules overlap heavily with Checkstyle.
+pub struct Pmd {
+    base: ToolBase,
+}
+
+impl Default for Pmd {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pmd {
+    /// Create a new PMD linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "pmd".to_string(),
+                description: "Source code analyzer for Java".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Java],
+            },
+        }
+    }
+
+    /// Parse the output of `pmd check -f xml`
+    ///
+    /// Priorities 1 and 2 are reported as errors, 3 as warnings and the rest
+    /// as info. Files PMD could not process are reported as errors too.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let Some(start) = output.find("<?xml").or_else(|| output.find("<pmd")) else {
+            return Vec::new();
+        };
+        let Ok(document) = roxmltree::Document::parse(&output[start..]) else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for node in document.root_element().children() {
+            if node.has_tag_name("file") {
+                let path = node.attribute("name").map(PathBuf::from);
+
+                for violation in node
+                    .children()
+                    .filter(|child| child.has_tag_name("violation"))
+                {
+                    let severity = match super::number_attribute(&violation, "priority") {
+                        Some(1 | 2) => IssueSeverity::Error,
+                        Some(3) => IssueSeverity::Warning,
+                        _ => IssueSeverity::Info,
+                    };
+
+                    issues.push(LintIssue {
+                        severity,
+                        message: violation
+                            .text()
+                            .unwrap_or("Unknown issue")
+                            .trim()
+                            .to_string(),
+                        file: path.clone(),
+                        line: super::number_attribute(&violation, "beginline"),
+                        column: super::number_attribute(&violation, "begincolumn"),
+                        code: violation.attribute("rule").map(String::from),
+                        fix_available: false,
+                    });
+                }
+            } else if node.has_tag_name("error") {
+                issues.push(LintIssue {
+                    severity: IssueSeverity::Error,
+                    message: node
+                        .attribute("msg")
+                        .unwrap_or("PMD could not process this file")
+                        .to_string(),
+                    file: node.attribute("filename").map(PathBuf::from),
+                    line: None,
+                    column: None,
+                    code: None,
+                    fix_available: false,
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+impl LintTool for Pmd {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_java_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        for (module, sources) in super::java_files_by_module(files) {
+            let Some(ruleset) = super::find_config(&module, PMD_RULESETS) else {
+                continue;
+            };
+
+            let mut command = Command::new("pmd");
+            command.current_dir(&module);
+            command.args(["check", "-f", "xml", "--no-progress", "--no-cache", "-R"]);
+            command.arg(&ruleset);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            for source in &sources {
+                command.arg("-d").arg(source);
+            }
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute pmd: {}", e),
+            })?;
+
+            let run_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let run_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            // Exit code 4 means violations were found and 5 that some files
+            // could not be processed; both still produce a report
+            let code = output.status.code().unwrap_or(-1);
+            if !matches!(code, 0 | 4 | 5) {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code,
+                    message: run_stderr,
+                });
+            }
+
+            issues.extend(self.parse_output(&run_stdout));
+            stdout.push_str(&run_stdout);
+            stderr.push_str(&run_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("pmd")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "PMD 7.0.0 (...)"
+        let output = utils::get_command_version("pmd", &["--version"])?;
+        output.split_whitespace().nth(1).map(String::from)
+    }
+}
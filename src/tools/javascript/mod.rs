@@ -1,7 +1,33 @@
 //! JavaScript and TypeScript tools
 
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::utils;
+
 mod eslint;
 mod prettier;
+mod tsc;
 
 pub use eslint::ESLint;
 pub use prettier::Prettier;
+pub use tsc::{tsconfig_projects, Tsc};
+
+/// The project's own copy of a Node.js tool from `node_modules/.bin`
+fn local_bin(bin: &str, dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join("node_modules").join(".bin").join(bin))
+        .find(|path| path.is_file())
+}
+
+/// Command for a Node.js tool, preferring the copy installed in the project
+/// at or above `dir` over a global one
+pub(crate) fn node_command(bin: &str, dir: &Path) -> Command {
+    Command::new(local_bin(bin, dir).unwrap_or_else(|| PathBuf::from(bin)))
+}
+
+/// Whether a Node.js tool is installed in the current project or globally
+pub(crate) fn node_tool_available(bin: &str) -> bool {
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    local_bin(bin, &current_dir).is_some() || utils::command_exists(bin)
+}
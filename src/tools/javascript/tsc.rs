@@ -0,0 +1,349 @@
+//! TypeScript compiler type checking
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Diagnostics: `file(line,col): error TS2322: message`
+static DIAGNOSTIC: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(.+?)\((\d+),(\d+)\): (error|warning|message) TS(\d+): (.*)$").unwrap()
+});
+
+/// Diagnostics that do not belong to a file: `error TS5083: message`
+static GLOBAL_DIAGNOSTIC: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(error|warning|message) TS(\d+): (.*)$").unwrap());
+
+/// TypeScript compiler used as a type checker
+pub struct Tsc {
+    base: ToolBase,
+}
+
+impl Default for Tsc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tsc {
+    /// Create a new tsc type checker
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "tsc".to_string(),
+                description: "TypeScript compiler type checking".to_string(),
+                tool_type: ToolType::TypeChecker,
+                languages: vec![Language::TypeScript],
+            },
+        }
+    }
+
+    /// Parse the output of `tsc --noEmit --pretty false`
+    ///
+    /// Messages that span several lines continue on indented lines, which are
+    /// folded into the preceding issue. File names are relative to `root`,
+    /// the directory tsc ran in.
+    pub fn parse_output(&self, output: &str, root: &Path) -> Vec<LintIssue> {
+        let mut issues: Vec<LintIssue> = Vec::new();
+
+        for line in output.lines() {
+            if let Some(capture) = DIAGNOSTIC.captures(line) {
+                issues.push(LintIssue {
+                    severity: severity(&capture[4]),
+                    message: capture[6].to_string(),
+                    file: Some(root.join(&capture[1])),
+                    line: capture[2].parse().ok(),
+                    column: capture[3].parse().ok(),
+                    code: Some(format!("TS{}", &capture[5])),
+                    fix_available: false,
+                });
+            } else if let Some(capture) = GLOBAL_DIAGNOSTIC.captures(line) {
+                issues.push(LintIssue {
+                    severity: severity(&capture[1]),
+                    message: capture[3].to_string(),
+                    file: None,
+                    line: None,
+                    column: None,
+                    code: Some(format!("TS{}", &capture[2])),
+                    fix_available: false,
+                });
+            } else if line.starts_with(' ') && !line.trim().is_empty() {
+                if let Some(issue) = issues.last_mut() {
+                    issue.message.push(' ');
+                    issue.message.push_str(line.trim());
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+fn severity(category: &str) -> IssueSeverity {
+    match category {
+        "error" => IssueSeverity::Error,
+        "warning" => IssueSeverity::Warning,
+        _ => IssueSeverity::Info,
+    }
+}
+
+/// The tsconfig.json projects covering the given paths
+///
+/// Each path maps to the nearest tsconfig.json at or above it. Project
+/// references are followed, and solution-style configs (`"files": []` with
+/// only references) are replaced by the projects they reference.
+pub fn tsconfig_projects(paths: &[PathBuf]) -> BTreeSet<PathBuf> {
+    let mut projects = BTreeSet::new();
+
+    for path in paths {
+        if let Some(dir) =
+            utils::find_ancestor_with(path, |dir| dir.join("tsconfig.json").is_file())
+        {
+            add_project(
+                &dir.join("tsconfig.json"),
+                &mut projects,
+                &mut BTreeSet::new(),
+            );
+        }
+    }
+
+    projects
+}
+
+/// Add a project and everything it references, skipping reference cycles
+fn add_project(tsconfig: &Path, projects: &mut BTreeSet<PathBuf>, seen: &mut BTreeSet<PathBuf>) {
+    if !seen.insert(tsconfig.to_path_buf()) {
+        return;
+    }
+
+    let config = std::fs::read_to_string(tsconfig)
+        .ok()
+        .and_then(|text| serde_json::from_str::<Value>(&strip_jsonc(&text)).ok())
+        .unwrap_or(Value::Null);
+
+    let references: Vec<PathBuf> = config
+        .get("references")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|reference| reference.get("path").and_then(Value::as_str))
+        .filter_map(|path| {
+            let path = tsconfig.parent()?.join(path);
+            let path = if path.is_dir() {
+                path.join("tsconfig.json")
+            } else {
+                path
+            };
+            path.canonicalize().ok()
+        })
+        .collect();
+
+    let solution_style = !references.is_empty()
+        && config
+            .get("files")
+            .and_then(Value::as_array)
+            .is_some_and(|files| files.is_empty())
+        && config.get("include").is_none();
+
+    if !solution_style {
+        projects.insert(tsconfig.to_path_buf());
+    }
+
+    for reference in references {
+        add_project(&reference, projects, seen);
+    }
+}
+
+/// Strip comments and trailing commas, which tsconfig.json allows
+fn strip_jsonc(text: &str) -> String {
+    let mut without_comments = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            without_comments.push(c);
+            match c {
+                '\\' => without_comments.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                without_comments.push(c);
+            }
+            ('/', Some('/')) => {
+                while chars.peek().is_some_and(|&next| next != '\n') {
+                    chars.next();
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            _ => without_comments.push(c),
+        }
+    }
+
+    // Drop commas that are only followed by whitespace and a closing bracket
+    let mut result = String::with_capacity(without_comments.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in without_comments.char_indices() {
+        if in_string {
+            in_string = escaped || c != '"';
+            escaped = !escaped && c == '\\';
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ','
+            && without_comments[index + 1..]
+                .trim_start()
+                .starts_with(['}', ']'])
+        {
+            continue;
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+impl LintTool for Tsc {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
+            matches!(ext, "ts" | "tsx" | "mts" | "cts")
+        } else {
+            false
+        }
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        // tsc always checks a whole project, so it runs once per tsconfig.json
+        // and only issues in our paths are kept
+        let targets: Vec<PathBuf> = files
+            .iter()
+            .filter(|path| path.is_dir() || self.can_handle(path))
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+
+        for tsconfig in tsconfig_projects(&targets) {
+            let Some(project_dir) = tsconfig.parent() else {
+                continue;
+            };
+
+            let mut command = super::node_command("tsc", project_dir);
+            command.current_dir(project_dir);
+            command.arg("--project").arg(&tsconfig);
+            command.args(["--noEmit", "--pretty", "false"]);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute tsc: {}", e),
+            })?;
+
+            let project_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let project_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            let project_issues = self.parse_output(&project_stdout, project_dir);
+
+            // A failure without any diagnostics means tsc itself broke
+            if !output.status.success() && project_issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: if project_stderr.trim().is_empty() {
+                        project_stdout
+                    } else {
+                        project_stderr
+                    },
+                });
+            }
+
+            issues.extend(project_issues.into_iter().filter(|issue| {
+                issue
+                    .file
+                    .as_ref()
+                    .is_none_or(|file| targets.iter().any(|target| file.starts_with(target)))
+            }));
+            stdout.push_str(&project_stdout);
+            stderr.push_str(&project_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::node_tool_available("tsc")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "Version 5.3.3"
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        let mut command = super::node_command("tsc", &current_dir);
+        command.arg("--version");
+        utils::log_command(&command);
+
+        let output = command.output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+
+        let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Some(
+            version
+                .strip_prefix("Version ")
+                .map(String::from)
+                .unwrap_or(version),
+        )
+    }
+}
@@ -0,0 +1,144 @@
+//! checkmake linter for Makefiles
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Template for checkmake's `--format` option, one violation per line
+const OUTPUT_FORMAT: &str = "{{.FileName}}:{{.LineNumber}}:{{.Rule}}:{{.Violation}}\n";
+
+/// Violations in [`OUTPUT_FORMAT`]: `file:line:rule:message`
+static VIOLATION: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(.+?):(\d+):([\w-]+):(.+)$").unwrap());
+
+/// checkmake linter for Makefiles
+pub struct Checkmake {
+    base: ToolBase,
+}
+
+impl Default for Checkmake {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checkmake {
+    /// Create a new checkmake linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "checkmake".to_string(),
+                description: "Linter for Makefiles".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Makefile],
+            },
+        }
+    }
+
+    /// Parse checkmake output produced with [`OUTPUT_FORMAT`]
+    ///
+    /// Rules that apply to the whole file, such as a missing `.PHONY` target,
+    /// are reported on line 0 and get no line number.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        output
+            .lines()
+            .filter_map(|line| VIOLATION.captures(line.trim()))
+            .map(|capture| LintIssue {
+                severity: IssueSeverity::Warning,
+                message: capture[4].trim().to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok().filter(|&line: &usize| line > 0),
+                column: None,
+                code: Some(capture[3].to_string()),
+                fix_available: false,
+            })
+            .collect()
+    }
+}
+
+impl LintTool for Checkmake {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_makefile(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let makefiles = super::makefiles(files);
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !makefiles.is_empty() {
+            // checkmake reads checkmake.ini from the working directory
+            let mut command = Command::new("checkmake");
+            command.arg(format!("--format={}", OUTPUT_FORMAT));
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&makefiles);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute checkmake: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            issues = self.parse_output(&stdout);
+
+            // checkmake exits non-zero when it finds violations, so only fail
+            // when there is nothing to show for it
+            if !output.status.success() && issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: stderr,
+                });
+            }
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("checkmake")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "checkmake 0.2.2 built at ..."
+        let output = utils::get_command_version("checkmake", &["--version"])?;
+        output.split_whitespace().nth(1).map(String::from)
+    }
+}
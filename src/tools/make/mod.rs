@@ -0,0 +1,23 @@
+//! Makefile tools
+
+use std::path::{Path, PathBuf};
+
+use crate::models::Language;
+use crate::utils;
+
+mod checkmake;
+
+pub use checkmake::Checkmake;
+
+/// Whether a file is a Makefile, by name or by the `.mk` extension
+fn is_makefile(file_path: &Path) -> bool {
+    utils::filename_language(file_path) == Some(Language::Makefile)
+        || file_path.extension().is_some_and(|ext| ext == "mk")
+}
+
+/// The Makefiles among `files`, with directories expanded
+///
+/// checkmake only accepts files, so directories are walked here.
+fn makefiles(files: &[PathBuf]) -> Vec<PathBuf> {
+    utils::walk_files(files, is_makefile)
+}
@@ -0,0 +1,179 @@
+//! markdownlint-cli2 linter for Markdown
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::javascript::{node_command, node_tool_available};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Violations: `file.md:line[:col] [error|warning] MD013/line-length message`
+static VIOLATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(.+?):(\d+)(?::(\d+))?(?: (error|warning))? (MD\d{3})(?:/\S+)? (.+)$").unwrap()
+});
+
+/// markdownlint-cli2 linter for Markdown
+pub struct Markdownlint {
+    base: ToolBase,
+}
+
+impl Default for Markdownlint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Markdownlint {
+    /// Create a new markdownlint linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "markdownlint".to_string(),
+                description: "Style checker and lint tool for Markdown".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Markdown],
+            },
+        }
+    }
+
+    /// Parse the output of `markdownlint-cli2`
+    ///
+    /// The rule number (`MD013`) becomes the code; its alias is dropped. Older
+    /// releases do not print a severity, and treat every violation as an error.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        output
+            .lines()
+            .filter_map(|line| VIOLATION.captures(line))
+            .map(|capture| LintIssue {
+                severity: match capture.get(4).map(|s| s.as_str()) {
+                    Some("warning") => IssueSeverity::Warning,
+                    _ => IssueSeverity::Error,
+                },
+                message: capture[6].to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: capture.get(3).and_then(|c| c.as_str().parse().ok()),
+                code: Some(capture[5].to_string()),
+                fix_available: false,
+            })
+            .collect()
+    }
+}
+
+impl LintTool for Markdownlint {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
+            matches!(ext, "md" | "markdown")
+        } else {
+            false
+        }
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // markdownlint-cli2 takes globs rather than directories
+        let patterns: Vec<String> = files
+            .iter()
+            .filter_map(|path| {
+                if path.is_dir() {
+                    Some(format!("{}/**/*.{{md,markdown}}", path.display()))
+                } else if self.can_handle(path) {
+                    Some(path.display().to_string())
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !patterns.is_empty() {
+            let current_dir = std::env::current_dir().unwrap_or_default();
+            let mut command = node_command("markdownlint-cli2", &current_dir);
+
+            if config.auto_fix {
+                command.arg("--fix");
+            }
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&patterns);
+
+            // Globs would otherwise descend into installed packages
+            command.arg("#node_modules");
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute markdownlint-cli2: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            // Exit code 1 means violations were found, 2 means the run failed
+            if output.status.code() != Some(0) && output.status.code() != Some(1) {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: stderr,
+                });
+            }
+
+            issues = self.parse_output(&stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        node_tool_available("markdownlint-cli2")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "markdownlint-cli2 v0.12.1 (markdownlint v0.33.0)"
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        let mut command = node_command("markdownlint-cli2", &current_dir);
+        command.arg("--help");
+        utils::log_command(&command);
+
+        // --help exits with status 2, so only the banner matters
+        let output = command.output().ok()?;
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .map(|version| version.trim_start_matches('v').to_string())
+    }
+}
@@ -0,0 +1,5 @@
+//! Markdown-specific tools
+
+mod markdownlint;
+
+pub use markdownlint::Markdownlint;
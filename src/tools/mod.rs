@@ -3,14 +3,29 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 use crate::errors::ToolError;
-use crate::models::{Language, LintResult, ToolConfig, ToolType};
+use crate::models::{Language, LintIssue, LintResult, ToolConfig, ToolType};
 
+pub mod cpp;
+pub mod csharp;
+pub mod css;
+pub mod docker;
+pub mod go;
 pub mod html;
+pub mod java;
 pub mod javascript;
+pub mod make;
+pub mod markdown;
+pub mod php;
 mod python;
+pub mod ruby;
 mod rust;
+pub mod shell;
+pub mod swift;
+pub mod toml;
+pub mod yaml;
 
 /// Trait for tools that can check code
 pub trait LintTool: Send + Sync {
@@ -45,6 +60,15 @@ pub trait LintTool: Send + Sync {
 
     /// Get the version of the tool
     fn version(&self) -> Option<String>;
+
+    /// Whether issues reported for one file can depend on other files
+    ///
+    /// True for type checkers and for linters that resolve imports or whole
+    /// crates. The result cache keys such tools by every file they can handle
+    /// in the project, since editing one file can change the issues in another.
+    fn cross_file(&self) -> bool {
+        self.tool_type() == ToolType::TypeChecker
+    }
 }
 
 /// Common functionality for tool implementations
@@ -62,6 +86,35 @@ pub struct ToolBase {
     pub languages: Vec<Language>,
 }
 
+/// Result of a tool run that completed, whatever it found
+///
+/// Issues alone do not make the run unsuccessful; empty output streams are
+/// left out.
+pub(crate) fn tool_result(
+    tool: &dyn LintTool,
+    issues: Vec<LintIssue>,
+    stdout: String,
+    stderr: String,
+    start: Instant,
+) -> LintResult {
+    LintResult {
+        tool_name: tool.name().to_string(),
+        tool: Some(crate::models::ToolInfo {
+            name: tool.name().to_string(),
+            tool_type: tool.tool_type(),
+            languages: tool.languages(),
+            available: tool.is_available(),
+            version: tool.version(),
+            description: tool.description().to_string(),
+        }),
+        success: true,
+        issues,
+        execution_time: start.elapsed(),
+        stdout: (!stdout.is_empty()).then_some(stdout),
+        stderr: (!stderr.is_empty()).then_some(stderr),
+    }
+}
+
 /// Tool information
 #[derive(Clone)]
 pub struct ToolInfo {
@@ -170,6 +223,65 @@ impl DefaultToolRegistry {
         // Register JavaScript tools
         registry.register_tool(Arc::new(javascript::Prettier::new()));
         registry.register_tool(Arc::new(javascript::ESLint::new()));
+        registry.register_tool(Arc::new(javascript::Tsc::new()));
+
+        // Register CSS tools
+        registry.register_tool(Arc::new(css::Stylelint::new()));
+        registry.register_tool(Arc::new(css::StylelintFixer::new()));
+
+        // Register Go tools
+        registry.register_tool(Arc::new(go::Gofmt::new()));
+        registry.register_tool(Arc::new(go::GoVet::new()));
+        registry.register_tool(Arc::new(go::GolangciLint::new()));
+
+        // Register Ruby tools
+        registry.register_tool(Arc::new(ruby::RuboCop::new()));
+        registry.register_tool(Arc::new(ruby::RuboCopFixer::new()));
+        registry.register_tool(Arc::new(ruby::Sorbet::new()));
+
+        // Register shell tools
+        registry.register_tool(Arc::new(shell::Shellcheck::new()));
+        registry.register_tool(Arc::new(shell::Shfmt::new()));
+
+        // Register C/C++ tools
+        registry.register_tool(Arc::new(cpp::ClangFormat::new()));
+        registry.register_tool(Arc::new(cpp::ClangTidy::new()));
+        registry.register_tool(Arc::new(cpp::ClangTidyFixer::new()));
+
+        // Register Java tools
+        registry.register_tool(Arc::new(java::GoogleJavaFormat::new()));
+        registry.register_tool(Arc::new(java::Checkstyle::new()));
+        registry.register_tool(Arc::new(java::Pmd::new()));
+
+        // Register Dockerfile tools
+        registry.register_tool(Arc::new(docker::Hadolint::new()));
+
+        // Register Makefile tools
+        registry.register_tool(Arc::new(make::Checkmake::new()));
+
+        // Register PHP tools
+        registry.register_tool(Arc::new(php::PhpCsFixer::new()));
+        registry.register_tool(Arc::new(php::PhpCsFixerFixer::new()));
+        registry.register_tool(Arc::new(php::PhpCsFixerCheck::new()));
+        registry.register_tool(Arc::new(php::Phpstan::new()));
+
+        // Register C# tools
+        registry.register_tool(Arc::new(csharp::DotnetFormat::new()));
+        registry.register_tool(Arc::new(csharp::DotnetFormatCheck::new()));
+
+        // Register Swift tools
+        registry.register_tool(Arc::new(swift::SwiftFormat::new()));
+        registry.register_tool(Arc::new(swift::SwiftLint::new()));
+
+        // Register Markdown tools
+        registry.register_tool(Arc::new(markdown::Markdownlint::new()));
+
+        // Register YAML tools
+        registry.register_tool(Arc::new(yaml::Yamllint::new()));
+
+        // Register TOML tools
+        registry.register_tool(Arc::new(toml::Taplo::new()));
+        registry.register_tool(Arc::new(toml::TaploLint::new()));
 
         registry
     }
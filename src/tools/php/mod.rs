@@ -0,0 +1,118 @@
+//! PHP-specific tools
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::utils;
+
+mod php_cs_fixer;
+mod phpstan;
+
+pub use php_cs_fixer::{PhpCsFixer, PhpCsFixerCheck, PhpCsFixerFixer};
+pub use phpstan::Phpstan;
+
+/// Whether a file is PHP source
+fn is_php_file(file_path: &Path) -> bool {
+    file_path.extension().is_some_and(|ext| ext == "php")
+}
+
+/// Find the Composer project a path belongs to by walking up to the nearest
+/// `composer.json`
+pub fn composer_root(path: &Path) -> Option<PathBuf> {
+    utils::find_ancestor_with(path, |dir| dir.join("composer.json").is_file())
+}
+
+/// Group PHP files and directories by Composer project
+///
+/// Paths are made absolute since the tools run from inside each project.
+/// Paths outside of any Composer project are grouped under the absolute
+/// current directory.
+pub fn php_paths_by_project(files: &[PathBuf]) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+    let mut projects: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+    let current_dir = utils::normalize_path(Path::new("."));
+
+    for path in files
+        .iter()
+        .filter(|path| path.is_dir() || is_php_file(path))
+    {
+        let Ok(absolute) = path.canonicalize() else {
+            continue;
+        };
+        let root = composer_root(&absolute).unwrap_or_else(|| current_dir.clone());
+        projects.entry(root).or_default().push(absolute);
+    }
+
+    projects
+}
+
+/// Composer's binary directory for a project, `vendor/bin` unless
+/// `config.bin-dir` in composer.json says otherwise
+fn bin_dir(root: &Path) -> PathBuf {
+    let configured = std::fs::read_to_string(root.join("composer.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+        .and_then(|json| {
+            json.pointer("/config/bin-dir")
+                .and_then(Value::as_str)
+                .map(String::from)
+        });
+
+    root.join(configured.as_deref().unwrap_or("vendor/bin"))
+}
+
+/// Command for a PHP tool, preferring the project's Composer-installed binary
+/// over one on the PATH so the pinned version is used
+fn php_command(executable: &str, root: &Path) -> Command {
+    let local = bin_dir(root).join(executable);
+    if local.is_file() {
+        Command::new(local)
+    } else {
+        Command::new(executable)
+    }
+}
+
+/// Composer project around the working directory, or the directory itself
+fn current_project() -> PathBuf {
+    let current_dir = utils::normalize_path(Path::new("."));
+    composer_root(&current_dir).unwrap_or(current_dir)
+}
+
+/// Whether a PHP tool can be run for a project, either from its Composer
+/// binaries or from the PATH
+fn php_tool_available(executable: &str, root: &Path) -> bool {
+    bin_dir(root).join(executable).is_file() || utils::command_exists(executable)
+}
+
+/// Fail with a message naming the project when a PHP tool cannot be run for it
+fn require_php_tool(name: &str, executable: &str, root: &Path) -> Result<(), ToolError> {
+    if php_tool_available(executable, root) {
+        Ok(())
+    } else {
+        Err(ToolError::ExecutionFailed {
+            name: name.to_string(),
+            message: format!(
+                "{} is neither installed in {} nor on the PATH",
+                executable,
+                root.display()
+            ),
+        })
+    }
+}
+
+/// Version of a PHP tool for a project, the first number in its `--version`
+/// output, e.g. `PHPStan - PHP Static Analysis Tool 1.10.57`
+fn php_tool_version(executable: &str, root: &Path) -> Option<String> {
+    let output = php_command(executable, root)
+        .arg("--version")
+        .output()
+        .ok()?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))
+        .map(String::from)
+}
@@ -0,0 +1,346 @@
+//! PHP-CS-Fixer formatter and fixer for PHP
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Exit code bits PHP-CS-Fixer uses for a broken setup, as opposed to files
+/// that need fixing (8) or could not be parsed (4)
+const FAILURE_BITS: i32 = 16 | 32 | 64;
+
+/// PHP-CS-Fixer formatter for PHP
+pub struct PhpCsFixer {
+    base: ToolBase,
+}
+
+/// PHP-CS-Fixer, run by `siren fix`
+pub struct PhpCsFixerFixer {
+    base: ToolBase,
+}
+
+/// PHP-CS-Fixer in dry-run mode, run by `siren check`
+pub struct PhpCsFixerCheck {
+    base: ToolBase,
+}
+
+impl Default for PhpCsFixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for PhpCsFixerFixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for PhpCsFixerCheck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhpCsFixer {
+    /// Create a new PHP-CS-Fixer formatter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "php-cs-fixer".to_string(),
+                description: "Fixes PHP code to follow coding standards".to_string(),
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Php],
+            },
+        }
+    }
+
+    /// Parse the output of `php-cs-fixer fix --diff --format=json`
+    ///
+    /// Each file that needs (or got) fixing becomes one issue, listing the
+    /// fixers involved and pointing at the first changed line of the diff.
+    /// File names are relative to `root`, the directory it ran in.
+    pub fn parse_output(&self, output: &str, root: &Path) -> Vec<LintIssue> {
+        let Some(json) = output
+            .find('{')
+            .and_then(|start| serde_json::from_str::<Value>(&output[start..]).ok())
+        else {
+            return Vec::new();
+        };
+
+        json.get("files")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|file| {
+                let name = file.get("name").and_then(Value::as_str)?;
+
+                let fixers: Vec<&str> = file
+                    .get("appliedFixers")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .collect();
+                let message = if fixers.is_empty() {
+                    "File needs fixing".to_string()
+                } else {
+                    format!("File needs fixing ({})", fixers.join(", "))
+                };
+
+                // First hunk header: `@@ -12,7 +12,6 @@`
+                let line = file
+                    .get("diff")
+                    .and_then(Value::as_str)
+                    .and_then(|diff| diff.lines().find_map(|l| l.strip_prefix("@@ -")))
+                    .and_then(|hunk| {
+                        hunk.split(|c: char| !c.is_ascii_digit())
+                            .next()?
+                            .parse()
+                            .ok()
+                    });
+
+                Some(LintIssue {
+                    severity: IssueSeverity::Style,
+                    message,
+                    file: Some(root.join(name)),
+                    line,
+                    column: None,
+                    code: None,
+                    fix_available: true,
+                })
+            })
+            .collect()
+    }
+}
+
+impl PhpCsFixerFixer {
+    /// Create a new PHP-CS-Fixer fixer
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "php-cs-fixer-fix".to_string(),
+                description: "Automatic fixer for PHP coding standard violations".to_string(),
+                tool_type: ToolType::Fixer,
+                languages: vec![Language::Php],
+            },
+        }
+    }
+}
+
+impl PhpCsFixerCheck {
+    /// Create a new PHP-CS-Fixer checker
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "php-cs-fixer-check".to_string(),
+                description: "Reports PHP coding standard violations without fixing them"
+                    .to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Php],
+            },
+        }
+    }
+}
+
+/// Run PHP-CS-Fixer on the given paths, only reporting what it would change
+/// unless `fix` is set
+///
+/// Runs once per Composer project so the project's .php-cs-fixer(.dist).php
+/// is picked up from the working directory. Returns the issues along with
+/// the combined stdout and stderr.
+fn run_php_cs_fixer(
+    name: &str,
+    files: &[PathBuf],
+    config: &ModelsToolConfig,
+    fix: bool,
+) -> Result<(Vec<LintIssue>, String, String), ToolError> {
+    let mut issues = Vec::new();
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+
+    for (root, targets) in super::php_paths_by_project(files) {
+        super::require_php_tool(name, "php-cs-fixer", &root)?;
+        let mut command = super::php_command("php-cs-fixer", &root);
+        command.current_dir(&root);
+
+        // The diff is only used to point at the first changed line.
+        // Intersecting keeps the config's Finder excludes in force for the
+        // paths we pass.
+        command.args([
+            "fix",
+            "--format=json",
+            "--diff",
+            "--path-mode=intersection",
+            "--no-interaction",
+        ]);
+        if !fix {
+            command.arg("--dry-run");
+        }
+
+        for arg in &config.extra_args {
+            command.arg(arg);
+        }
+        command.args(&targets);
+
+        utils::log_command(&command);
+
+        let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+            name: name.to_string(),
+            message: format!("Failed to execute php-cs-fixer: {}", e),
+        })?;
+
+        let project_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let project_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+        let code = output.status.code().unwrap_or(-1);
+        if code < 0 || code & FAILURE_BITS != 0 {
+            return Err(ToolError::ToolFailed {
+                name: name.to_string(),
+                code,
+                message: project_stderr,
+            });
+        }
+
+        issues.extend(PhpCsFixer::new().parse_output(&project_stdout, &root));
+        stdout.push_str(&project_stdout);
+        stderr.push_str(&project_stderr);
+    }
+
+    Ok((issues, stdout, stderr))
+}
+
+impl LintTool for PhpCsFixer {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_php_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Rewrite files unless only checking
+        let (issues, stdout, stderr) = run_php_cs_fixer(self.name(), files, config, !config.check)?;
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::php_tool_available("php-cs-fixer", &super::current_project())
+    }
+
+    fn version(&self) -> Option<String> {
+        super::php_tool_version("php-cs-fixer", &super::current_project())
+    }
+}
+
+impl LintTool for PhpCsFixerFixer {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_php_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Report the files that were fixed
+        let (issues, stdout, stderr) = run_php_cs_fixer(self.name(), files, config, true)?;
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::php_tool_available("php-cs-fixer", &super::current_project())
+    }
+
+    fn version(&self) -> Option<String> {
+        super::php_tool_version("php-cs-fixer", &super::current_project())
+    }
+}
+
+impl LintTool for PhpCsFixerCheck {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_php_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Only report what would change
+        let (issues, stdout, stderr) = run_php_cs_fixer(self.name(), files, config, false)?;
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::php_tool_available("php-cs-fixer", &super::current_project())
+    }
+
+    fn version(&self) -> Option<String> {
+        super::php_tool_version("php-cs-fixer", &super::current_project())
+    }
+}
@@ -0,0 +1,221 @@
+//! PHPStan type checker for PHP
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Configuration files PHPStan looks for, in its own order of precedence
+const PHPSTAN_CONFIGS: &[&str] = &["phpstan.neon", "phpstan.neon.dist", "phpstan.dist.neon"];
+
+/// PHPStan type checker for PHP
+pub struct Phpstan {
+    base: ToolBase,
+}
+
+impl Default for Phpstan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Phpstan {
+    /// Create a new PHPStan type checker
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "phpstan".to_string(),
+                description: "Static analysis and type checking for PHP".to_string(),
+                tool_type: ToolType::TypeChecker,
+                languages: vec![Language::Php],
+            },
+        }
+    }
+
+    /// Parse the output of `phpstan analyse --error-format=json`
+    ///
+    /// Codes are the error identifiers PHPStan reports since 1.11, such as
+    /// `method.notFound`. Errors not tied to a file, like a bad config, are
+    /// reported without one.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let Some(json) = output
+            .find('{')
+            .and_then(|start| serde_json::from_str::<Value>(&output[start..]).ok())
+        else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for (file, report) in json
+            .get("files")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            // Errors in traits are keyed as `Trait.php (in context of class Foo)`
+            let file = file.split(" (in context of").next().unwrap_or(file);
+
+            for message in report
+                .get("messages")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let mut text = message
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Unknown issue")
+                    .to_string();
+                if let Some(tip) = message.get("tip").and_then(Value::as_str) {
+                    text = format!("{} ({})", text, tip);
+                }
+
+                issues.push(LintIssue {
+                    severity: IssueSeverity::Error,
+                    message: text,
+                    file: Some(PathBuf::from(file)),
+                    line: message
+                        .get("line")
+                        .and_then(Value::as_u64)
+                        .map(|n| n as usize),
+                    column: None,
+                    code: message
+                        .get("identifier")
+                        .and_then(Value::as_str)
+                        .map(String::from),
+                    fix_available: false,
+                });
+            }
+        }
+
+        for error in json
+            .get("errors")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            issues.push(LintIssue {
+                severity: IssueSeverity::Error,
+                message: error.to_string(),
+                file: None,
+                line: None,
+                column: None,
+                code: None,
+                fix_available: false,
+            });
+        }
+
+        issues
+    }
+}
+
+impl LintTool for Phpstan {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_php_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        for (root, targets) in super::php_paths_by_project(files) {
+            super::require_php_tool(self.name(), "phpstan", &root)?;
+            let mut command = super::php_command("phpstan", &root);
+            command.current_dir(&root);
+            command.args([
+                "analyse",
+                "--error-format=json",
+                "--no-progress",
+                "--no-interaction",
+            ]);
+
+            // With a config, analyse the paths it lists and keep only issues
+            // in ours; that way its excludes and bootstrap files still apply.
+            // Without one, PHPStan needs the paths on the command line.
+            let phpstan_config = PHPSTAN_CONFIGS
+                .iter()
+                .map(|name| root.join(name))
+                .find(|path| path.is_file());
+            if let Some(phpstan_config) = &phpstan_config {
+                command.arg("-c").arg(phpstan_config);
+            }
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            if phpstan_config.is_none() {
+                command.args(&targets);
+            }
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute phpstan: {}", e),
+            })?;
+
+            let project_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let project_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            let project_issues = self.parse_output(&project_stdout);
+
+            // Exit code 1 means errors were found; a failure without a report
+            // means PHPStan itself broke
+            if !output.status.success() && project_issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: project_stderr,
+                });
+            }
+
+            issues.extend(project_issues.into_iter().filter(|issue| {
+                issue
+                    .file
+                    .as_ref()
+                    .is_none_or(|file| targets.iter().any(|target| file.starts_with(target)))
+            }));
+            stdout.push_str(&project_stdout);
+            stderr.push_str(&project_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::php_tool_available("phpstan", &super::current_project())
+    }
+
+    fn version(&self) -> Option<String> {
+        super::php_tool_version("phpstan", &super::current_project())
+    }
+}
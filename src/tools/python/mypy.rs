@@ -200,4 +200,9 @@ impl LintTool for MyPy {
     fn version(&self) -> Option<String> {
         utils::get_command_version("mypy", &["--version"])
     }
+
+    fn cross_file(&self) -> bool {
+        // Types flow in from imported modules
+        true
+    }
 }
@@ -219,4 +219,9 @@ impl LintTool for PyLint {
     fn version(&self) -> Option<String> {
         utils::get_command_version("pylint", &["--version"])
     }
+
+    fn cross_file(&self) -> bool {
+        // Inference follows imports into other modules
+        true
+    }
 }
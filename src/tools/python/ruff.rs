@@ -79,11 +79,10 @@ impl Ruff {
                                 let fix_available = diagnostic.get("fix").is_some();
 
                                 // Get full message with code display if available
-                                let formatted_message =
-                                    format!("{}: {}", code_str, message_str);
+                                let formatted_message = format!("{}: {}", code_str, message_str);
 
                                 // Remove URL section - we don't want to display URLs in output
-                                
+
                                 // Remove fix information - we don't want "Fix:" messages in output
                                 // We still track whether a fix is available via fix_available flag
 
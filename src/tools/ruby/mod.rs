@@ -0,0 +1,77 @@
+//! Ruby-specific tools
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::models::Language;
+use crate::utils;
+
+mod rubocop;
+mod sorbet;
+
+pub use rubocop::{RuboCop, RuboCopFixer};
+pub use sorbet::Sorbet;
+
+/// Nearest directory at or above `path` holding a `Gemfile.lock`
+fn bundle_root(path: &Path) -> Option<PathBuf> {
+    utils::find_ancestor_with(path, |dir| dir.join("Gemfile.lock").is_file())
+}
+
+/// Whether the `Gemfile.lock` in `root` pins a gem
+fn bundled(root: &Path, gem: &str) -> bool {
+    let pinned = format!("{} (", gem);
+    std::fs::read_to_string(root.join("Gemfile.lock")).is_ok_and(|lock| {
+        lock.lines()
+            .any(|line| line.trim_start().starts_with(&pinned))
+    })
+}
+
+/// Command for a Ruby tool working on `path`, run through Bundler when the
+/// project around it bundles the gem so its pinned version is used
+fn ruby_command(executable: &str, gem: &str, path: &Path) -> Command {
+    match bundle_root(path).filter(|root| bundled(root, gem)) {
+        Some(root) if utils::command_exists("bundle") => {
+            let mut command = Command::new("bundle");
+            command.args(["exec", executable]);
+            // Point Bundler at the project's Gemfile without moving the
+            // working directory, so relative paths keep working
+            command.env("BUNDLE_GEMFILE", root.join("Gemfile"));
+            command
+        }
+        _ => Command::new(executable),
+    }
+}
+
+/// Whether a Ruby tool can be run from the current directory, either directly
+/// or through Bundler
+fn ruby_tool_available(executable: &str, gem: &str) -> bool {
+    utils::command_exists(executable)
+        || (bundle_root(Path::new(".")).is_some_and(|root| bundled(&root, gem))
+            && utils::command_exists("bundle"))
+}
+
+/// First line of a Ruby tool's version output
+fn ruby_tool_version(executable: &str, gem: &str) -> Option<String> {
+    let mut command = ruby_command(executable, gem, Path::new("."));
+    command.arg("--version");
+    utils::log_command(&command);
+
+    let output = command.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+}
+
+/// Whether a file is Ruby source
+fn is_ruby_file(file_path: &Path) -> bool {
+    if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
+        matches!(ext, "rb" | "rake" | "gemspec" | "rbi" | "ru")
+    } else {
+        utils::filename_language(file_path) == Some(Language::Ruby)
+    }
+}
@@ -0,0 +1,294 @@
+//! RuboCop linter and fixer for Ruby
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// RuboCop linter for Ruby
+pub struct RuboCop {
+    base: ToolBase,
+}
+
+/// RuboCop autocorrect, run by `siren fix`
+pub struct RuboCopFixer {
+    base: ToolBase,
+}
+
+impl Default for RuboCop {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for RuboCopFixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuboCop {
+    /// Create a new RuboCop linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "rubocop".to_string(),
+                description: "Ruby static code analyzer and formatter".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Ruby],
+            },
+        }
+    }
+
+    /// Parse the output of `rubocop --format json`
+    ///
+    /// Offenses RuboCop already corrected are skipped unless `corrected` is
+    /// set, in which case only those are returned.
+    pub fn parse_output(&self, output: &str, corrected: bool) -> Vec<LintIssue> {
+        let Ok(json) = serde_json::from_str::<Value>(output) else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for file in json
+            .get("files")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let path = file.get("path").and_then(Value::as_str).map(PathBuf::from);
+
+            for offense in file
+                .get("offenses")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let was_corrected = offense
+                    .get("corrected")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false);
+                if was_corrected != corrected {
+                    continue;
+                }
+
+                let severity = match offense.get("severity").and_then(Value::as_str) {
+                    Some("fatal" | "error") => IssueSeverity::Error,
+                    Some("warning") => IssueSeverity::Warning,
+                    Some("info") => IssueSeverity::Info,
+                    _ => IssueSeverity::Style, // convention, refactor
+                };
+
+                let cop_name = offense.get("cop_name").and_then(Value::as_str);
+                let message = offense
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Unknown issue");
+
+                // The cop name is also prepended to the message, drop it there
+                let message = cop_name
+                    .and_then(|cop| message.strip_prefix(cop))
+                    .and_then(|rest| rest.strip_prefix(": "))
+                    .unwrap_or(message);
+
+                let location = offense.get("location");
+                let position = |key: &str| {
+                    location
+                        .and_then(|l| l.get(key))
+                        .and_then(Value::as_u64)
+                        .map(|n| n as usize)
+                };
+
+                issues.push(LintIssue {
+                    severity,
+                    message: message.to_string(),
+                    file: path.clone(),
+                    line: position("start_line").or_else(|| position("line")),
+                    column: position("start_column").or_else(|| position("column")),
+                    code: cop_name.map(String::from),
+                    fix_available: offense
+                        .get("correctable")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+impl RuboCopFixer {
+    /// Create a new RuboCop fixer
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "rubocop-fix".to_string(),
+                description: "Automatic fixer for RuboCop offenses".to_string(),
+                tool_type: ToolType::Fixer,
+                languages: vec![Language::Ruby],
+            },
+        }
+    }
+}
+
+/// Run RuboCop on the given paths, autocorrecting when `fix` is set
+///
+/// Safe corrections (`-a`) are used unless unsafe fixes were allowed, which
+/// switches to `-A`. Returns the raw JSON report along with stderr.
+fn run_rubocop(
+    name: &str,
+    files: &[PathBuf],
+    config: &ModelsToolConfig,
+    fix: bool,
+) -> Result<Option<(String, String)>, ToolError> {
+    let paths: Vec<&PathBuf> = files
+        .iter()
+        .filter(|path| path.is_dir() || super::is_ruby_file(path))
+        .collect();
+    if paths.is_empty() {
+        return Ok(None);
+    }
+
+    let mut command = super::ruby_command("rubocop", "rubocop", paths[0]);
+
+    // Respect the project's Exclude list even for files passed explicitly
+    command.args(["--format", "json", "--force-exclusion"]);
+
+    if fix {
+        command.arg(if config.unsafe_fixes { "-A" } else { "-a" });
+    }
+
+    for arg in &config.extra_args {
+        command.arg(arg);
+    }
+    command.args(&paths);
+
+    utils::log_command(&command);
+
+    let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+        name: name.to_string(),
+        message: format!("Failed to execute rubocop: {}", e),
+    })?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    // Exit code 1 means offenses were found, 2 means RuboCop itself failed
+    let code = output.status.code().unwrap_or(-1);
+    if !(0..=1).contains(&code) {
+        return Err(ToolError::ToolFailed {
+            name: name.to_string(),
+            code,
+            message: if stderr.trim().is_empty() {
+                stdout
+            } else {
+                stderr
+            },
+        });
+    }
+
+    Ok(Some((stdout, stderr)))
+}
+
+impl LintTool for RuboCop {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_ruby_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // With auto-fix on, report only what could not be corrected
+        let output = run_rubocop(self.name(), files, config, config.auto_fix)?;
+        let issues = output
+            .as_ref()
+            .map(|(stdout, _)| self.parse_output(stdout, false))
+            .unwrap_or_default();
+
+        let (stdout, stderr) = output.unwrap_or_default();
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::ruby_tool_available("rubocop", "rubocop")
+    }
+
+    fn version(&self) -> Option<String> {
+        super::ruby_tool_version("rubocop", "rubocop")
+    }
+}
+
+impl LintTool for RuboCopFixer {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_ruby_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Report the offenses that were corrected
+        let output = run_rubocop(self.name(), files, config, true)?;
+        let issues = output
+            .as_ref()
+            .map(|(stdout, _)| RuboCop::new().parse_output(stdout, true))
+            .unwrap_or_default();
+
+        let (stdout, stderr) = output.unwrap_or_default();
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::ruby_tool_available("rubocop", "rubocop")
+    }
+
+    fn version(&self) -> Option<String> {
+        super::ruby_tool_version("rubocop", "rubocop")
+    }
+}
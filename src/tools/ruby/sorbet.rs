@@ -0,0 +1,162 @@
+//! Sorbet type checker for Ruby
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Error headers: `file.rb:line: message https://srb.help/code`
+static ERROR_HEADER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.+?\.rbi?):(\d+): (.+?) https://srb\.help/(\d+)$").unwrap());
+
+/// Sorbet type checker for Ruby
+pub struct Sorbet {
+    base: ToolBase,
+}
+
+impl Default for Sorbet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sorbet {
+    /// Create a new Sorbet type checker
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "sorbet".to_string(),
+                description: "Fast, powerful type checker for Ruby".to_string(),
+                tool_type: ToolType::TypeChecker,
+                languages: vec![Language::Ruby],
+            },
+        }
+    }
+
+    /// Parse the output of `srb tc`
+    ///
+    /// Each error starts with a header line followed by an indented excerpt of
+    /// the offending code, which is skipped. File names are relative to
+    /// `root`, the directory holding `sorbet/config`.
+    pub fn parse_output(&self, output: &str, root: &Path) -> Vec<LintIssue> {
+        output
+            .lines()
+            .filter_map(|line| ERROR_HEADER.captures(line))
+            .map(|capture| LintIssue {
+                severity: IssueSeverity::Error,
+                message: capture[3].to_string(),
+                file: Some(root.join(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: None,
+                code: Some(capture[4].to_string()),
+                fix_available: false,
+            })
+            .collect()
+    }
+}
+
+/// Nearest ancestor directory holding a `sorbet/config`
+fn project_root(path: &Path) -> Option<PathBuf> {
+    utils::find_ancestor_with(path, |dir| dir.join("sorbet").join("config").is_file())
+}
+
+impl LintTool for Sorbet {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_ruby_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        // Sorbet always checks the whole project described by sorbet/config,
+        // so it runs once per project; issues in other files are dropped,
+        // project-wide ones without a file are kept
+        let targets: Vec<PathBuf> = files
+            .iter()
+            .filter(|path| path.is_dir() || self.can_handle(path))
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+        let roots: BTreeSet<PathBuf> = targets.iter().filter_map(|p| project_root(p)).collect();
+
+        for root in roots {
+            let mut command = super::ruby_command("srb", "sorbet", &root);
+            command.current_dir(&root);
+            command.arg("tc");
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute srb: {}", e),
+            })?;
+
+            let project_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let project_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            let project_issues = self.parse_output(&project_stderr, &root);
+
+            // A failure without any type errors means Sorbet itself broke
+            if !output.status.success() && project_issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: project_stderr,
+                });
+            }
+
+            issues.extend(project_issues.into_iter().filter(|issue| {
+                issue
+                    .file
+                    .as_ref()
+                    .is_none_or(|file| targets.iter().any(|target| file.starts_with(target)))
+            }));
+            stdout.push_str(&project_stdout);
+            stderr.push_str(&project_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        super::ruby_tool_available("srb", "sorbet")
+    }
+
+    fn version(&self) -> Option<String> {
+        super::ruby_tool_version("srb", "sorbet")
+    }
+}
@@ -281,7 +281,7 @@ impl Clippy {
                     let normalized_path = file_path
                         .canonicalize()
                         .unwrap_or_else(|_| file_path.clone());
-                    normalized_rust_files.iter().any(|f| *f == normalized_path)
+                    normalized_rust_files.contains(&normalized_path)
                 } else {
                     true // Keep issues without file info
                 }
@@ -456,6 +456,11 @@ impl LintTool for Clippy {
             None
         }
     }
+
+    fn cross_file(&self) -> bool {
+        // Issues come from compiling the whole crate
+        true
+    }
 }
 
 impl LintTool for ClippyFixer {
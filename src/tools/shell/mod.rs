@@ -0,0 +1,28 @@
+//! Shell script tools
+
+use std::path::{Path, PathBuf};
+
+use crate::models::Language;
+use crate::utils;
+
+mod shellcheck;
+mod shfmt;
+
+pub use shellcheck::Shellcheck;
+pub use shfmt::Shfmt;
+
+/// Whether a file is a shell script, by extension or by shebang
+fn is_shell_script(file_path: &Path) -> bool {
+    match file_path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => matches!(ext, "sh" | "bash" | "ksh"),
+        None => utils::shebang_language(file_path) == Some(Language::Shell),
+    }
+}
+
+/// The shell scripts among `files`, with directories expanded
+///
+/// Neither shellcheck nor shfmt respects .gitignore when walking directories,
+/// so the walk happens here.
+fn shell_scripts(files: &[PathBuf]) -> Vec<PathBuf> {
+    utils::walk_files(files, is_shell_script)
+}
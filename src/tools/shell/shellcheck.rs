@@ -0,0 +1,167 @@
+//! ShellCheck linter for shell scripts
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// ShellCheck linter for shell scripts
+pub struct Shellcheck {
+    base: ToolBase,
+}
+
+impl Default for Shellcheck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shellcheck {
+    /// Create a new ShellCheck linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "shellcheck".to_string(),
+                description: "Static analysis for shell scripts".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Shell],
+            },
+        }
+    }
+
+    /// Parse the output of `shellcheck -f json1`
+    ///
+    /// Codes are reported as `SC2086`, the form used in ShellCheck's wiki and
+    /// in `# shellcheck disable=` directives.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let Ok(json) = serde_json::from_str::<Value>(output) else {
+            return Vec::new();
+        };
+
+        json.get("comments")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|comment| {
+                let number =
+                    |key: &str| comment.get(key).and_then(Value::as_u64).map(|n| n as usize);
+
+                let severity = match comment.get("level").and_then(Value::as_str) {
+                    Some("error") => IssueSeverity::Error,
+                    Some("warning") => IssueSeverity::Warning,
+                    Some("info") => IssueSeverity::Info,
+                    _ => IssueSeverity::Style,
+                };
+
+                LintIssue {
+                    severity,
+                    message: comment
+                        .get("message")
+                        .and_then(Value::as_str)
+                        .unwrap_or("Unknown issue")
+                        .to_string(),
+                    file: comment
+                        .get("file")
+                        .and_then(Value::as_str)
+                        .map(PathBuf::from),
+                    line: number("line"),
+                    column: number("column"),
+                    code: number("code").map(|code| format!("SC{}", code)),
+                    fix_available: comment.get("fix").is_some_and(|fix| !fix.is_null()),
+                }
+            })
+            .collect()
+    }
+}
+
+impl LintTool for Shellcheck {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_shell_script(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let scripts = super::shell_scripts(files);
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !scripts.is_empty() {
+            let mut command = Command::new("shellcheck");
+            command.args(["-f", "json1"]);
+
+            // Follow `source` statements, as in a CI run
+            command.arg("--external-sources");
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&scripts);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute shellcheck: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            issues = self.parse_output(&stdout);
+
+            // Exit code 1 only means issues were found; anything else without
+            // a report is a bad option or an unreadable file
+            let code = output.status.code().unwrap_or(-1);
+            if code != 0 && code != 1 && issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code,
+                    message: stderr,
+                });
+            }
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("shellcheck")
+    }
+
+    fn version(&self) -> Option<String> {
+        // The version is on the second line: "version: 0.9.0"
+        let output = Command::new("shellcheck").arg("--version").output().ok()?;
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| line.strip_prefix("version: "))
+            .map(|version| version.trim().to_string())
+    }
+}
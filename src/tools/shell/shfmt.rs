@@ -0,0 +1,158 @@
+//! shfmt formatter for shell scripts
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Syntax errors: `file:line:col: message`
+static SYNTAX_ERROR: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(.+?):(\d+):(\d+): (.+)$").unwrap());
+
+/// shfmt formatter for shell scripts
+pub struct Shfmt {
+    base: ToolBase,
+}
+
+impl Default for Shfmt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shfmt {
+    /// Create a new shfmt formatter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "shfmt".to_string(),
+                description: "Shell script formatter".to_string(),
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Shell],
+            },
+        }
+    }
+
+    /// Parse the output of `shfmt -l`
+    ///
+    /// Every file on stdout needs formatting; syntax errors that kept shfmt
+    /// from parsing a file are reported on stderr.
+    pub fn parse_output(&self, stdout: &str, stderr: &str) -> Vec<LintIssue> {
+        let mut issues: Vec<LintIssue> = stdout
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|file| LintIssue {
+                severity: IssueSeverity::Style,
+                message: "File needs formatting".to_string(),
+                file: Some(PathBuf::from(file)),
+                line: None,
+                column: None,
+                code: None,
+                fix_available: true,
+            })
+            .collect();
+
+        for capture in stderr
+            .lines()
+            .filter_map(|line| SYNTAX_ERROR.captures(line))
+        {
+            issues.push(LintIssue {
+                severity: IssueSeverity::Error,
+                message: capture[4].to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: capture[3].parse().ok(),
+                code: None,
+                fix_available: false,
+            });
+        }
+
+        issues
+    }
+}
+
+impl LintTool for Shfmt {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_shell_script(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let scripts = super::shell_scripts(files);
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !scripts.is_empty() {
+            let mut command = Command::new("shfmt");
+
+            // List files that differ, and rewrite them unless only checking.
+            // Indentation and other style flags are left to .editorconfig,
+            // which shfmt ignores as soon as any printer flag is given.
+            command.arg("-l");
+            if !config.check {
+                command.arg("-w");
+            }
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&scripts);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute shfmt: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            issues = self.parse_output(&stdout, &stderr);
+        }
+
+        Ok(LintResult {
+            success: issues.is_empty(),
+            ..tool_result(self, issues, stdout, stderr, start)
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("shfmt")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "v3.7.0"
+        let output = utils::get_command_version("shfmt", &["--version"])?;
+        Some(output.trim_start_matches('v').to_string())
+    }
+}
@@ -0,0 +1,45 @@
+//! Swift-specific tools
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::utils;
+
+mod swift_format;
+mod swiftlint;
+
+pub use swift_format::SwiftFormat;
+pub use swiftlint::SwiftLint;
+
+/// Whether a file is Swift source
+fn is_swift_file(file_path: &Path) -> bool {
+    file_path.extension().is_some_and(|ext| ext == "swift")
+}
+
+/// Find the Swift package a path belongs to by walking up to the nearest
+/// `Package.swift`
+pub fn package_root(path: &Path) -> Option<PathBuf> {
+    utils::find_ancestor_with(path, |dir| dir.join("Package.swift").is_file())
+}
+
+/// Group Swift files and directories by package
+///
+/// Paths are made absolute since the tools run from inside each package, where
+/// SwiftLint looks for its .swiftlint.yml. Paths outside of any package are
+/// grouped under the current directory.
+fn swift_paths_by_package(files: &[PathBuf]) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+    let mut packages: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+
+    for path in files
+        .iter()
+        .filter(|path| path.is_dir() || is_swift_file(path))
+    {
+        let Ok(absolute) = path.canonicalize() else {
+            continue;
+        };
+        let root = package_root(&absolute).unwrap_or_else(|| PathBuf::from("."));
+        packages.entry(root).or_default().push(absolute);
+    }
+
+    packages
+}
@@ -0,0 +1,151 @@
+//! swift-format linter for Swift
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Diagnostics of `swift-format lint`:
+/// `file:line:column: warning: [Rule] message`
+static DIAGNOSTIC: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(.+?):(\d+):(\d+): (error|warning|note): (?:\[(\w+)\] )?(.+)$").unwrap()
+});
+
+/// swift-format linter for Swift
+pub struct SwiftFormat {
+    base: ToolBase,
+}
+
+impl Default for SwiftFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SwiftFormat {
+    /// Create a new swift-format linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "swift-format".to_string(),
+                description: "Checks Swift code against the swift-format style rules".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Swift],
+            },
+        }
+    }
+
+    /// Parse the diagnostics `swift-format lint` prints to stderr
+    ///
+    /// The rule name in brackets becomes the code; errors such as files that
+    /// fail to parse have none.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        output
+            .lines()
+            .filter_map(|line| DIAGNOSTIC.captures(line.trim_end()))
+            .map(|capture| LintIssue {
+                severity: match &capture[4] {
+                    "error" => IssueSeverity::Error,
+                    "note" => IssueSeverity::Info,
+                    _ => IssueSeverity::Warning,
+                },
+                message: capture[6].trim().to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: capture[3].parse().ok(),
+                code: capture.get(5).map(|rule| rule.as_str().to_string()),
+                fix_available: false,
+            })
+            .collect()
+    }
+}
+
+impl LintTool for SwiftFormat {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_swift_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        for (root, targets) in super::swift_paths_by_package(files) {
+            // swift-format finds the nearest .swift-format for each file itself
+            let mut command = Command::new("swift-format");
+            command.current_dir(&root);
+            command.args(["lint", "--recursive"]);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&targets);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute swift-format: {}", e),
+            })?;
+
+            let package_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let package_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            let package_issues = self.parse_output(&package_stderr);
+
+            // Lint findings only fail the run with --strict; a failure
+            // without diagnostics means swift-format itself broke
+            if !output.status.success() && package_issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: package_stderr,
+                });
+            }
+
+            issues.extend(package_issues);
+            stdout.push_str(&package_stdout);
+            stderr.push_str(&package_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("swift-format")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "510.1.0"
+        utils::get_command_version("swift-format", &["--version"])
+    }
+}
@@ -0,0 +1,165 @@
+//! SwiftLint linter for Swift
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// SwiftLint linter for Swift
+pub struct SwiftLint {
+    base: ToolBase,
+}
+
+impl Default for SwiftLint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SwiftLint {
+    /// Create a new SwiftLint linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "swiftlint".to_string(),
+                description: "Enforces Swift style and conventions".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Swift],
+            },
+        }
+    }
+
+    /// Parse the output of `swiftlint lint --reporter json`
+    ///
+    /// Codes are rule identifiers such as `line_length`. Violations that
+    /// apply to a whole line have no column.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let Some(Value::Array(violations)) = output
+            .find('[')
+            .and_then(|start| serde_json::from_str::<Value>(&output[start..]).ok())
+        else {
+            return Vec::new();
+        };
+
+        violations
+            .iter()
+            .map(|violation| LintIssue {
+                severity: match violation.get("severity").and_then(Value::as_str) {
+                    Some("Error") => IssueSeverity::Error,
+                    _ => IssueSeverity::Warning,
+                },
+                message: violation
+                    .get("reason")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Unknown issue")
+                    .to_string(),
+                file: violation
+                    .get("file")
+                    .and_then(Value::as_str)
+                    .map(PathBuf::from),
+                line: violation
+                    .get("line")
+                    .and_then(Value::as_u64)
+                    .map(|n| n as usize),
+                column: violation
+                    .get("character")
+                    .and_then(Value::as_u64)
+                    .map(|n| n as usize),
+                code: violation
+                    .get("rule_id")
+                    .and_then(Value::as_str)
+                    .map(String::from),
+                fix_available: false,
+            })
+            .collect()
+    }
+}
+
+impl LintTool for SwiftLint {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_swift_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        for (root, targets) in super::swift_paths_by_package(files) {
+            // SwiftLint reads .swiftlint.yml from the working directory
+            let mut command = Command::new("swiftlint");
+            command.current_dir(&root);
+            command.args(["lint", "--reporter", "json", "--quiet"]);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&targets);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute swiftlint: {}", e),
+            })?;
+
+            let package_stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let package_stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            let package_issues = self.parse_output(&package_stdout);
+
+            // SwiftLint exits with 2 when error-level violations are found, so
+            // only fail when there is nothing to show for it
+            if !output.status.success() && package_issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: package_stderr,
+                });
+            }
+
+            issues.extend(package_issues);
+            stdout.push_str(&package_stdout);
+            stderr.push_str(&package_stderr);
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("swiftlint")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "0.54.0"
+        utils::get_command_version("swiftlint", &["version"])
+    }
+}
@@ -0,0 +1,28 @@
+//! TOML-specific tools
+
+use std::path::{Path, PathBuf};
+
+mod taplo;
+
+pub use taplo::{Taplo, TaploLint};
+
+/// Taplo takes globs rather than directories
+fn taplo_patterns(files: &[PathBuf]) -> Vec<String> {
+    files
+        .iter()
+        .filter_map(|path| {
+            if path.is_dir() {
+                Some(format!("{}/**/*.toml", path.display()))
+            } else if is_toml_file(path) {
+                Some(path.display().to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Whether a file is TOML
+fn is_toml_file(file_path: &Path) -> bool {
+    file_path.extension().is_some_and(|ext| ext == "toml")
+}
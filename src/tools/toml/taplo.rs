@@ -0,0 +1,285 @@
+//! Taplo formatter and linter for TOML
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Diagnostic headers: `error: message`
+static DIAGNOSTIC: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(error|warning): (.+)$").unwrap());
+
+/// Diagnostic locations: `┌─ file.toml:line:col`
+static LOCATION: Lazy<Regex> = Lazy::new(|| Regex::new(r"┌─ (.+?):(\d+):(\d+)\s*$").unwrap());
+
+/// Files `taplo fmt --check` would change
+static UNFORMATTED: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"the file is not properly formatted path="(.+)""#).unwrap());
+
+/// Taplo formatter for TOML
+pub struct Taplo {
+    base: ToolBase,
+}
+
+/// Taplo linter for TOML, validating syntax and any associated schema
+pub struct TaploLint {
+    base: ToolBase,
+}
+
+impl Default for Taplo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for TaploLint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Taplo {
+    /// Create a new Taplo formatter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "taplo".to_string(),
+                description: "TOML toolkit formatter".to_string(),
+                tool_type: ToolType::Formatter,
+                languages: vec![Language::Toml],
+            },
+        }
+    }
+
+    /// Parse the output of `taplo fmt --check` or `taplo lint`
+    ///
+    /// Taplo logs to stderr. Unformatted files are reported on a log line
+    /// each, while syntax and schema errors are printed as an `error:` header
+    /// followed by a `┌─ file:line:col` location.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+        let mut pending: Option<LintIssue> = None;
+
+        for line in output.lines() {
+            if let Some(capture) = UNFORMATTED.captures(line) {
+                issues.push(LintIssue {
+                    severity: IssueSeverity::Style,
+                    message: "File needs formatting".to_string(),
+                    file: Some(PathBuf::from(&capture[1])),
+                    line: None,
+                    column: None,
+                    code: None,
+                    fix_available: true,
+                });
+            } else if let Some(capture) = DIAGNOSTIC.captures(line) {
+                issues.extend(pending.take());
+                pending = Some(LintIssue {
+                    severity: match &capture[1] {
+                        "error" => IssueSeverity::Error,
+                        _ => IssueSeverity::Warning,
+                    },
+                    message: capture[2].to_string(),
+                    file: None,
+                    line: None,
+                    column: None,
+                    code: None,
+                    fix_available: false,
+                });
+            } else if let Some(capture) = LOCATION.captures(line) {
+                if let Some(mut issue) = pending.take() {
+                    issue.file = Some(PathBuf::from(&capture[1]));
+                    issue.line = capture[2].parse().ok();
+                    issue.column = capture[3].parse().ok();
+                    issues.push(issue);
+                }
+            }
+        }
+
+        issues.extend(pending);
+        issues
+    }
+}
+
+impl TaploLint {
+    /// Create a new Taplo linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "taplo-lint".to_string(),
+                description: "TOML toolkit linter with schema validation".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Toml],
+            },
+        }
+    }
+}
+
+/// Run a taplo subcommand on the TOML files among `files`
+///
+/// Returns the exit code, stdout and stderr, or `None` if there was nothing
+/// to run on.
+fn run_taplo(
+    name: &str,
+    subcommand: &[&str],
+    files: &[PathBuf],
+    config: &ModelsToolConfig,
+) -> Result<Option<(Option<i32>, String, String)>, ToolError> {
+    let patterns = super::taplo_patterns(files);
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut command = Command::new("taplo");
+    command.args(["--colors", "never"]);
+    command.args(subcommand);
+
+    for arg in &config.extra_args {
+        command.arg(arg);
+    }
+    command.args(&patterns);
+
+    utils::log_command(&command);
+
+    let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+        name: name.to_string(),
+        message: format!("Failed to execute taplo: {}", e),
+    })?;
+
+    Ok(Some((
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    )))
+}
+
+fn taplo_version() -> Option<String> {
+    // "taplo 0.9.0"
+    let output = utils::get_command_version("taplo", &["--version"])?;
+    Some(
+        output
+            .strip_prefix("taplo ")
+            .map(String::from)
+            .unwrap_or(output),
+    )
+}
+
+impl LintTool for Taplo {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_toml_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // Rewrite files unless only checking
+        let subcommand: &[&str] = if config.check {
+            &["fmt", "--check"]
+        } else {
+            &["fmt"]
+        };
+
+        let (issues, stdout, stderr) = match run_taplo(self.name(), subcommand, files, config)? {
+            Some((_, stdout, stderr)) => (self.parse_output(&stderr), stdout, stderr),
+            None => Default::default(),
+        };
+
+        Ok(LintResult {
+            success: issues.is_empty(),
+            ..tool_result(self, issues, stdout, stderr, start)
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("taplo")
+    }
+
+    fn version(&self) -> Option<String> {
+        taplo_version()
+    }
+}
+
+impl LintTool for TaploLint {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        super::is_toml_file(file_path)
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        let (issues, stdout, stderr) = match run_taplo(self.name(), &["lint"], files, config)? {
+            Some((code, stdout, stderr)) => {
+                let issues = Taplo::new().parse_output(&stderr);
+
+                // A failure without any diagnostics means taplo itself broke
+                if code != Some(0) && issues.is_empty() {
+                    return Err(ToolError::ToolFailed {
+                        name: self.name().to_string(),
+                        code: code.unwrap_or(-1),
+                        message: stderr,
+                    });
+                }
+
+                (issues, stdout, stderr)
+            }
+            None => Default::default(),
+        };
+
+        // Tool executed successfully even if issues were found
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("taplo")
+    }
+
+    fn version(&self) -> Option<String> {
+        taplo_version()
+    }
+}
@@ -0,0 +1,5 @@
+//! YAML-specific tools
+
+mod yamllint;
+
+pub use yamllint::Yamllint;
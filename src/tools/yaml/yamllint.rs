@@ -0,0 +1,155 @@
+//! yamllint linter for YAML
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::errors::ToolError;
+use crate::models::tools::ToolConfig as ModelsToolConfig;
+use crate::models::{IssueSeverity, Language, LintIssue, LintResult, ToolType};
+use crate::tools::{tool_result, LintTool, ToolBase};
+use crate::utils;
+
+/// Problems: `file.yaml:line:col: [level] message (rule)`
+static PROBLEM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(.+?):(\d+):(\d+): \[(error|warning)\] (.+?)(?: \(([\w-]+)\))?$").unwrap()
+});
+
+/// yamllint linter for YAML
+pub struct Yamllint {
+    base: ToolBase,
+}
+
+impl Default for Yamllint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Yamllint {
+    /// Create a new yamllint linter
+    pub fn new() -> Self {
+        Self {
+            base: ToolBase {
+                name: "yamllint".to_string(),
+                description: "Linter for YAML files".to_string(),
+                tool_type: ToolType::Linter,
+                languages: vec![Language::Yaml],
+            },
+        }
+    }
+
+    /// Parse the output of `yamllint -f parsable`
+    ///
+    /// Syntax errors have no rule, so they come without a code.
+    pub fn parse_output(&self, output: &str) -> Vec<LintIssue> {
+        output
+            .lines()
+            .filter_map(|line| PROBLEM.captures(line))
+            .map(|capture| LintIssue {
+                severity: match &capture[4] {
+                    "error" => IssueSeverity::Error,
+                    _ => IssueSeverity::Warning,
+                },
+                message: capture[5].to_string(),
+                file: Some(PathBuf::from(&capture[1])),
+                line: capture[2].parse().ok(),
+                column: capture[3].parse().ok(),
+                code: capture.get(6).map(|rule| rule.as_str().to_string()),
+                fix_available: false,
+            })
+            .collect()
+    }
+}
+
+impl LintTool for Yamllint {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
+            matches!(ext, "yaml" | "yml")
+        } else {
+            false
+        }
+    }
+
+    fn execute(
+        &self,
+        files: &[PathBuf],
+        config: &ModelsToolConfig,
+    ) -> Result<LintResult, ToolError> {
+        let start = Instant::now();
+
+        // yamllint would walk installed packages and ignored trees too, so
+        // directories are expanded here
+        let paths = utils::walk_files(files, |path| self.can_handle(path));
+
+        let mut issues = Vec::new();
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+
+        if !paths.is_empty() {
+            let mut command = Command::new("yamllint");
+            command.args(["-f", "parsable"]);
+
+            for arg in &config.extra_args {
+                command.arg(arg);
+            }
+            command.args(&paths);
+
+            utils::log_command(&command);
+
+            let output = command.output().map_err(|e| ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: format!("Failed to execute yamllint: {}", e),
+            })?;
+
+            stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            issues = self.parse_output(&stdout);
+
+            // A failure without any problems means the configuration is broken
+            if !output.status.success() && issues.is_empty() {
+                return Err(ToolError::ToolFailed {
+                    name: self.name().to_string(),
+                    code: output.status.code().unwrap_or(-1),
+                    message: stderr,
+                });
+            }
+        }
+
+        Ok(tool_result(self, issues, stdout, stderr, start))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.base.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        self.base.languages.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn is_available(&self) -> bool {
+        utils::command_exists("yamllint")
+    }
+
+    fn version(&self) -> Option<String> {
+        // "yamllint 1.33.0"
+        let output = utils::get_command_version("yamllint", &["--version"])?;
+        Some(
+            output
+                .strip_prefix("yamllint ")
+                .map(String::from)
+                .unwrap_or(output),
+        )
+    }
+}
@@ -0,0 +1,345 @@
+//! Git helpers for selecting changed files and lines
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use log::debug;
+
+use crate::errors::SirenError;
+use crate::models::LintResult;
+
+use super::{log_command, normalize_path};
+
+/// Lines of a file that changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangedLines {
+    /// The whole file is new (untracked or added)
+    All,
+
+    /// Only these 1-based line ranges changed
+    Ranges(Vec<RangeInclusive<usize>>),
+}
+
+impl ChangedLines {
+    /// Whether a 1-based line is part of the change
+    pub fn contains(&self, line: usize) -> bool {
+        match self {
+            ChangedLines::All => true,
+            ChangedLines::Ranges(ranges) => ranges.iter().any(|range| range.contains(&line)),
+        }
+    }
+}
+
+/// Files and lines changed relative to a base commit
+#[derive(Debug, Clone, Default)]
+pub struct ChangeSet {
+    files: BTreeMap<PathBuf, ChangedLines>,
+}
+
+impl ChangeSet {
+    /// Collect everything changed since the merge base of `base` and `HEAD`
+    ///
+    /// Covers committed, staged and unstaged changes as well as untracked
+    /// files, which is what a pull request against `base` would contain once
+    /// everything is committed. Deleted files are left out.
+    pub fn since(dir: &Path, base: &str) -> Result<Self, SirenError> {
+        let root = repository_root(dir)?;
+
+        let merge_base = git(&root, &["merge-base", base, "HEAD"]).map_err(|e| {
+            SirenError::InvalidArgument(format!("Cannot diff against '{}': {}", base, e))
+        })?;
+        let merge_base = merge_base.trim();
+        debug!("Merge base of {} and HEAD is {}", base, merge_base);
+
+        // Zero context lines, so every hunk is exactly the changed lines
+        let diff = git(
+            &root,
+            &[
+                "diff",
+                "--no-color",
+                "--no-ext-diff",
+                "--unified=0",
+                "--diff-filter=ACMR",
+                merge_base,
+            ],
+        )
+        .map_err(SirenError::InvalidArgument)?;
+
+        let mut files: BTreeMap<PathBuf, ChangedLines> = parse_hunks(&diff)
+            .into_iter()
+            .map(|(file, lines)| (root.join(file), lines))
+            .collect();
+
+        for file in untracked_files(&root)? {
+            files.insert(file, ChangedLines::All);
+        }
+
+        Ok(Self::from_files(files))
+    }
+
+    /// Build a change set from already known changes
+    pub fn from_files(files: BTreeMap<PathBuf, ChangedLines>) -> Self {
+        Self {
+            files: files
+                .into_iter()
+                .map(|(file, lines)| (normalize_path(&file), lines))
+                .collect(),
+        }
+    }
+
+    /// Changed files that still exist on disk
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.files
+            .keys()
+            .filter(|file| file.exists())
+            .cloned()
+            .collect()
+    }
+
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Whether an issue location falls inside the change
+    ///
+    /// Issues without a line count as changed when their file did.
+    pub fn contains(&self, file: &Path, line: Option<usize>) -> bool {
+        self.files
+            .get(&normalize_path(file))
+            .is_some_and(|lines| line.is_none_or(|line| lines.contains(line)))
+    }
+
+    /// Keep only issues on changed lines, returning how many were dropped
+    ///
+    /// Issues that are not tied to a file are kept.
+    pub fn filter_results(&self, results: &mut [LintResult]) -> usize {
+        let mut dropped = 0;
+
+        for result in results.iter_mut() {
+            let before = result.issues.len();
+            result.issues.retain(|issue| {
+                issue
+                    .file
+                    .as_deref()
+                    .is_none_or(|file| self.contains(file, issue.line))
+            });
+
+            if result.issues.len() != before {
+                dropped += before - result.issues.len();
+                result.success = result.issues.is_empty();
+            }
+        }
+
+        dropped
+    }
+}
+
+/// Parse the new-side line ranges out of a `git diff --unified=0`
+///
+/// Paths are relative to the repository root. Pure deletions add no lines;
+/// files that show up without hunks (renames, mode changes) are skipped.
+pub fn parse_hunks(diff: &str) -> BTreeMap<PathBuf, ChangedLines> {
+    let mut files = BTreeMap::new();
+    let mut current: Option<PathBuf> = None;
+
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            current = path
+                .trim_end_matches('\t')
+                .strip_prefix("b/")
+                .map(PathBuf::from);
+            continue;
+        }
+
+        let (Some(file), Some(hunk)) = (&current, line.strip_prefix("@@ ")) else {
+            continue;
+        };
+
+        // @@ -old[,count] +new[,count] @@
+        let Some(new) = hunk
+            .split_whitespace()
+            .find_map(|part| part.strip_prefix('+'))
+        else {
+            continue;
+        };
+        let (start, count) = match new.split_once(',') {
+            Some((start, count)) => (start.parse().ok(), count.parse().ok()),
+            None => (new.parse().ok(), Some(1)),
+        };
+
+        if let (Some(start), Some(count)) = (start, count) {
+            let entry = files
+                .entry(file.clone())
+                .or_insert_with(|| ChangedLines::Ranges(Vec::new()));
+            if let ChangedLines::Ranges(ranges) = entry {
+                if count > 0 {
+                    ranges.push(start..=start + count - 1);
+                }
+            }
+        }
+    }
+
+    files
+}
+
+/// Files staged for commit, as absolute paths
+///
+/// Deletions are left out since there is nothing left to lint.
+pub fn staged_files(dir: &Path) -> Result<Vec<PathBuf>, SirenError> {
+    let root = repository_root(dir)?;
+    let output = git(
+        &root,
+        &[
+            "diff",
+            "--cached",
+            "--name-only",
+            "-z",
+            "--diff-filter=ACMR",
+        ],
+    )
+    .map_err(|e| SirenError::Io(std::io::Error::other(e)))?;
+
+    Ok(output
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(|file| root.join(file))
+        .collect())
+}
+
+/// Working tree with its unstaged changes set aside, so it matches the index
+///
+/// Unstaged edits to tracked files are saved as a patch inside the git
+/// directory and reverted until the snapshot is restored, which happens on
+/// drop as well. Untracked files are left alone. If the patch no longer
+/// applies, e.g. because a tool rewrote a file, it is kept so nothing is lost.
+#[derive(Debug)]
+pub struct StagedSnapshot {
+    root: PathBuf,
+    patch: Option<PathBuf>,
+}
+
+impl StagedSnapshot {
+    /// Revert unstaged changes in the repository containing `dir`
+    pub fn take(dir: &Path) -> Result<Self, SirenError> {
+        let root = repository_root(dir)?;
+        let io_error = |e: String| SirenError::Io(std::io::Error::other(e));
+
+        let diff = git_output(
+            &root,
+            &[
+                "diff",
+                "--binary",
+                "--no-color",
+                "--no-ext-diff",
+                "--no-renames",
+            ],
+        )
+        .map_err(io_error)?;
+        if diff.is_empty() {
+            return Ok(Self { root, patch: None });
+        }
+
+        let git_dir = git(&root, &["rev-parse", "--absolute-git-dir"]).map_err(io_error)?;
+        let patch = PathBuf::from(git_dir.trim())
+            .join(format!("siren-unstaged-{}.patch", std::process::id()));
+        std::fs::write(&patch, &diff)?;
+        debug!("Saved unstaged changes to {:?}", patch);
+
+        let changed =
+            git(&root, &["diff", "--name-only", "-z", "--no-renames"]).map_err(io_error)?;
+        let mut args = vec!["checkout", "--"];
+        args.extend(changed.split('\0').filter(|file| !file.is_empty()));
+        git(&root, &args).map_err(io_error)?;
+
+        Ok(Self {
+            root,
+            patch: Some(patch),
+        })
+    }
+
+    /// Put the unstaged changes back
+    pub fn restore(mut self) -> Result<(), SirenError> {
+        self.reapply()
+    }
+
+    fn reapply(&mut self) -> Result<(), SirenError> {
+        let Some(patch) = self.patch.take() else {
+            return Ok(());
+        };
+
+        let patch_arg = patch.to_string_lossy();
+        git(&self.root, &["apply", "--whitespace=nowarn", &patch_arg]).map_err(|e| {
+            SirenError::Io(std::io::Error::other(format!(
+                "Could not restore unstaged changes ({}), they are saved in {}",
+                e,
+                patch.display()
+            )))
+        })?;
+
+        std::fs::remove_file(&patch)?;
+        Ok(())
+    }
+}
+
+impl Drop for StagedSnapshot {
+    fn drop(&mut self) {
+        if let Err(e) = self.reapply() {
+            log::error!("{}", e);
+        }
+    }
+}
+
+/// Root of the repository containing `dir`
+pub fn toplevel(dir: &Path) -> Option<PathBuf> {
+    git(dir, &["rev-parse", "--show-toplevel"])
+        .ok()
+        .map(|root| PathBuf::from(root.trim()))
+}
+
+/// Directory git runs hooks from, honouring `core.hooksPath`
+pub fn hooks_dir(dir: &Path) -> Result<PathBuf, SirenError> {
+    let root = repository_root(dir)?;
+    let hooks = git(&root, &["rev-parse", "--git-path", "hooks"])
+        .map_err(|e| SirenError::Io(std::io::Error::other(e)))?;
+    Ok(root.join(hooks.trim()))
+}
+
+/// Untracked files that are not ignored, as absolute paths
+pub fn untracked_files(root: &Path) -> Result<Vec<PathBuf>, SirenError> {
+    let output = git(root, &["ls-files", "--others", "--exclude-standard", "-z"])
+        .map_err(|e| SirenError::Io(std::io::Error::other(e)))?;
+
+    Ok(output
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(|file| root.join(file))
+        .collect())
+}
+
+fn repository_root(dir: &Path) -> Result<PathBuf, SirenError> {
+    toplevel(dir).ok_or_else(|| {
+        SirenError::InvalidArgument(format!("{} is not inside a git repository", dir.display()))
+    })
+}
+
+/// Run git and return its stdout, or its stderr as the error
+fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    git_output(dir, args).map(|stdout| String::from_utf8_lossy(&stdout).into_owned())
+}
+
+/// Like [`git`], keeping stdout as raw bytes
+fn git_output(dir: &Path, args: &[&str]) -> Result<Vec<u8>, String> {
+    let mut command = Command::new("git");
+    command.args(args).current_dir(dir);
+    log_command(&command);
+
+    let output = command.output().map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
@@ -6,7 +6,10 @@ use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::models::Language;
+
 // Export path management utilities
+pub mod git;
 pub mod path_manager;
 
 /// Log a command that is about to be executed
@@ -64,6 +67,56 @@ pub fn get_command_version<S: AsRef<OsStr>>(command: S, args: &[&str]) -> Option
     }
 }
 
+/// Detect the language of a file from its name alone
+///
+/// Covers files identified by name rather than extension: `Dockerfile`,
+/// `Containerfile`, `Dockerfile.*` and `*.Dockerfile` for Docker,
+/// `Makefile`, `makefile` and `GNUmakefile` for Make, and `Gemfile` and
+/// `Rakefile` for Ruby.
+pub fn filename_language(path: &Path) -> Option<Language> {
+    let name = path.file_name()?.to_str()?;
+    let lowercase = name.to_lowercase();
+
+    if matches!(name, "Makefile" | "makefile" | "GNUmakefile") {
+        Some(Language::Makefile)
+    } else if matches!(name, "Gemfile" | "Rakefile") {
+        Some(Language::Ruby)
+    } else if matches!(lowercase.as_str(), "dockerfile" | "containerfile")
+        || lowercase.starts_with("dockerfile.")
+        || lowercase.starts_with("containerfile.")
+        || lowercase.ends_with(".dockerfile")
+    {
+        Some(Language::Docker)
+    } else {
+        None
+    }
+}
+
+/// Detect the language of a script from its shebang line
+///
+/// Both `#!/bin/bash` and `#!/usr/bin/env bash` forms are understood. Only
+/// shell interpreters are recognised for now.
+pub fn shebang_language(path: &Path) -> Option<Language> {
+    use std::io::Read;
+
+    let mut head = [0u8; 128];
+    let read = std::fs::File::open(path).ok()?.read(&mut head).ok()?;
+    let first_line = String::from_utf8_lossy(&head[..read]);
+    let first_line = first_line.lines().next()?.strip_prefix("#!")?;
+
+    let mut words = first_line.split_whitespace();
+    let mut interpreter = Path::new(words.next()?).file_name()?.to_str()?;
+    if interpreter == "env" {
+        // Skip options such as `env -S`
+        interpreter = words.find(|word| !word.starts_with('-'))?;
+    }
+
+    match interpreter {
+        "sh" | "bash" | "dash" | "ksh" | "mksh" | "ash" => Some(Language::Shell),
+        _ => None,
+    }
+}
+
 /// Check if a directory is a git repository
 pub fn is_git_repo(dir: &Path) -> bool {
     let git_dir = dir.join(".git");
@@ -71,58 +124,52 @@ pub fn is_git_repo(dir: &Path) -> bool {
 }
 
 /// Get list of files modified in git
+///
+/// Includes staged, unstaged and untracked (but not ignored) files; deleted
+/// files are left out since there is nothing left to lint.
 pub fn get_git_modified_files(dir: &Path) -> Result<Vec<PathBuf>, std::io::Error> {
-    println!("Looking for git repo in: {:?}", dir);
-    if !is_git_repo(dir) {
-        println!("Not a git repository: {:?}", dir);
+    let Some(root) = git::toplevel(dir) else {
+        debug!("Not a git repository: {:?}", dir);
         return Ok(Vec::new());
-    }
-    println!("Found git repository: {:?}", dir);
+    };
+    debug!("Found git repository: {:?}", root);
 
-    // Get modified files using git status
+    // -z keeps paths unquoted and puts the source of a rename in its own entry
     let output = Command::new("git")
-        .args(["status", "--porcelain"])
-        .current_dir(dir)
+        .args(["status", "--porcelain", "-z", "--untracked-files=all"])
+        .current_dir(&root)
         .output()?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        println!("Git status failed: {}", stderr);
-        return Err(std::io::Error::new(std::io::ErrorKind::Other, stderr));
+        debug!("Git status failed: {}", stderr);
+        return Err(std::io::Error::other(stderr));
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    println!("Git status output: {:?}", stdout);
-
-    // Parse output into file paths - include both modified and added files
-    let files: Vec<PathBuf> = stdout
-        .lines()
-        .filter(|line| !line.is_empty()) // Skip empty lines
-        .filter_map(|line| {
-            println!("Processing git status line: '{}'", line);
-
-            // Git status --porcelain format has two status characters followed by a space
-            // then the file path. Skip untracked files (marked with "??")
-            if line.starts_with("??") {
-                println!("Skipping untracked file");
-                return None;
-            }
+    let mut entries = stdout.split('\0').filter(|entry| !entry.is_empty());
+    let mut files = Vec::new();
 
-            // Extract the filename part (after the status codes and space)
-            if line.len() > 3 {
-                let file_path = line[3..].trim();
-                println!("Extracted file path: '{}'", file_path);
-                let absolute_path = dir.join(file_path);
-                println!("Resolved to absolute path: {:?}", absolute_path);
-                Some(absolute_path)
-            } else {
-                println!("Line too short to contain a valid path");
-                None
-            }
-        })
-        .collect();
+    while let Some(entry) = entries.next() {
+        // Two status characters, a space, then the path
+        if entry.len() <= 3 {
+            continue;
+        }
+        let (status, path) = entry.split_at(3);
+
+        // Renames and copies are followed by their source path, skip it
+        if status.contains('R') || status.contains('C') {
+            entries.next();
+        }
+
+        let file = root.join(path);
+        if file.exists() {
+            debug!("Git modified file ({}): {:?}", status.trim(), file);
+            files.push(file);
+        }
+    }
 
-    println!("Found {} modified files: {:?}", files.len(), files);
+    debug!("Found {} modified files", files.len());
     Ok(files)
 }
 
@@ -298,6 +345,49 @@ pub fn collect_files_with_gitignore(dir: &Path) -> Result<Vec<PathBuf>, crate::e
     Ok(files)
 }
 
+/// Nearest directory at or above `path` that `marker` accepts
+///
+/// The path is made absolute first, and a file starts the search from the
+/// directory holding it. Used to find the project a file belongs to, e.g. the
+/// nearest directory with a `go.mod`.
+pub fn find_ancestor_with(path: &Path, marker: impl Fn(&Path) -> bool) -> Option<PathBuf> {
+    let absolute = path.canonicalize().ok()?;
+    let start = if absolute.is_dir() {
+        absolute.as_path()
+    } else {
+        absolute.parent()?
+    };
+
+    start
+        .ancestors()
+        .find(|dir| marker(dir))
+        .map(Path::to_path_buf)
+}
+
+/// The files among `paths` matching a predicate, with directories expanded
+///
+/// For tools that only accept files or that do not respect .gitignore when
+/// walking directories themselves, so build trees and vendored code are skipped.
+pub fn walk_files(paths: &[PathBuf], predicate: impl Fn(&Path) -> bool) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for path in paths {
+        if path.is_dir() {
+            files.extend(
+                ignore::WalkBuilder::new(path)
+                    .build()
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.into_path())
+                    .filter(|file| file.is_file() && predicate(file)),
+            );
+        } else if predicate(path) {
+            files.push(path.clone());
+        }
+    }
+
+    files
+}
+
 /// Check if a directory is a valid Python package
 ///
 /// A directory is considered a valid Python package if it or any of its
@@ -335,3 +425,18 @@ pub fn is_valid_python_package(dir: &Path) -> bool {
 
     false
 }
+
+/// Make a path comparable regardless of how a tool chose to print it
+///
+/// Relative paths are resolved against the current directory and symlinks are
+/// followed where the path exists.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map(|dir| dir.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    };
+    absolute.canonicalize().unwrap_or(absolute)
+}
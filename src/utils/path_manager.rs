@@ -101,13 +101,8 @@ impl PathManager {
                     if let Ok(files) = self.collect_files_from_directory(&path) {
                         if !files.is_empty() {
                             // Check if any files have recognized extensions
-                            let has_recognized_files = files.iter().any(|f| {
-                                if let Some(ext) = f.extension().and_then(|e| e.to_str()) {
-                                    self.detect_language_from_extension(ext).is_some()
-                                } else {
-                                    false
-                                }
-                            });
+                            let has_recognized_files =
+                                files.iter().any(|f| self.detect_language(f).is_some());
 
                             if has_recognized_files {
                                 subdirectories.push(path);
@@ -180,6 +175,34 @@ impl PathManager {
         Ok(files)
     }
 
+    /// Collect the files staged for commit
+    ///
+    /// Only files inside `paths` are kept when any are given. Pair this with a
+    /// [`StagedSnapshot`](utils::git::StagedSnapshot) so tools see the staged
+    /// content rather than the working tree.
+    pub fn collect_staged_files(&mut self, paths: &[PathBuf]) -> Result<&mut Self, SirenError> {
+        let staged = utils::git::staged_files(Path::new("."))?;
+        log::debug!("Found {} staged files", staged.len());
+        Ok(self.add_files_within(staged, paths))
+    }
+
+    /// Add the files that lie inside any of `paths`, or all of them when
+    /// `paths` is empty
+    pub fn add_files_within(&mut self, files: Vec<PathBuf>, paths: &[PathBuf]) -> &mut Self {
+        let roots: Vec<PathBuf> = paths
+            .iter()
+            .map(|path| path.canonicalize().unwrap_or_else(|_| path.clone()))
+            .collect();
+
+        for file in files {
+            let canonical = file.canonicalize().unwrap_or_else(|_| file.clone());
+            if roots.is_empty() || roots.iter().any(|root| canonical.starts_with(root)) {
+                self.add_file(file);
+            }
+        }
+        self
+    }
+
     /// Add files to the manager
     pub fn add_files(&mut self, files: Vec<PathBuf>) -> &mut Self {
         for file in files {
@@ -206,19 +229,30 @@ impl PathManager {
                 .push(file.clone());
         }
 
-        // Add to language map based on extension
-        if let Some(ext) = file.extension().and_then(|e| e.to_str()) {
-            if let Some(language) = self.detect_language_from_extension(ext) {
-                self.files_by_language
-                    .entry(language)
-                    .or_default()
-                    .push(file);
-            }
+        // Add to language map based on name, extension or shebang
+        if let Some(language) = self.detect_language(&file) {
+            self.files_by_language
+                .entry(language)
+                .or_default()
+                .push(file);
         }
 
         self
     }
 
+    /// Detect the language of a file from its name or extension, sniffing
+    /// the shebang of extensionless scripts
+    fn detect_language(&self, file: &Path) -> Option<Language> {
+        if let Some(language) = utils::filename_language(file) {
+            return Some(language);
+        }
+
+        match file.extension().and_then(|e| e.to_str()) {
+            Some(ext) => self.detect_language_from_extension(ext),
+            None => utils::shebang_language(file),
+        }
+    }
+
     /// Detect language from file extension
     fn detect_language_from_extension(&self, ext: &str) -> Option<Language> {
         match ext {
@@ -227,10 +261,20 @@ impl PathManager {
             "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" => Some(Language::JavaScript),
             "html" | "htm" | "djhtml" | "jinja" | "jinja2" => Some(Language::Html),
             "css" | "scss" | "sass" | "less" => Some(Language::Css),
+            "sh" | "bash" | "ksh" => Some(Language::Shell),
             "json" => Some(Language::Json),
             "md" | "markdown" => Some(Language::Markdown),
             "yaml" | "yml" => Some(Language::Yaml),
             "toml" => Some(Language::Toml),
+            "go" => Some(Language::Go),
+            "c" => Some(Language::C),
+            "cpp" | "cc" | "cxx" | "c++" | "h" | "hpp" | "hh" | "hxx" => Some(Language::Cpp),
+            "java" => Some(Language::Java),
+            "mk" => Some(Language::Makefile),
+            "php" => Some(Language::Php),
+            "cs" => Some(Language::CSharp),
+            "swift" => Some(Language::Swift),
+            "rb" | "rake" | "gemspec" | "rbi" => Some(Language::Ruby),
             _ => None,
         }
     }
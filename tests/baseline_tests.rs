@@ -0,0 +1,153 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use siren::baseline::{Baseline, BASELINE_FILE};
+use siren::errors::{ConfigError, SirenError};
+use siren::models::{IssueSeverity, LintIssue, LintResult};
+use tempfile::TempDir;
+
+fn issue(file: &Path, line: usize, code: &str) -> LintIssue {
+    LintIssue {
+        severity: IssueSeverity::Warning,
+        message: format!("Problem on line {}", line),
+        file: Some(file.to_path_buf()),
+        line: Some(line),
+        column: Some(1),
+        code: Some(code.to_string()),
+        fix_available: false,
+    }
+}
+
+fn result(tool_name: &str, issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(1),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+#[test]
+fn test_baseline_survives_line_shifts() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("legacy.py");
+    fs::write(&file, "import os\nx  =  eval(data)\n").unwrap();
+
+    let baseline = Baseline::from_results(
+        &[result("pylint", vec![issue(&file, 2, "W0123")])],
+        dir.path(),
+    );
+    assert_eq!(baseline.len(), 1);
+
+    // New code above the old issue, plus whitespace-only edits to its line
+    fs::write(
+        &file,
+        "import os\nimport sys\n\nx = eval(data)\ny = eval(data2)\n",
+    )
+    .unwrap();
+
+    let mut results = vec![result(
+        "pylint",
+        vec![issue(&file, 4, "W0123"), issue(&file, 5, "W0123")],
+    )];
+    assert_eq!(baseline.filter(&mut results, dir.path()), 1);
+
+    // Only the genuinely new issue remains
+    assert_eq!(results[0].issues.len(), 1);
+    assert_eq!(results[0].issues[0].line, Some(5));
+    assert!(!results[0].success);
+}
+
+#[test]
+fn test_baseline_matches_tool_code_and_count() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("dup.py");
+    fs::write(&file, "print(x)\nprint(x)\nprint(x)\n").unwrap();
+
+    // Two identical lines were baselined, a third copy is new
+    let baseline = Baseline::from_results(
+        &[result(
+            "ruff",
+            vec![issue(&file, 1, "F821"), issue(&file, 2, "F821")],
+        )],
+        dir.path(),
+    );
+    assert_eq!(baseline.entries.len(), 1);
+    assert_eq!(baseline.entries[0].count, 2);
+
+    let mut results = vec![
+        result(
+            "ruff",
+            vec![
+                issue(&file, 1, "F821"),
+                issue(&file, 2, "F821"),
+                issue(&file, 3, "F821"),
+            ],
+        ),
+        // Same line and code, but another tool
+        result("pylint", vec![issue(&file, 1, "F821")]),
+    ];
+
+    assert_eq!(baseline.filter(&mut results, dir.path()), 2);
+    assert_eq!(results[0].issues.len(), 1);
+    assert_eq!(results[1].issues.len(), 1);
+}
+
+#[test]
+fn test_baseline_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("app.py");
+    fs::write(&file, "a = 1\n").unwrap();
+    let path = dir.path().join(BASELINE_FILE);
+
+    assert!(Baseline::load(&path).unwrap().is_none());
+
+    let baseline =
+        Baseline::from_results(&[result("mypy", vec![issue(&file, 1, "misc")])], dir.path());
+    baseline.save(&path).unwrap();
+
+    let loaded = Baseline::load(&path).unwrap().unwrap();
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(
+        loaded.entries[0].fingerprint,
+        baseline.entries[0].fingerprint
+    );
+
+    fs::write(&path, r#"{"version": 99, "entries": []}"#).unwrap();
+    assert!(matches!(
+        Baseline::load(&path),
+        Err(SirenError::Config(ConfigError::InvalidValue { .. }))
+    ));
+
+    fs::write(&path, "not json").unwrap();
+    assert!(matches!(
+        Baseline::load(&path),
+        Err(SirenError::Config(ConfigError::ParseError(_)))
+    ));
+}
+
+#[test]
+fn test_baseline_files_are_relative_to_the_project_root() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    let file = dir.path().join("src/app.py");
+    fs::write(&file, "a = 1\n").unwrap();
+
+    let baseline =
+        Baseline::from_results(&[result("mypy", vec![issue(&file, 1, "misc")])], dir.path());
+    assert_eq!(
+        baseline.entries[0].fingerprint.file.as_deref(),
+        Some("src/app.py")
+    );
+
+    // The same file spelled differently by the tool still matches
+    let mut results = vec![result(
+        "mypy",
+        vec![issue(&dir.path().join("src/../src/./app.py"), 1, "misc")],
+    )];
+    assert_eq!(baseline.filter(&mut results, dir.path()), 1);
+}
@@ -0,0 +1,315 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use siren::cache::ResultCache;
+use siren::errors::ToolError;
+use siren::models::{IssueSeverity, Language, LintIssue, LintResult, ToolConfig, ToolType};
+use siren::tools::LintTool;
+use tempfile::TempDir;
+
+/// A Python linter that flags every file containing "TODO"
+struct TodoLinter {
+    version: Option<String>,
+}
+
+impl TodoLinter {
+    fn new(version: &str) -> Self {
+        Self {
+            version: Some(version.to_string()),
+        }
+    }
+}
+
+impl LintTool for TodoLinter {
+    fn name(&self) -> &str {
+        "todo-linter"
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path.extension().is_some_and(|ext| ext == "py")
+    }
+
+    fn execute(&self, files: &[PathBuf], _config: &ToolConfig) -> Result<LintResult, ToolError> {
+        let issues = files
+            .iter()
+            .filter(|file| fs::read_to_string(file).unwrap().contains("TODO"))
+            .map(|file| LintIssue {
+                severity: IssueSeverity::Warning,
+                message: "Unresolved TODO".to_string(),
+                file: Some(file.clone()),
+                line: Some(1),
+                column: None,
+                code: Some("T001".to_string()),
+                fix_available: false,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(LintResult {
+            tool_name: self.name().to_string(),
+            tool: None,
+            success: issues.is_empty(),
+            issues,
+            execution_time: Duration::from_millis(1),
+            stdout: None,
+            stderr: None,
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::Linter
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        vec![Language::Python]
+    }
+
+    fn description(&self) -> &str {
+        "Flags TODO comments"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn version(&self) -> Option<String> {
+        self.version.clone()
+    }
+}
+
+fn config() -> ToolConfig {
+    ToolConfig {
+        enabled: true,
+        extra_args: Vec::new(),
+        env_vars: Default::default(),
+        executable_path: None,
+        report_level: None,
+        auto_fix: false,
+        unsafe_fixes: false,
+        check: false,
+    }
+}
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("clean.py"), "print('hi')\n").unwrap();
+    fs::write(dir.path().join("todo.py"), "# TODO: finish\n").unwrap();
+    fs::write(dir.path().join("notes.txt"), "TODO\n").unwrap();
+    dir
+}
+
+#[test]
+fn test_cache_key_inputs() {
+    let base = ResultCache::key("ruff", "0.4.0", &config(), b"x = 1\n");
+
+    assert_eq!(
+        base,
+        ResultCache::key("ruff", "0.4.0", &config(), b"x = 1\n")
+    );
+    assert_ne!(
+        base,
+        ResultCache::key("ruff", "0.4.0", &config(), b"x = 2\n")
+    );
+    assert_ne!(
+        base,
+        ResultCache::key("ruff", "0.5.0", &config(), b"x = 1\n")
+    );
+    assert_ne!(
+        base,
+        ResultCache::key("black", "0.4.0", &config(), b"x = 1\n")
+    );
+
+    let mut strict = config();
+    strict.extra_args = vec!["--select=ALL".to_string()];
+    assert_ne!(base, ResultCache::key("ruff", "0.4.0", &strict, b"x = 1\n"));
+}
+
+#[test]
+fn test_cache_replays_unchanged_files() {
+    let project = project();
+    let cache = ResultCache::for_project(project.path());
+    let tool = TodoLinter::new("1.0");
+    let paths = vec![project.path().to_path_buf()];
+
+    // Cold cache: everything misses and the tool keeps its original paths
+    let lookup = cache.lookup(&tool, &config(), &paths).unwrap();
+    assert_eq!(lookup.hit_files(), 0);
+    assert_eq!(lookup.missed_files().len(), 2);
+    assert!(!lookup.is_complete());
+    assert_eq!(lookup.paths_to_run(&paths), paths);
+
+    let result = tool.execute(&lookup.missed_files(), &config()).unwrap();
+    cache.store(&lookup, &result);
+
+    // Warm cache: the tool does not need to run at all
+    let lookup = cache.lookup(&tool, &config(), &paths).unwrap();
+    assert!(lookup.is_complete());
+    let replayed = lookup.replay(&tool);
+    assert_eq!(replayed.issues.len(), 1);
+    assert_eq!(
+        replayed.issues[0].file.as_deref(),
+        Some(project.path().join("todo.py").as_path())
+    );
+    assert_eq!(replayed.tool.unwrap().version.as_deref(), Some("1.0"));
+
+    // Editing one file only re-runs that file
+    fs::write(project.path().join("clean.py"), "# TODO: oops\n").unwrap();
+    let lookup = cache.lookup(&tool, &config(), &paths).unwrap();
+    assert_eq!(lookup.hit_files(), 1);
+    assert_eq!(
+        lookup.paths_to_run(&paths),
+        vec![project.path().join("clean.py")]
+    );
+
+    let mut result = tool
+        .execute(&lookup.paths_to_run(&paths), &config())
+        .unwrap();
+    cache.store(&lookup, &result);
+    lookup.merge_into(&mut result);
+    assert_eq!(result.issues.len(), 2);
+    assert!(!result.success);
+
+    // A new tool version invalidates everything
+    let upgraded = TodoLinter::new("2.0");
+    let lookup = cache.lookup(&upgraded, &config(), &paths).unwrap();
+    assert_eq!(lookup.hit_files(), 0);
+}
+
+#[test]
+fn test_cache_skips_unversioned_tools_and_stray_issues() {
+    let project = project();
+    let cache = ResultCache::for_project(project.path());
+    let paths = vec![project.path().join("todo.py")];
+
+    let unversioned = TodoLinter { version: None };
+    assert!(cache.lookup(&unversioned, &config(), &paths).is_none());
+
+    // An issue outside the checked files means the run is not stored
+    let tool = TodoLinter::new("1.0");
+    let lookup = cache.lookup(&tool, &config(), &paths).unwrap();
+    let mut result = tool.execute(&paths, &config()).unwrap();
+    result.issues.push(LintIssue {
+        file: None,
+        ..result.issues[0].clone()
+    });
+    cache.store(&lookup, &result);
+
+    assert!(!cache
+        .lookup(&tool, &config(), &paths)
+        .unwrap()
+        .is_complete());
+    assert!(!cache.dir().exists());
+}
+
+/// A Python checker that flags `a.py` when `b.py` no longer defines the
+/// function it imports, and like clippy only reports issues in the files it
+/// was asked about
+struct ImportChecker {
+    root: PathBuf,
+}
+
+impl LintTool for ImportChecker {
+    fn name(&self) -> &str {
+        "import-checker"
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path.extension().is_some_and(|ext| ext == "py")
+    }
+
+    fn execute(&self, files: &[PathBuf], _config: &ToolConfig) -> Result<LintResult, ToolError> {
+        let importer = self.root.join("a.py");
+        let broken = !fs::read_to_string(self.root.join("b.py"))
+            .unwrap()
+            .contains("def helper");
+        let asked = files.iter().any(|path| importer.starts_with(path));
+
+        let issues: Vec<LintIssue> = (broken && asked)
+            .then(|| LintIssue {
+                severity: IssueSeverity::Error,
+                message: "cannot import name 'helper' from 'b'".to_string(),
+                file: Some(importer),
+                line: Some(1),
+                column: None,
+                code: Some("E0611".to_string()),
+                fix_available: false,
+            })
+            .into_iter()
+            .collect();
+
+        Ok(LintResult {
+            tool_name: self.name().to_string(),
+            tool: None,
+            success: issues.is_empty(),
+            issues,
+            execution_time: Duration::from_millis(1),
+            stdout: None,
+            stderr: None,
+        })
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::Linter
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        vec![Language::Python]
+    }
+
+    fn description(&self) -> &str {
+        "Checks imports across modules"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn version(&self) -> Option<String> {
+        Some("1.0".to_string())
+    }
+
+    fn cross_file(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_cache_reruns_cross_file_tools_on_any_edit() {
+    let project = TempDir::new().unwrap();
+    fs::write(project.path().join("a.py"), "from b import helper\n").unwrap();
+    fs::write(project.path().join("b.py"), "def helper():\n    pass\n").unwrap();
+
+    let cache = ResultCache::for_project(project.path());
+    let tool = ImportChecker {
+        root: project.path().to_path_buf(),
+    };
+    let paths = vec![project.path().to_path_buf()];
+
+    let lookup = cache.lookup(&tool, &config(), &paths).unwrap();
+    let result = tool
+        .execute(&lookup.paths_to_run(&paths), &config())
+        .unwrap();
+    assert!(result.issues.is_empty());
+    cache.store(&lookup, &result);
+    assert!(cache
+        .lookup(&tool, &config(), &paths)
+        .unwrap()
+        .is_complete());
+
+    // Only b.py changes, but the new error is reported in a.py
+    fs::write(project.path().join("b.py"), "def renamed():\n    pass\n").unwrap();
+    let lookup = cache.lookup(&tool, &config(), &paths).unwrap();
+    assert_eq!(lookup.hit_files(), 0);
+    assert_eq!(lookup.paths_to_run(&paths), paths);
+
+    let mut result = tool
+        .execute(&lookup.paths_to_run(&paths), &config())
+        .unwrap();
+    cache.store(&lookup, &result);
+    lookup.merge_into(&mut result);
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(
+        result.issues[0].file.as_deref(),
+        Some(project.path().join("a.py").as_path())
+    );
+}
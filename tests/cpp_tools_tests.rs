@@ -0,0 +1,184 @@
+//! Integration tests for C and C++ tools
+
+use std::fs;
+use std::path::PathBuf;
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language, ToolType};
+use siren::tools::cpp::{compilation_database, ClangFormat, ClangTidy};
+use siren::tools::LintTool;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_parse_clang_output() {
+    let issues = ClangTidy::new().parse_output(
+        "\
+/work/firmware/src/uart.c:42:9: warning: variable 'status' is not initialized [cppcoreguidelines-init-variables]
+   42 |     int status;
+      |         ^
+      |                = 0
+/work/firmware/src/uart.c:42:15: note: FIX-IT applied suggested code changes
+/work/firmware/src/uart.c:57:5: error: use of undeclared identifier 'reg' [clang-diagnostic-error]
+/work/firmware/src/uart.c:60:12: warning: 'strcpy' is insecure [clang-analyzer-security.insecureAPI.strcpy,cert-msc24-c]
+/work/firmware/include/uart.h:12:6: note: previous declaration is here
+",
+    );
+    assert_eq!(issues.len(), 3);
+
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(
+        issues[0].code,
+        Some("cppcoreguidelines-init-variables".to_string())
+    );
+    assert_eq!(issues[0].message, "variable 'status' is not initialized");
+    assert_eq!(
+        issues[0].file,
+        Some(PathBuf::from("/work/firmware/src/uart.c"))
+    );
+    assert_eq!(issues[0].line, Some(42));
+    assert_eq!(issues[0].column, Some(9));
+    assert!(issues[0].fix_available);
+
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].code, Some("clang-diagnostic-error".to_string()));
+    assert!(!issues[1].fix_available);
+    assert_eq!(
+        issues[2].code,
+        Some("clang-analyzer-security.insecureAPI.strcpy,cert-msc24-c".to_string())
+    );
+
+    // clang-format --dry-run, folded into one issue per file
+    let issues = ClangFormat::new().parse_output(
+        "\
+src/uart.c:3:10: warning: code should be clang-formatted [-Wclang-format-violations]
+int main(){
+         ^
+src/uart.c:7:1: warning: code should be clang-formatted [-Wclang-format-violations]
+include/uart.h:1:20: warning: code should be clang-formatted [-Wclang-format-violations]
+",
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].severity, IssueSeverity::Style);
+    assert_eq!(issues[0].file, Some(PathBuf::from("src/uart.c")));
+    assert_eq!(issues[0].line, Some(3));
+    assert!(issues[0].fix_available);
+    assert_eq!(issues[1].file, Some(PathBuf::from("include/uart.h")));
+}
+
+#[test]
+fn test_detect_cpp_tools_and_compilation_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::create_dir_all(root.join("src/drivers")).unwrap();
+    fs::create_dir_all(root.join("build")).unwrap();
+    fs::write(root.join("src/main.c"), "int main(void) { return 0; }\n").unwrap();
+    fs::write(root.join("src/drivers/uart.cpp"), "void uart() {}\n").unwrap();
+    fs::write(root.join(".clang-format"), "BasedOnStyle: LLVM\n").unwrap();
+    fs::write(root.join("build/compile_commands.json"), "[]\n").unwrap();
+
+    // The database in build/ covers everything below the project root
+    let database = root.join("build").canonicalize().unwrap();
+    assert_eq!(
+        compilation_database(&root.join("src/drivers/uart.cpp")),
+        Some(database.clone())
+    );
+    assert_eq!(compilation_database(&root.join("src")), Some(database));
+
+    // A database directly in a directory wins over an outer build/
+    fs::write(root.join("src/drivers/compile_commands.json"), "[]\n").unwrap();
+    assert_eq!(
+        compilation_database(&root.join("src/drivers/uart.cpp")),
+        Some(root.join("src/drivers").canonicalize().unwrap())
+    );
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert!(info.languages.contains(&Language::C));
+    assert!(info.languages.contains(&Language::Cpp));
+
+    let detected = |name: &str| {
+        info.detected_tools
+            .iter()
+            .find(|tool| tool.name == name)
+            .unwrap_or_else(|| panic!("{} should be detected", name))
+            .clone()
+    };
+    assert_eq!(detected("clang-format").tool_type, ToolType::Formatter);
+
+    // The compilation database is not a clang-tidy config; .clang-tidy is
+    assert!(!info
+        .detected_tools
+        .iter()
+        .any(|tool| tool.name == "clang-tidy"));
+    fs::write(root.join(".clang-tidy"), "Checks: 'bugprone-*'\n").unwrap();
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    let clang_tidy = info
+        .detected_tools
+        .iter()
+        .find(|tool| tool.name == "clang-tidy")
+        .expect("clang-tidy should be detected");
+    assert_eq!(clang_tidy.config_path, root.join(".clang-tidy"));
+}
+
+#[test]
+fn test_clang_tidy_runs_once_per_compilation_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    fs::create_dir_all(root.join("firmware/build")).unwrap();
+    fs::create_dir_all(root.join("firmware/src")).unwrap();
+    fs::create_dir_all(root.join("tools")).unwrap();
+    fs::write(root.join("firmware/build/compile_commands.json"), "[]").unwrap();
+    fs::write(
+        root.join("firmware/src/main.c"),
+        "int main(void) { return 0; }\n",
+    )
+    .unwrap();
+    fs::write(root.join("firmware/src/driver.h"), "void init(void);\n").unwrap();
+    fs::write(root.join("tools/gen.cpp"), "int main() {}\n").unwrap();
+    fs::write(root.join("tools/README.md"), "Generators\n").unwrap();
+
+    let fakes = FakePath::install();
+    let clang_tidy = fakes.tool("clang-tidy", "", "", 0);
+
+    ClangTidy::new()
+        .execute(&[root.join("firmware"), root.join("tools")], &tool_config())
+        .unwrap();
+
+    // Headers are only checked through the sources that include them, and
+    // sources without a database get default flags after `--`
+    let runs = clang_tidy.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(
+        runs[0].args,
+        [
+            "--quiet".to_string(),
+            root.join("tools/gen.cpp").display().to_string(),
+            "--".to_string()
+        ]
+    );
+    assert_eq!(
+        runs[1].args,
+        [
+            "--quiet".to_string(),
+            "-p".to_string(),
+            root.join("firmware/build").display().to_string(),
+            root.join("firmware/src/main.c").display().to_string()
+        ]
+    );
+
+    // Nothing to compile means no run
+    ClangTidy::new()
+        .execute(&[root.join("firmware/src/driver.h")], &tool_config())
+        .unwrap();
+    assert_eq!(clang_tidy.runs().len(), 2);
+}
@@ -0,0 +1,279 @@
+//! Integration tests for C# and Swift tools
+
+use std::fs;
+use std::path::PathBuf;
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language, ToolType};
+use siren::tools::csharp::{project_root, DotnetFormat, DotnetFormatCheck};
+use siren::tools::swift::{package_root, SwiftFormat, SwiftLint};
+use siren::tools::{DefaultToolRegistry, LintTool, ToolRegistry};
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_parse_dotnet_format_and_swift_output() {
+    let issues = DotnetFormat::new().parse_output(
+        r#"[
+            {"DocumentId":{"ProjectId":{"Id":"3f1c"},"Id":"9a2e"},"FileName":"Program.cs","FilePath":"/work/App/Program.cs","FileChanges":[
+                {"LineNumber":5,"CharNumber":25,"DiagnosticId":"WHITESPACE","FormatDescription":"Fix whitespace formatting. Replace 3 characters with '\\s'."},
+                {"LineNumber":12,"CharNumber":9,"DiagnosticId":"IDE0005","FormatDescription":"warning IDE0005: Using directive is unnecessary."},
+                {"LineNumber":20,"CharNumber":21,"DiagnosticId":"CA1822","FormatDescription":"warning CA1822: Member 'Greet' does not access instance data and can be marked as static"}
+            ]}
+        ]"#,
+    );
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].severity, IssueSeverity::Style);
+    assert_eq!(issues[0].code, Some("WHITESPACE".to_string()));
+    assert_eq!(issues[0].file, Some(PathBuf::from("/work/App/Program.cs")));
+    assert_eq!(issues[0].line, Some(5));
+    assert_eq!(issues[0].column, Some(25));
+    assert!(issues[0].fix_available);
+    assert_eq!(issues[1].severity, IssueSeverity::Style);
+    assert_eq!(issues[2].severity, IssueSeverity::Warning);
+    assert_eq!(issues[2].code, Some("CA1822".to_string()));
+    assert!(DotnetFormat::new().parse_output("[]").is_empty());
+
+    let issues = SwiftFormat::new().parse_output(
+        "\
+/work/Pkg/Sources/App/main.swift:3:1: warning: [Indentation] replace leading whitespace with 2 spaces
+/work/Pkg/Sources/App/main.swift:9:14: error: expected '}' in class
+",
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].code, Some("Indentation".to_string()));
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(
+        issues[0].message,
+        "replace leading whitespace with 2 spaces"
+    );
+    assert_eq!(issues[0].column, Some(1));
+    assert_eq!(issues[1].code, None);
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].line, Some(9));
+
+    let issues = SwiftLint::new().parse_output(
+        r#"[
+            {"character":null,"file":"/work/Pkg/Sources/App/main.swift","line":4,"reason":"Line should be 120 characters or less; currently it has 131 characters","rule_id":"line_length","severity":"Warning","type":"Line Length"},
+            {"character":7,"file":"/work/Pkg/Sources/App/main.swift","line":10,"reason":"Force casts should be avoided","rule_id":"force_cast","severity":"Error","type":"Force Cast"}
+        ]"#,
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].code, Some("line_length".to_string()));
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[0].column, None);
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].column, Some(7));
+    assert_eq!(
+        issues[1].file,
+        Some(PathBuf::from("/work/Pkg/Sources/App/main.swift"))
+    );
+}
+
+#[test]
+fn test_detect_csharp_and_swift_projects_and_tools() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::create_dir_all(root.join("src/App")).unwrap();
+    fs::write(root.join("Shop.sln"), "\n").unwrap();
+    fs::write(root.join("src/App/App.csproj"), "<Project />\n").unwrap();
+    fs::write(root.join("src/App/Program.cs"), "class Program {}\n").unwrap();
+    fs::write(
+        root.join(".editorconfig"),
+        "[*.cs]\ncsharp_style_var_elsewhere = true\n",
+    )
+    .unwrap();
+
+    fs::create_dir_all(root.join("ios/Sources/Kit")).unwrap();
+    fs::write(
+        root.join("ios/Package.swift"),
+        "// swift-tools-version:5.9\n",
+    )
+    .unwrap();
+    fs::write(root.join("ios/Sources/Kit/Kit.swift"), "struct Kit {}\n").unwrap();
+    fs::write(root.join(".swiftlint.yml"), "disabled_rules:\n  - todo\n").unwrap();
+
+    // The nearest project wins over the solution further up
+    assert_eq!(
+        project_root(&root.join("src/App/Program.cs")),
+        Some(root.join("src/App").canonicalize().unwrap())
+    );
+    assert_eq!(project_root(root), Some(root.canonicalize().unwrap()));
+    assert_eq!(
+        package_root(&root.join("ios/Sources/Kit/Kit.swift")),
+        Some(root.join("ios").canonicalize().unwrap())
+    );
+    assert_eq!(package_root(&root.join("src/App/Program.cs")), None);
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert!(info.languages.contains(&Language::CSharp));
+    assert!(info.languages.contains(&Language::Swift));
+
+    let detected = |name: &str| {
+        info.detected_tools
+            .iter()
+            .find(|tool| tool.name == name)
+            .unwrap_or_else(|| panic!("{} should be detected", name))
+            .clone()
+    };
+    assert_eq!(
+        detected("dotnet-format").config_path,
+        root.join(".editorconfig")
+    );
+    assert_eq!(
+        detected("swiftlint").config_path,
+        root.join(".swiftlint.yml")
+    );
+}
+
+#[test]
+fn test_dotnet_format_runs_per_project_on_requested_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    fs::create_dir_all(root.join("App/src")).unwrap();
+    fs::write(root.join("App/App.sln"), "").unwrap();
+    fs::write(root.join("App/App.csproj"), "<Project/>").unwrap();
+    fs::write(root.join("App/src/Program.cs"), "class Program {}\n").unwrap();
+    fs::write(root.join("App/src/Util.cs"), "class Util {}\n").unwrap();
+    fs::create_dir_all(root.join("Lib")).unwrap();
+    fs::write(root.join("Lib/Lib.csproj"), "<Project/>").unwrap();
+    fs::write(root.join("Lib/Lib.cs"), "class Lib {}\n").unwrap();
+
+    let fakes = FakePath::install();
+    let dotnet = fakes.tool("dotnet", "", "", 0);
+
+    DotnetFormat::new()
+        .execute(
+            &[
+                root.join("App/src/Program.cs"),
+                root.join("App/App.csproj"),
+                root.join("Lib"),
+            ],
+            &tool_config(),
+        )
+        .unwrap();
+
+    let runs = dotnet.runs();
+    assert_eq!(runs.len(), 2);
+
+    // The solution wins over the project, and only the requested file is
+    // included
+    assert_eq!(runs[0].dir, root.join("App"));
+    assert_eq!(
+        runs[0].args[..2],
+        [
+            "format".to_string(),
+            root.join("App/App.sln").display().to_string()
+        ]
+    );
+    assert_eq!(
+        runs[0].args[runs[0].args.len() - 2..],
+        ["--include", "src/Program.cs"]
+    );
+
+    // A whole project needs no --include
+    assert_eq!(runs[1].dir, root.join("Lib"));
+    assert_eq!(
+        runs[1].args[1],
+        root.join("Lib/Lib.csproj").display().to_string()
+    );
+    assert!(!runs[1].args.contains(&"--include".to_string()));
+}
+
+#[test]
+fn test_dotnet_format_check_verifies_without_formatting() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    fs::write(root.join("App.csproj"), "<Project/>").unwrap();
+    fs::write(root.join("Program.cs"), "class Program {}\n").unwrap();
+
+    // `siren check` only runs linters and type checkers
+    let registry = DefaultToolRegistry::with_default_tools();
+    let checkers: Vec<_> = registry
+        .get_tools_for_language_and_type(Language::CSharp, ToolType::Linter)
+        .iter()
+        .map(|tool| tool.name().to_string())
+        .collect();
+    assert_eq!(checkers, ["dotnet-format-check"]);
+
+    // Exit code 2 means files would have been changed
+    let fakes = FakePath::install();
+    let dotnet = fakes.tool("dotnet", "", "", 2);
+
+    let result = DotnetFormatCheck::new()
+        .execute(&[root.join("Program.cs")], &tool_config())
+        .unwrap();
+
+    let runs = dotnet.runs();
+    assert_eq!(runs.len(), 1);
+    assert!(runs[0].args.contains(&"--verify-no-changes".to_string()));
+    assert_eq!(result.tool_name, "dotnet-format-check");
+}
+
+#[test]
+fn test_swiftlint_runs_once_per_package() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    for package in ["Core", "Feature"] {
+        let sources = root.join(package).join("Sources").join(package);
+        fs::create_dir_all(&sources).unwrap();
+        fs::write(
+            root.join(package).join("Package.swift"),
+            "// swift-tools-version:5.9\n",
+        )
+        .unwrap();
+        fs::write(sources.join("main.swift"), "print(1)\n").unwrap();
+        fs::write(sources.join("util.swift"), "let x = 1\n").unwrap();
+    }
+    fs::write(root.join("Feature/README.md"), "# Feature\n").unwrap();
+
+    let fakes = FakePath::install();
+    let swiftlint = fakes.tool("swiftlint", "[]", "", 0);
+
+    SwiftLint::new()
+        .execute(
+            &[
+                root.join("Core/Sources/Core/main.swift"),
+                root.join("Core/Sources/Core/util.swift"),
+                root.join("Feature/Sources"),
+                root.join("Feature/README.md"),
+            ],
+            &tool_config(),
+        )
+        .unwrap();
+
+    // Each package is linted from its root, where .swiftlint.yml lives
+    let runs = swiftlint.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].dir, root.join("Core"));
+    assert_eq!(
+        runs[0].args,
+        [
+            "lint".to_string(),
+            "--reporter".to_string(),
+            "json".to_string(),
+            "--quiet".to_string(),
+            root.join("Core/Sources/Core/main.swift")
+                .display()
+                .to_string(),
+            root.join("Core/Sources/Core/util.swift")
+                .display()
+                .to_string()
+        ]
+    );
+    assert_eq!(runs[1].dir, root.join("Feature"));
+    assert_eq!(
+        runs[1].args[4..],
+        [root.join("Feature/Sources").display().to_string()]
+    );
+}
@@ -0,0 +1,169 @@
+//! Integration tests for CSS tools
+
+use std::fs;
+use std::path::PathBuf;
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language};
+use siren::tools::css::{Stylelint, StylelintFixer};
+use siren::tools::LintTool;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_parse_stylelint_output() {
+    let output = r##"[
+        {
+            "source": "/work/design-system/src/_buttons.scss",
+            "deprecations": [],
+            "invalidOptionWarnings": [],
+            "parseErrors": [],
+            "errored": true,
+            "warnings": [
+                {
+                    "line": 4,
+                    "column": 3,
+                    "endLine": 4,
+                    "endColumn": 20,
+                    "rule": "color-no-invalid-hex",
+                    "severity": "error",
+                    "text": "Unexpected invalid hex color \"#ggg\" (color-no-invalid-hex)"
+                },
+                {
+                    "line": 9,
+                    "column": 1,
+                    "rule": "scss/at-extend-no-missing-placeholder",
+                    "severity": "warning",
+                    "text": "Expected a placeholder selector (e.g. %placeholder) to be used in @extend (scss/at-extend-no-missing-placeholder)",
+                    "fix": {"range": [10, 12], "text": ""}
+                }
+            ]
+        },
+        {
+            "source": "/work/design-system/src/_tokens.scss",
+            "errored": false,
+            "warnings": [],
+            "invalidOptionWarnings": [{"text": "Invalid option value \"tab\" for rule \"indentation\""}]
+        }
+    ]"##;
+
+    let issues = Stylelint::new().parse_output(output);
+    assert_eq!(issues.len(), 3);
+
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[0].message, "Unexpected invalid hex color \"#ggg\"");
+    assert_eq!(issues[0].code, Some("color-no-invalid-hex".to_string()));
+    assert_eq!(
+        issues[0].file,
+        Some(PathBuf::from("/work/design-system/src/_buttons.scss"))
+    );
+    assert_eq!(issues[0].line, Some(4));
+    assert_eq!(issues[0].column, Some(3));
+    assert!(!issues[0].fix_available);
+
+    assert_eq!(issues[1].severity, IssueSeverity::Warning);
+    assert_eq!(
+        issues[1].code,
+        Some("scss/at-extend-no-missing-placeholder".to_string())
+    );
+    assert!(issues[1].fix_available);
+
+    assert_eq!(issues[2].severity, IssueSeverity::Error);
+    assert_eq!(issues[2].file, None);
+
+    assert!(Stylelint::new().parse_output("").is_empty());
+    assert!(Stylelint::new().parse_output("[]").is_empty());
+}
+
+#[test]
+fn test_detect_scss_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/_buttons.scss"), ".btn { color: red; }\n").unwrap();
+    fs::write(root.join("src/theme.less"), "@primary: blue;\n").unwrap();
+    fs::write(
+        root.join("stylelint.config.mjs"),
+        "export default { extends: ['stylelint-config-standard-scss'] };\n",
+    )
+    .unwrap();
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert_eq!(info.languages, vec![Language::Css]);
+    assert_eq!(info.file_counts.get(&Language::Css), Some(&2));
+
+    let stylelint = info
+        .detected_tools
+        .iter()
+        .find(|tool| tool.name == "stylelint")
+        .expect("stylelint should be detected");
+    assert_eq!(stylelint.config_path, root.join("stylelint.config.mjs"));
+}
+
+#[test]
+fn test_stylelint_gets_globs_for_directories_and_skips_other_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    fs::create_dir_all(root.join("styles")).unwrap();
+    fs::write(root.join("styles/theme.scss"), "a { color: red; }\n").unwrap();
+    fs::write(root.join("app.css"), "a { color: #ggg; }\n").unwrap();
+    fs::write(root.join("app.js"), "export {};\n").unwrap();
+
+    // Stylelint 16 reports on stderr and exits with 2 when it finds problems
+    let report = format!(
+        r#"[{{"source": "{}", "warnings": [{{"line": 1, "column": 12, "rule": "color-no-invalid-hex", "severity": "error", "text": "Unexpected invalid hex color (color-no-invalid-hex)"}}]}}]"#,
+        root.join("app.css").display()
+    );
+    let fakes = FakePath::install();
+    let stylelint = fakes.tool("stylelint", "", &report, 2);
+
+    let files = [
+        root.join("styles"),
+        root.join("app.css"),
+        root.join("app.js"),
+    ];
+    let result = Stylelint::new().execute(&files, &tool_config()).unwrap();
+    StylelintFixer::new()
+        .execute(&files, &tool_config())
+        .unwrap();
+
+    let styles_glob = format!(
+        "{}/**/*.{{css,scss,sass,less}}",
+        root.join("styles").display()
+    );
+    let app_css = root.join("app.css").display().to_string();
+    let runs = stylelint.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(
+        runs[0].args,
+        [
+            "--formatter",
+            "json",
+            "--allow-empty-input",
+            &styles_glob,
+            &app_css
+        ]
+    );
+    assert_eq!(
+        runs[1].args,
+        [
+            "--formatter",
+            "json",
+            "--allow-empty-input",
+            "--fix",
+            &styles_glob,
+            &app_css
+        ]
+    );
+
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.issues[0].file, Some(root.join("app.css")));
+    assert_eq!(result.issues[0].severity, IssueSeverity::Error);
+}
@@ -23,6 +23,7 @@ fn create_test_config() -> ModelsToolConfig {
         executable_path: None,
         report_level: None,
         auto_fix: false,
+        unsafe_fixes: false,
         check: true,
     }
 }
@@ -131,7 +132,9 @@ fn test_djlint_integration() {
     // Test linting
     let linter = DjLint::new();
     let config = create_test_config();
-    let result = linter.execute(&[file_path.clone()], &config).unwrap();
+    let result = linter
+        .execute(std::slice::from_ref(&file_path), &config)
+        .unwrap();
 
     // Should find issues (H021 for inline style, H020 for empty div)
     assert!(!result.success);
@@ -143,7 +146,7 @@ fn test_djlint_integration() {
     format_config.auto_fix = true;
 
     let format_result = formatter
-        .execute(&[file_path.clone()], &format_config)
+        .execute(std::slice::from_ref(&file_path), &format_config)
         .unwrap();
     assert!(format_result.success);
 
@@ -0,0 +1,173 @@
+//! Integration tests for Dockerfile and Makefile tools
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language};
+use siren::tools::docker::Hadolint;
+use siren::tools::make::Checkmake;
+use siren::tools::LintTool;
+use siren::utils::filename_language;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_dockerfiles_and_makefiles_detected_by_name() {
+    for name in [
+        "Dockerfile",
+        "Containerfile",
+        "Dockerfile.prod",
+        "api.Dockerfile",
+        "worker.dockerfile",
+    ] {
+        assert_eq!(
+            filename_language(Path::new(name)),
+            Some(Language::Docker),
+            "{}",
+            name
+        );
+    }
+    for name in ["Makefile", "makefile", "GNUmakefile"] {
+        assert_eq!(
+            filename_language(Path::new(name)),
+            Some(Language::Makefile),
+            "{}",
+            name
+        );
+    }
+    assert_eq!(filename_language(Path::new("docker-compose.yml")), None);
+    assert_eq!(filename_language(Path::new("Makefile.am")), None);
+}
+
+#[test]
+fn test_parse_hadolint_and_checkmake_output() {
+    let issues = Hadolint::new().parse_output(
+        r#"[
+            {"code":"DL3008","column":1,"file":"Dockerfile","level":"warning","line":4,"message":"Pin versions in apt get install. Instead of `apt-get install <package>` use `apt-get install <package>=<version>`"},
+            {"code":"SC2086","column":1,"file":"Dockerfile","level":"info","line":9,"message":"Double quote to prevent globbing and word splitting."},
+            {"code":"DL3000","column":1,"file":"deploy/Dockerfile.prod","level":"error","line":2,"message":"Use absolute WORKDIR"}
+        ]"#,
+    );
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].code, Some("DL3008".to_string()));
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[0].line, Some(4));
+    assert_eq!(issues[0].column, Some(1));
+    assert_eq!(issues[1].code, Some("SC2086".to_string()));
+    assert_eq!(issues[1].severity, IssueSeverity::Info);
+    assert_eq!(issues[2].severity, IssueSeverity::Error);
+    assert_eq!(
+        issues[2].file,
+        Some(PathBuf::from("deploy/Dockerfile.prod"))
+    );
+    assert!(Hadolint::new().parse_output("[]").is_empty());
+
+    let issues = Checkmake::new().parse_output(
+        "\
+Makefile:0:minphony:Missing required phony target \"test\"
+Makefile:12:maxbodylength:Target body for \"release\" exceeds allowed length of 5 (8).
+",
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].code, Some("minphony".to_string()));
+    assert_eq!(issues[0].message, "Missing required phony target \"test\"");
+    assert_eq!(issues[0].line, None);
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[1].code, Some("maxbodylength".to_string()));
+    assert_eq!(issues[1].line, Some(12));
+    assert_eq!(issues[1].file, Some(PathBuf::from("Makefile")));
+}
+
+#[test]
+fn test_detect_docker_and_make_tools() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::create_dir_all(root.join("deploy")).unwrap();
+    fs::write(root.join("deploy/Dockerfile.prod"), "FROM alpine:3.19\n").unwrap();
+    fs::write(root.join("Makefile"), "all:\n\techo hi\n").unwrap();
+    fs::write(root.join(".hadolint.yaml"), "ignored:\n  - DL3008\n").unwrap();
+    fs::write(
+        root.join("checkmake.ini"),
+        "[maxbodylength]\nmaxBodyLength = 10\n",
+    )
+    .unwrap();
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert!(info.languages.contains(&Language::Docker));
+    assert!(info.languages.contains(&Language::Makefile));
+    assert_eq!(info.file_counts.get(&Language::Docker), Some(&1));
+
+    let detected = |name: &str| {
+        info.detected_tools
+            .iter()
+            .find(|tool| tool.name == name)
+            .unwrap_or_else(|| panic!("{} should be detected", name))
+            .clone()
+    };
+    assert_eq!(
+        detected("hadolint").config_path,
+        root.join(".hadolint.yaml")
+    );
+    assert_eq!(
+        detected("checkmake").config_path,
+        root.join("checkmake.ini")
+    );
+}
+
+#[test]
+fn test_hadolint_and_checkmake_get_files_by_name() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    fs::create_dir_all(root.join("deploy")).unwrap();
+    fs::create_dir_all(root.join("build")).unwrap();
+    fs::write(root.join("deploy/Dockerfile.prod"), "FROM alpine\n").unwrap();
+    fs::write(root.join("deploy/compose.yaml"), "services: {}\n").unwrap();
+    fs::write(root.join("build/rules.mk"), "all:\n").unwrap();
+    fs::write(root.join("GNUmakefile"), "all:\n").unwrap();
+    fs::write(root.join("Makefile.am"), "SUBDIRS = src\n").unwrap();
+
+    let fakes = FakePath::install();
+    let hadolint = fakes.tool("hadolint", "[]", "", 0);
+    let checkmake = fakes.tool("checkmake", "", "", 0);
+
+    let files = [
+        root.join("deploy"),
+        root.join("build"),
+        root.join("GNUmakefile"),
+        root.join("Makefile.am"),
+    ];
+    Hadolint::new().execute(&files, &tool_config()).unwrap();
+    Checkmake::new().execute(&files, &tool_config()).unwrap();
+
+    let path = |name: &str| root.join(name).display().to_string();
+
+    // Directories are expanded here, since neither tool walks them
+    let runs = hadolint.runs();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(
+        runs[0].args,
+        [
+            "--format".to_string(),
+            "json".to_string(),
+            "--no-fail".to_string(),
+            path("deploy/Dockerfile.prod")
+        ]
+    );
+
+    let runs = checkmake.runs();
+    assert_eq!(runs.len(), 1);
+    let makefiles: Vec<&String> = runs[0]
+        .args
+        .iter()
+        .filter(|arg| arg.starts_with('/'))
+        .collect();
+    assert_eq!(makefiles, [&path("build/rules.mk"), &path("GNUmakefile")]);
+}
@@ -150,6 +150,7 @@ async fn test_executor_run_tool() {
         executable_path: None,
         report_level: None,
         auto_fix: false,
+        unsafe_fixes: false,
         check: true,
     };
 
@@ -218,6 +219,7 @@ async fn test_executor_run_tools_for_language() {
         executable_path: None,
         report_level: None,
         auto_fix: false,
+        unsafe_fixes: false,
         check: true,
     };
 
@@ -286,6 +288,7 @@ async fn test_executor_run_tools_for_language_and_type() {
         executable_path: None,
         report_level: None,
         auto_fix: false,
+        unsafe_fixes: false,
         check: true,
     };
 
@@ -0,0 +1,221 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use siren::cli::{CheckArgs, Verbosity};
+use siren::commands::{check_status, CheckCommand, CheckStatus};
+use siren::config::SirenConfig;
+use siren::detection::DefaultProjectDetector;
+use siren::errors::{exit_code, ConfigError, SirenError, ToolError};
+use siren::models::{IssueSeverity, Language, LintIssue, LintResult, ToolConfig, ToolType};
+use siren::output::JsonFormatter;
+use siren::tools::{DefaultToolRegistry, LintTool, ToolRegistry};
+use tempfile::TempDir;
+
+/// A Python linter that reports one issue of a fixed severity, or fails
+struct MockLinter {
+    severity: Option<IssueSeverity>,
+    fails: bool,
+}
+
+impl LintTool for MockLinter {
+    fn name(&self) -> &str {
+        "mock-linter"
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path.extension().is_some_and(|ext| ext == "py")
+    }
+
+    fn execute(&self, files: &[PathBuf], _config: &ToolConfig) -> Result<LintResult, ToolError> {
+        if self.fails {
+            return Err(ToolError::ExecutionFailed {
+                name: self.name().to_string(),
+                message: "boom".to_string(),
+            });
+        }
+
+        Ok(result(
+            self.severity
+                .map(|severity| issue(severity, files.first().cloned()))
+                .into_iter()
+                .collect(),
+        ))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::Linter
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        vec![Language::Python]
+    }
+
+    fn description(&self) -> &str {
+        "Mock linter"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn version(&self) -> Option<String> {
+        None
+    }
+}
+
+fn issue(severity: IssueSeverity, file: Option<PathBuf>) -> LintIssue {
+    LintIssue {
+        severity,
+        message: "Mock issue".to_string(),
+        file,
+        line: Some(1),
+        column: None,
+        code: None,
+        fix_available: false,
+    }
+}
+
+fn result(issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: "mock-linter".to_string(),
+        tool: None,
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(1),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+async fn run_check(linter: MockLinter, fail_level: &str) -> Result<CheckStatus, SirenError> {
+    let project = TempDir::new().unwrap();
+    fs::write(project.path().join("main.py"), "print('hello')\n").unwrap();
+
+    let mut registry = DefaultToolRegistry::new();
+    registry.register_tool(Arc::new(linter));
+
+    let mut config = SirenConfig::default();
+    config.general.fail_level = fail_level.to_string();
+
+    let command = CheckCommand::new(
+        DefaultProjectDetector::new(),
+        registry,
+        JsonFormatter::new(),
+        Verbosity::Quiet,
+    );
+    let args = CheckArgs {
+        strict: false,
+        tools: None,
+        tool_types: None,
+        format: "json".to_string(),
+        auto_fix: false,
+        no_cache: false,
+        watch: false,
+        write_baseline: false,
+        no_baseline: false,
+        diff_base: None,
+        changed_lines: false,
+        staged: false,
+        paths: Vec::new(),
+    };
+
+    command
+        .execute(args, vec![project.path().to_path_buf()], false, &config)
+        .await
+}
+
+#[test]
+fn test_check_status_thresholds() {
+    let warnings = vec![result(vec![issue(IssueSeverity::Warning, None)])];
+
+    assert_eq!(
+        check_status(&warnings, &[], IssueSeverity::Error),
+        CheckStatus::Passed
+    );
+    assert_eq!(
+        check_status(&warnings, &[], IssueSeverity::Warning),
+        CheckStatus::IssuesFound
+    );
+    assert_eq!(
+        check_status(&warnings, &[], IssueSeverity::Style),
+        CheckStatus::IssuesFound
+    );
+
+    // A failed tool wins, the results are incomplete
+    assert_eq!(
+        check_status(&warnings, &["ruff".to_string()], IssueSeverity::Error),
+        CheckStatus::ToolFailed
+    );
+}
+
+#[test]
+fn test_exit_codes_are_distinct() {
+    assert_eq!(CheckStatus::Passed.exit_code(), exit_code::SUCCESS);
+    assert_eq!(
+        CheckStatus::IssuesFound.exit_code(),
+        exit_code::ISSUES_FOUND
+    );
+    assert_eq!(CheckStatus::ToolFailed.exit_code(), exit_code::TOOL_FAILED);
+
+    let config_error = SirenError::from(ConfigError::ParseError("bad".to_string()));
+    assert_eq!(config_error.exit_code(), exit_code::CONFIG_ERROR);
+
+    let tool_error = SirenError::from(ToolError::NotFound("ruff".to_string()));
+    assert_eq!(tool_error.exit_code(), exit_code::TOOL_FAILED);
+
+    let codes = [
+        exit_code::SUCCESS,
+        exit_code::ISSUES_FOUND,
+        exit_code::CONFIG_ERROR,
+        exit_code::TOOL_FAILED,
+        exit_code::INTERNAL_ERROR,
+    ];
+    for (i, a) in codes.iter().enumerate() {
+        for b in &codes[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_check_command_honours_fail_level() {
+    let warning = || MockLinter {
+        severity: Some(IssueSeverity::Warning),
+        fails: false,
+    };
+
+    assert_eq!(
+        run_check(warning(), "error").await.unwrap(),
+        CheckStatus::Passed
+    );
+    assert_eq!(
+        run_check(warning(), "warning").await.unwrap(),
+        CheckStatus::IssuesFound
+    );
+
+    let failing = MockLinter {
+        severity: None,
+        fails: true,
+    };
+    assert_eq!(
+        run_check(failing, "error").await.unwrap(),
+        CheckStatus::ToolFailed
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_fail_level_is_a_config_error() {
+    let linter = MockLinter {
+        severity: None,
+        fails: false,
+    };
+
+    let err = run_check(linter, "catastrophic").await.unwrap_err();
+    assert!(matches!(
+        err,
+        SirenError::Config(ConfigError::InvalidValue { .. })
+    ));
+    assert_eq!(err.exit_code(), exit_code::CONFIG_ERROR);
+}
@@ -76,7 +76,7 @@ mod file_selection {
             let git_files = mock_utils::get_git_modified_files(dir)?;
 
             // Filter git files to only include those that match our paths
-            if paths.len() == 1 && paths[0] == PathBuf::from(".") {
+            if paths.len() == 1 && paths[0] == Path::new(".") {
                 // If only the current directory is specified, use all git files
                 Ok(git_files)
             } else {
@@ -97,7 +97,7 @@ mod file_selection {
             let mut all_files = Vec::new();
 
             // If only the current directory is specified, scan it
-            if paths.len() == 1 && paths[0] == PathBuf::from(".") {
+            if paths.len() == 1 && paths[0] == Path::new(".") {
                 let dir_files = mock_utils::collect_files_with_gitignore(Path::new("."))?;
                 all_files.extend(dir_files);
             } else {
@@ -299,3 +299,55 @@ fn test_filter_files_for_tool() {
         );
     }
 }
+
+#[test]
+fn test_walk_files_expands_directories_and_skips_ignored() {
+    let temp_dir = create_test_directory();
+    let base_dir = temp_dir.path();
+    fs::create_dir(base_dir.join(".git")).unwrap();
+    let mut gitignore = fs::OpenOptions::new()
+        .append(true)
+        .open(base_dir.join(".gitignore"))
+        .unwrap();
+    writeln!(gitignore, "nested/").unwrap();
+
+    let is_python = |path: &Path| path.extension().is_some_and(|ext| ext == "py");
+    let mut files = siren::utils::walk_files(
+        &[base_dir.to_path_buf(), base_dir.join("file3.rs")],
+        is_python,
+    );
+    files.sort();
+
+    // ignored.py and nested/ are skipped, the named Rust file does not match
+    assert_eq!(
+        files,
+        get_absolute_paths(base_dir, &["file1.py", "file2.py", "subdir/file4.py"])
+    );
+}
+
+#[test]
+fn test_find_ancestor_with_marker() {
+    let temp_dir = create_test_directory();
+    let base_dir = temp_dir.path().canonicalize().unwrap();
+    fs::write(base_dir.join("subdir/go.mod"), "module example\n").unwrap();
+
+    let has_go_mod = |dir: &Path| dir.join("go.mod").is_file();
+
+    // Files start from their directory, directories from themselves
+    assert_eq!(
+        siren::utils::find_ancestor_with(&base_dir.join("subdir/nested/file6.py"), has_go_mod),
+        Some(base_dir.join("subdir"))
+    );
+    assert_eq!(
+        siren::utils::find_ancestor_with(&base_dir.join("subdir"), has_go_mod),
+        Some(base_dir.join("subdir"))
+    );
+    assert_eq!(
+        siren::utils::find_ancestor_with(&base_dir.join("file1.py"), has_go_mod),
+        None
+    );
+    assert_eq!(
+        siren::utils::find_ancestor_with(&base_dir.join("missing.py"), has_go_mod),
+        None
+    );
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use siren::config::OutputConfig;
+use siren::errors::SirenError;
+use siren::models::LintResult;
+use siren::output::{create_formatter, OutputFormat, OutputFormatter};
+
+fn empty_result(tool_name: &str) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: true,
+        issues: Vec::new(),
+        execution_time: Duration::from_millis(1),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+#[test]
+fn test_output_format_parsing() {
+    assert_eq!("pretty".parse::<OutputFormat>(), Ok(OutputFormat::Pretty));
+    assert_eq!("JSON".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+    assert_eq!("sarif".parse::<OutputFormat>(), Ok(OutputFormat::Sarif));
+    assert!("xml".parse::<OutputFormat>().is_err());
+}
+
+#[test]
+fn test_create_formatter_by_name() {
+    assert!(!create_formatter("pretty").unwrap().is_machine_readable());
+    assert!(create_formatter("json").unwrap().is_machine_readable());
+    assert!(create_formatter("sarif").unwrap().is_machine_readable());
+    assert!(create_formatter("junit").unwrap().is_machine_readable());
+    assert!(!create_formatter("github").unwrap().is_machine_readable());
+
+    assert!(matches!(
+        create_formatter("yaml"),
+        Err(SirenError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn test_json_formatter_emits_valid_json() {
+    let formatter = create_formatter("json").unwrap();
+    let config = OutputConfig::default();
+
+    // Even an empty run must produce a parseable document
+    let empty: serde_json::Value =
+        serde_json::from_str(&formatter.format_results(&[], &config)).unwrap();
+    assert_eq!(empty, serde_json::json!([]));
+
+    let output = formatter.format_results(&[empty_result("ruff")], &config);
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(parsed[0]["tool_name"], "ruff");
+}
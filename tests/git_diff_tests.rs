@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use siren::models::{IssueSeverity, LintIssue, LintResult};
+use siren::utils::get_git_modified_files;
+use siren::utils::git::{parse_hunks, ChangeSet, ChangedLines};
+use tempfile::TempDir;
+
+fn issue(file: &Path, line: usize) -> LintIssue {
+    LintIssue {
+        severity: IssueSeverity::Warning,
+        message: format!("issue on line {}", line),
+        file: Some(file.to_path_buf()),
+        line: Some(line),
+        column: Some(1),
+        code: None,
+        fix_available: false,
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args([
+            "-c",
+            "user.name=siren",
+            "-c",
+            "user.email=siren@example.com",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git should run");
+    assert!(status.status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn test_parse_hunks() {
+    let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -3 +3 @@ fn main() {
+-    old();
++    new();
+@@ -10,0 +11,3 @@ fn main() {
++    a();
++    b();
++    c();
+@@ -20,2 +22,0 @@ fn helper() {
+-    gone();
+-    gone();
+diff --git a/new.py b/new.py
+new file mode 100644
+--- /dev/null
++++ b/new.py
+@@ -0,0 +1,2 @@
++import os
++print(os.name)
+";
+
+    let hunks = parse_hunks(diff);
+    assert_eq!(
+        hunks.get(Path::new("src/lib.rs")),
+        Some(&ChangedLines::Ranges(vec![3..=3, 11..=13]))
+    );
+    assert_eq!(
+        hunks.get(Path::new("new.py")),
+        Some(&ChangedLines::Ranges(vec![1..=2]))
+    );
+    assert_eq!(hunks.len(), 2);
+}
+
+#[test]
+fn test_filter_results_keeps_changed_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let changed = temp_dir.path().join("changed.rs");
+    let added = temp_dir.path().join("added.rs");
+    let untouched = temp_dir.path().join("untouched.rs");
+    for file in [&changed, &added, &untouched] {
+        fs::write(file, "fn main() {}\n").unwrap();
+    }
+
+    let mut files = BTreeMap::new();
+    files.insert(changed.clone(), ChangedLines::Ranges(vec![5..=7]));
+    files.insert(added.clone(), ChangedLines::All);
+    let changeset = ChangeSet::from_files(files);
+
+    let mut results = vec![LintResult {
+        tool_name: "clippy".to_string(),
+        tool: None,
+        success: false,
+        issues: vec![
+            issue(&changed, 4),
+            issue(&changed, 6),
+            issue(&added, 100),
+            issue(&untouched, 6),
+        ],
+        execution_time: Duration::from_millis(1),
+        stdout: None,
+        stderr: None,
+    }];
+
+    assert_eq!(changeset.filter_results(&mut results), 2);
+    let kept: Vec<_> = results[0]
+        .issues
+        .iter()
+        .map(|issue| (issue.file.clone().unwrap(), issue.line.unwrap()))
+        .collect();
+    assert_eq!(kept, vec![(changed, 6), (added, 100)]);
+    assert!(!results[0].success);
+}
+
+#[test]
+fn test_changes_since_merge_base_include_untracked_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    git(root, &["init", "-q", "-b", "main"]);
+    fs::write(root.join("kept.py"), "a = 1\nb = 2\nc = 3\n").unwrap();
+    fs::write(root.join("clean.py"), "x = 1\n").unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "initial"]);
+
+    // A feature branch commit, an unstaged edit and an untracked file
+    git(root, &["checkout", "-q", "-b", "feature"]);
+    fs::write(root.join("kept.py"), "a = 1\nb = 20\nc = 3\n").unwrap();
+    git(root, &["commit", "-q", "-am", "change b"]);
+    fs::write(root.join("kept.py"), "a = 1\nb = 20\nc = 3\nd = 4\n").unwrap();
+    fs::write(root.join("new.py"), "y = 2\n").unwrap();
+
+    let changeset = ChangeSet::since(root, "main").unwrap();
+    let names: Vec<PathBuf> = changeset
+        .files()
+        .iter()
+        .map(|file| PathBuf::from(file.file_name().unwrap()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![PathBuf::from("kept.py"), PathBuf::from("new.py")]
+    );
+
+    let kept = root.join("kept.py");
+    assert!(!changeset.contains(&kept, Some(1)));
+    assert!(changeset.contains(&kept, Some(2)));
+    assert!(changeset.contains(&kept, Some(4)));
+    assert!(changeset.contains(&root.join("new.py"), Some(1)));
+    assert!(!changeset.contains(&root.join("clean.py"), Some(1)));
+
+    assert!(ChangeSet::since(root, "no-such-branch").is_err());
+
+    // --git-modified sees the unstaged edit and the untracked file too
+    let mut modified: Vec<PathBuf> = get_git_modified_files(root)
+        .unwrap()
+        .iter()
+        .map(|file| PathBuf::from(file.file_name().unwrap()))
+        .collect();
+    modified.sort();
+    assert_eq!(
+        modified,
+        vec![PathBuf::from("kept.py"), PathBuf::from("new.py")]
+    );
+}
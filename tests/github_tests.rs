@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use siren::cli::Verbosity;
+use siren::commands::{CheckCommand, CheckRun, CheckStatus};
+use siren::config::{OutputConfig, SirenConfig};
+use siren::detection::DefaultProjectDetector;
+use siren::models::{IssueSeverity, LintIssue, LintResult, ProjectInfo};
+use siren::output::github::GITHUB_STEP_SUMMARY_ENV;
+use siren::output::{create_formatter, GithubFormatter, OutputFormatter};
+use siren::suppression::SuppressedCounts;
+use siren::tools::DefaultToolRegistry;
+use tempfile::TempDir;
+
+fn issue(severity: IssueSeverity, code: Option<&str>, message: &str) -> LintIssue {
+    LintIssue {
+        severity,
+        message: message.to_string(),
+        file: Some(PathBuf::from("src/app.py")),
+        line: Some(12),
+        column: Some(4),
+        code: code.map(String::from),
+        fix_available: false,
+    }
+}
+
+fn result(tool_name: &str, issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(10),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+#[test]
+fn test_github_annotation_format() {
+    let formatter = GithubFormatter::new();
+
+    assert_eq!(
+        formatter.format_annotation(
+            "ruff",
+            &issue(IssueSeverity::Error, Some("F401"), "unused import")
+        ),
+        "::error file=src/app.py,line=12,col=4,title=ruff/F401::unused import"
+    );
+
+    // Info and style map to notices, a missing code leaves the tool as title
+    assert!(formatter
+        .format_annotation("pylint", &issue(IssueSeverity::Style, None, "x"))
+        .starts_with("::notice file=src/app.py,line=12,col=4,title=pylint::"));
+}
+
+#[test]
+fn test_github_annotation_escaping() {
+    let formatter = GithubFormatter::new();
+    let annotation = formatter.format_annotation(
+        "clippy",
+        &issue(
+            IssueSeverity::Warning,
+            Some("clippy::unwrap_used"),
+            "100% bad\nsecond line",
+        ),
+    );
+
+    assert_eq!(
+        annotation,
+        "::warning file=src/app.py,line=12,col=4,title=clippy/clippy%3A%3Aunwrap_used::100%25 bad%0Asecond line"
+    );
+}
+
+#[test]
+fn test_github_results_and_summary() {
+    let formatter = create_formatter("github").unwrap();
+    let results = vec![
+        result(
+            "ruff",
+            vec![
+                issue(IssueSeverity::Error, Some("F401"), "unused import"),
+                issue(IssueSeverity::Warning, Some("E501"), "line too long"),
+            ],
+        ),
+        result("mypy", Vec::new()),
+    ];
+
+    let output = formatter.format_results(&results, &OutputConfig::default());
+    assert_eq!(output.lines().count(), 2);
+    assert!(output.lines().all(|line| line.starts_with("::")));
+
+    let summary = GithubFormatter::new().step_summary(&results);
+    assert!(summary.contains("**2** issues in **1** files"));
+    assert!(summary.contains("| ruff | 1 | 1 | 0 | 0 |"));
+    assert!(summary.contains("| mypy | 0 | 0 | 0 | 0 |"));
+
+    assert!(GithubFormatter::new()
+        .step_summary(&[])
+        .contains("No issues found"));
+}
+
+#[test]
+fn test_step_summary_written_for_clean_runs() {
+    let temp_dir = TempDir::new().unwrap();
+    let summary_file = temp_dir.path().join("step_summary.md");
+    std::env::set_var(GITHUB_STEP_SUMMARY_ENV, &summary_file);
+
+    let command = CheckCommand::new(
+        DefaultProjectDetector::new(),
+        DefaultToolRegistry::new(),
+        GithubFormatter::new(),
+        Verbosity::Quiet,
+    );
+    let run = CheckRun {
+        project_info: ProjectInfo {
+            languages: Vec::new(),
+            frameworks: Vec::new(),
+            file_counts: HashMap::new(),
+            detected_tools: Vec::new(),
+        },
+        results: Vec::new(),
+        failed_tools: Vec::new(),
+        suppressed: SuppressedCounts::default(),
+    };
+
+    // Rendering the summary alone has no side effects
+    GithubFormatter::new().format_summary(&[], &SuppressedCounts::default());
+    assert!(!summary_file.exists());
+
+    let status = command.print_run(&run, &SirenConfig::default(), IssueSeverity::Error);
+    std::env::remove_var(GITHUB_STEP_SUMMARY_ENV);
+
+    assert_eq!(status, CheckStatus::Passed);
+    assert!(fs::read_to_string(&summary_file)
+        .unwrap()
+        .contains("No issues found"));
+}
@@ -0,0 +1,243 @@
+//! Integration tests for Go tools
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use siren::errors::ToolError;
+use siren::models::IssueSeverity;
+use siren::tools::go::{package_patterns, GoVet, Gofmt, GolangciLint};
+use siren::tools::LintTool;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+/// Two modules side by side, plus a Go file outside of any module
+fn two_modules() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    for module in ["api", "worker"] {
+        fs::create_dir_all(root.join(module).join("internal/db")).unwrap();
+        fs::write(
+            root.join(module).join("go.mod"),
+            format!("module example.com/{}\n", module),
+        )
+        .unwrap();
+        fs::write(root.join(module).join("main.go"), "package main\n").unwrap();
+        fs::write(root.join(module).join("internal/db/db.go"), "package db\n").unwrap();
+    }
+    fs::write(root.join("script.go"), "package main\n").unwrap();
+
+    (temp_dir, root)
+}
+
+#[test]
+fn test_parse_go_tool_output() {
+    let root = Path::new("/work/service");
+
+    // gofmt -l lists files, syntax errors go to stderr
+    let issues = Gofmt::new().parse_output(
+        "main.go\ninternal/db/db.go\n",
+        "broken.go:3:1: expected declaration, found foo\n",
+    );
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].file, Some(PathBuf::from("main.go")));
+    assert_eq!(issues[0].severity, IssueSeverity::Style);
+    assert!(issues[0].fix_available);
+    assert_eq!(issues[2].severity, IssueSeverity::Error);
+    assert_eq!(issues[2].line, Some(3));
+
+    // go vet -json, with a build error from another package in between
+    let vet_output = r#"# example.com/service
+{
+	"example.com/service": {
+		"printf": [
+			{
+				"posn": "/work/service/main.go:10:2",
+				"message": "fmt.Printf format %d has arg s of wrong type string"
+			}
+		]
+	}
+}
+# example.com/service/db
+vet: db/db.go:4:2: undefined: sql
+"#;
+    let issues = GoVet::new().parse_output(vet_output, root);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[0].file, Some(root.join("db/db.go")));
+    assert_eq!(issues[0].message, "undefined: sql");
+    assert_eq!(issues[1].code, Some("printf".to_string()));
+    assert_eq!(issues[1].file, Some(PathBuf::from("/work/service/main.go")));
+    assert_eq!(issues[1].line, Some(10));
+    assert_eq!(issues[1].column, Some(2));
+
+    // golangci-lint --out-format json
+    let golangci_output = r#"{
+        "Issues": [
+            {
+                "FromLinter": "errcheck",
+                "Text": "Error return value of `f.Close` is not checked",
+                "Severity": "",
+                "Pos": {"Filename": "internal/db/db.go", "Offset": 120, "Line": 14, "Column": 9},
+                "Replacement": null
+            },
+            {
+                "FromLinter": "gofumpt",
+                "Text": "File is not `gofumpt`-ed",
+                "Severity": "error",
+                "Pos": {"Filename": "main.go", "Offset": 0, "Line": 3, "Column": 0},
+                "Replacement": {"NeedOnlyDelete": false, "NewLines": ["import \"fmt\""]}
+            }
+        ],
+        "Report": {"Linters": []}
+    }"#;
+    let issues = GolangciLint::new().parse_output(golangci_output, root);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].code, Some("errcheck".to_string()));
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[0].file, Some(root.join("internal/db/db.go")));
+    assert_eq!(issues[0].line, Some(14));
+    assert!(!issues[0].fix_available);
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].column, None);
+    assert!(issues[1].fix_available);
+
+    assert!(GolangciLint::new().parse_output("", root).is_empty());
+}
+
+#[test]
+fn test_package_patterns_per_module() {
+    let (_temp_dir, root) = two_modules();
+
+    let patterns = package_patterns(&[
+        root.join("api/main.go"),
+        root.join("api/internal/db/db.go"),
+        root.join("api/internal/db"),
+        root.join("worker"),
+        root.join("script.go"),
+        root.join("README.md"),
+    ]);
+
+    assert_eq!(patterns.len(), 2);
+    assert_eq!(
+        patterns.get(&root.join("api")),
+        Some(&vec![
+            ".".to_string(),
+            "./internal/db".to_string(),
+            "./internal/db/...".to_string()
+        ])
+    );
+    assert_eq!(
+        patterns.get(&root.join("worker")),
+        Some(&vec!["./...".to_string()])
+    );
+}
+
+#[test]
+fn test_golangci_lint_runs_once_per_module() {
+    let (_temp_dir, root) = two_modules();
+    let fakes = FakePath::install();
+    let golangci = fakes.tool(
+        "golangci-lint",
+        r#"{"Issues": [{"FromLinter": "errcheck", "Text": "unchecked", "Pos": {"Filename": "main.go", "Line": 3, "Column": 1}}]}"#,
+        "",
+        1,
+    );
+
+    let result = GolangciLint::new()
+        .execute(
+            &[
+                root.join("api/main.go"),
+                root.join("worker"),
+                root.join("script.go"),
+            ],
+            &tool_config(),
+        )
+        .unwrap();
+
+    // Each module is linted from its own root, the stray file is left out
+    let runs = golangci.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].dir, root.join("api"));
+    assert_eq!(runs[0].args, ["run", "--out-format", "json", "."]);
+    assert_eq!(runs[1].dir, root.join("worker"));
+    assert_eq!(runs[1].args, ["run", "--out-format", "json", "./..."]);
+
+    // Reported paths are relative to the module they came from
+    let files: Vec<_> = result
+        .issues
+        .iter()
+        .filter_map(|i| i.file.clone())
+        .collect();
+    assert_eq!(
+        files,
+        [root.join("api/main.go"), root.join("worker/main.go")]
+    );
+}
+
+#[test]
+fn test_go_vet_failure_without_diagnostics_is_an_error() {
+    let (_temp_dir, root) = two_modules();
+    let fakes = FakePath::install();
+    fakes.tool(
+        "go",
+        "",
+        "go: errors parsing go.mod: unknown directive\n",
+        1,
+    );
+
+    let error = GoVet::new()
+        .execute(&[root.join("api")], &tool_config())
+        .unwrap_err();
+    match error {
+        ToolError::ToolFailed {
+            name,
+            code,
+            message,
+        } => {
+            assert_eq!(name, "go-vet");
+            assert_eq!(code, 1);
+            assert!(message.contains("errors parsing go.mod"));
+        }
+        other => panic!("expected ToolFailed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_gofmt_skips_ignored_directories() {
+    let (_temp_dir, root) = two_modules();
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(&root)
+        .output()
+        .unwrap();
+    fs::create_dir_all(root.join("api/vendor/example.com/lib")).unwrap();
+    fs::write(
+        root.join("api/vendor/example.com/lib/lib.go"),
+        "package lib\n",
+    )
+    .unwrap();
+    fs::write(root.join(".gitignore"), "vendor/\n").unwrap();
+
+    let fakes = FakePath::install();
+    let gofmt = fakes.tool("gofmt", "", "", 0);
+
+    Gofmt::new()
+        .execute(&[root.join("api")], &tool_config())
+        .unwrap();
+
+    let runs = gofmt.runs();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].args[..2], ["-l", "-w"]);
+    let mut files: Vec<PathBuf> = runs[0].args[2..].iter().map(PathBuf::from).collect();
+    files.sort();
+    assert_eq!(
+        files,
+        [root.join("api/internal/db/db.go"), root.join("api/main.go")]
+    );
+}
@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use siren::cli::{HookInstallArgs, Verbosity};
+use siren::commands::{HookCommand, HOOK_MARKER};
+use siren::utils::git::{staged_files, StagedSnapshot};
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args([
+            "-c",
+            "user.name=siren",
+            "-c",
+            "user.email=siren@example.com",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git should run");
+    assert!(output.status.success(), "git {:?} failed", args);
+}
+
+fn repo() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    git(temp_dir.path(), &["init", "-q"]);
+    temp_dir
+}
+
+#[test]
+fn test_install_writes_hooks() {
+    let temp_dir = repo();
+    let command = HookCommand::new(Verbosity::Quiet);
+    let args = HookInstallArgs {
+        pre_push: true,
+        force: false,
+    };
+
+    let written = command.install(&args, temp_dir.path()).unwrap();
+    assert_eq!(written.len(), 2);
+
+    let pre_commit = temp_dir.path().join(".git/hooks/pre-commit");
+    let content = fs::read_to_string(&pre_commit).unwrap();
+    assert!(content.contains(HOOK_MARKER));
+    assert!(content.contains("siren check --staged"));
+    assert!(
+        fs::read_to_string(temp_dir.path().join(".git/hooks/pre-push"))
+            .unwrap()
+            .contains("--diff-base")
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&pre_commit).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+
+    // Re-installing over our own hooks is fine
+    assert!(command.install(&args, temp_dir.path()).is_ok());
+}
+
+#[test]
+fn test_install_keeps_foreign_hooks() {
+    let temp_dir = repo();
+    let hooks_dir = temp_dir.path().join(".git/hooks");
+    fs::create_dir_all(&hooks_dir).unwrap();
+    fs::write(hooks_dir.join("pre-commit"), "#!/bin/sh\nmake lint\n").unwrap();
+
+    let command = HookCommand::new(Verbosity::Quiet);
+    let mut args = HookInstallArgs {
+        pre_push: false,
+        force: false,
+    };
+
+    let error = command.install(&args, temp_dir.path()).unwrap_err();
+    assert!(error.to_string().contains("--force"));
+    assert_eq!(
+        fs::read_to_string(hooks_dir.join("pre-commit")).unwrap(),
+        "#!/bin/sh\nmake lint\n"
+    );
+
+    args.force = true;
+    command.install(&args, temp_dir.path()).unwrap();
+    assert!(fs::read_to_string(hooks_dir.join("pre-commit"))
+        .unwrap()
+        .contains(HOOK_MARKER));
+}
+
+#[test]
+fn test_staged_snapshot_matches_index() {
+    let temp_dir = repo();
+    let root = temp_dir.path();
+    fs::write(root.join("app.py"), "a = 1\n").unwrap();
+    fs::write(root.join("other.py"), "b = 1\n").unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "initial"]);
+
+    // Stage one edit, then keep editing without staging
+    fs::write(root.join("app.py"), "a = 2\n").unwrap();
+    git(root, &["add", "app.py"]);
+    fs::write(root.join("app.py"), "a = 2\nbroken(\n").unwrap();
+    fs::write(root.join("other.py"), "b = 2\n").unwrap();
+    fs::write(root.join("untracked.py"), "c = 1\n").unwrap();
+
+    let staged = staged_files(root).unwrap();
+    assert_eq!(staged.len(), 1);
+    assert!(staged[0].ends_with("app.py"));
+
+    let snapshot = StagedSnapshot::take(root).unwrap();
+    assert_eq!(fs::read_to_string(root.join("app.py")).unwrap(), "a = 2\n");
+    assert_eq!(
+        fs::read_to_string(root.join("other.py")).unwrap(),
+        "b = 1\n"
+    );
+    assert!(root.join("untracked.py").exists());
+
+    snapshot.restore().unwrap();
+    assert_eq!(
+        fs::read_to_string(root.join("app.py")).unwrap(),
+        "a = 2\nbroken(\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("other.py")).unwrap(),
+        "b = 2\n"
+    );
+    assert_eq!(staged_files(root).unwrap().len(), 1);
+}
@@ -0,0 +1,129 @@
+use std::fs;
+
+use siren::cli::{InitArgs, Verbosity};
+use siren::commands::InitCommand;
+use siren::config::{ConfigProvider, TomlConfigProvider};
+use siren::detection::DefaultProjectDetector;
+use siren::models::Language;
+use tempfile::TempDir;
+
+/// Creates a small Python project with a pylint config
+fn create_python_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    fs::write(temp_dir.path().join("main.py"), "print('hello')\n").unwrap();
+    fs::write(temp_dir.path().join(".pylintrc"), "[MASTER]\n").unwrap();
+
+    temp_dir
+}
+
+fn init_args(team: bool, force: bool) -> InitArgs {
+    InitArgs { team, force }
+}
+
+#[test]
+fn test_init_writes_loadable_config() {
+    let project = create_python_project();
+    let command = InitCommand::new(DefaultProjectDetector::new(), Verbosity::Quiet);
+
+    let config_path = command
+        .execute(init_args(false, false), vec![project.path().to_path_buf()])
+        .expect("init should succeed");
+    assert!(config_path.exists());
+
+    // The generated file must round-trip through the regular config loader
+    let config = TomlConfigProvider::new()
+        .load_config(project.path())
+        .expect("generated config should load");
+
+    assert_eq!(config.general.fail_level, "error");
+    assert!(config.languages.contains_key(&Language::Python));
+    assert!(!config.languages.contains_key(&Language::Rust));
+    assert!(config.tools.contains_key("ruff"));
+    assert!(!config.tools.contains_key("clippy"));
+
+    // The detected pylint config should be referenced
+    let pylint = config.tools.get("pylint").expect("pylint section missing");
+    assert_eq!(
+        pylint.config_file.as_deref(),
+        Some(std::path::Path::new(".pylintrc"))
+    );
+}
+
+#[test]
+fn test_init_respects_force() {
+    let project = create_python_project();
+    let command = InitCommand::new(DefaultProjectDetector::new(), Verbosity::Quiet);
+    let paths = vec![project.path().to_path_buf()];
+
+    command
+        .execute(init_args(false, false), paths.clone())
+        .expect("first init should succeed");
+
+    // A second run without --force must not overwrite the file
+    assert!(command
+        .execute(init_args(false, false), paths.clone())
+        .is_err());
+
+    // With --force it is regenerated
+    assert!(command.execute(init_args(true, true), paths).is_ok());
+}
+
+#[test]
+fn test_init_team_profile_is_stricter() {
+    let project = create_python_project();
+    let command = InitCommand::new(DefaultProjectDetector::new(), Verbosity::Quiet);
+
+    command
+        .execute(init_args(true, false), vec![project.path().to_path_buf()])
+        .expect("init should succeed");
+
+    let config = TomlConfigProvider::new()
+        .load_config(project.path())
+        .expect("generated config should load");
+
+    assert_eq!(config.general.fail_level, "warning");
+    assert_eq!(
+        config
+            .languages
+            .get(&Language::Python)
+            .unwrap()
+            .ignore_rules,
+        None
+    );
+    let mypy_args = config.tools["mypy"].extra_args.clone().unwrap_or_default();
+    assert!(mypy_args.contains(&"--strict".to_string()));
+}
+
+#[test]
+fn test_init_does_not_use_project_markers_as_tool_configs() {
+    let project = TempDir::new().unwrap();
+    let root = project.path();
+    fs::write(root.join("go.mod"), "module example.com/app\n").unwrap();
+    fs::write(root.join("main.go"), "package main\n").unwrap();
+    fs::write(root.join(".golangci.yml"), "linters: {}\n").unwrap();
+    fs::write(root.join("tsconfig.json"), "{}\n").unwrap();
+    fs::write(root.join("index.ts"), "export {};\n").unwrap();
+
+    let command = InitCommand::new(DefaultProjectDetector::new(), Verbosity::Quiet);
+    command
+        .execute(init_args(false, false), vec![root.to_path_buf()])
+        .expect("init should succeed");
+    let config = TomlConfigProvider::new()
+        .load_config(root)
+        .expect("generated config should load");
+
+    // Real config files are still referenced
+    assert_eq!(
+        config.tools["golangci-lint"].config_file.as_deref(),
+        Some(std::path::Path::new(".golangci.yml"))
+    );
+
+    // go.mod only marks the module
+    let go_vet = config.tools.get("go-vet").expect("go-vet section missing");
+    assert_eq!(go_vet.config_file, None);
+
+    // tsc finds tsconfig.json per project itself
+    let tsc = config.tools.get("tsc").expect("tsc section missing");
+    assert_eq!(tsc.config_file, None);
+}
@@ -180,6 +180,7 @@ async fn verify_issue_detection(
         executable_path: None,
         report_level: None,
         auto_fix: false,
+        unsafe_fixes: false,
         // For formatters, we need to set check to true to detect formatting issues
         check: true,
     };
@@ -0,0 +1,215 @@
+//! Integration tests for Java tools
+
+use std::fs;
+use std::path::PathBuf;
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language, ToolType};
+use siren::tools::java::{module_root, Checkstyle, GoogleJavaFormat, Pmd};
+use siren::tools::LintTool;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_parse_java_tool_output() {
+    let issues = Checkstyle::new().parse_output(
+        r#"Starting audit...
+<?xml version="1.0" encoding="UTF-8"?>
+<checkstyle version="10.12.5">
+<file name="/work/shop/api/src/main/java/com/acme/App.java">
+<error line="14" column="17" severity="warning" message="Method name &apos;Run&apos; must match pattern &apos;^[a-z][a-zA-Z0-9]*$&apos;." source="com.puppycrawl.tools.checkstyle.checks.naming.MethodNameCheck"/>
+<error line="3" severity="error" message="Unused import - java.util.List." source="com.puppycrawl.tools.checkstyle.checks.imports.UnusedImportsCheck"/>
+</file>
+<file name="/work/shop/api/src/main/java/com/acme/Clean.java">
+</file>
+</checkstyle>
+"#,
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[0].code, Some("MethodName".to_string()));
+    assert_eq!(
+        issues[0].message,
+        "Method name 'Run' must match pattern '^[a-z][a-zA-Z0-9]*$'."
+    );
+    assert_eq!(
+        issues[0].file,
+        Some(PathBuf::from(
+            "/work/shop/api/src/main/java/com/acme/App.java"
+        ))
+    );
+    assert_eq!(issues[0].line, Some(14));
+    assert_eq!(issues[0].column, Some(17));
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].code, Some("UnusedImports".to_string()));
+    assert_eq!(issues[1].column, None);
+
+    let issues = Pmd::new().parse_output(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<pmd xmlns="http://pmd.sourceforge.net/report/2.0.0" version="7.0.0" timestamp="2024-03-22T10:15:30.000">
+<file name="/work/shop/api/src/main/java/com/acme/App.java">
+<violation beginline="9" endline="9" begincolumn="20" endcolumn="25" rule="UnusedPrivateField" ruleset="Best Practices" package="com.acme" class="App" priority="3">
+Avoid unused private fields such as 'cache'.
+</violation>
+<violation beginline="21" endline="23" begincolumn="9" endcolumn="10" rule="EmptyCatchBlock" ruleset="Error Prone" package="com.acme" class="App" priority="1">
+Avoid empty catch blocks
+</violation>
+</file>
+<error filename="/work/shop/api/src/main/java/com/acme/Broken.java" msg="ParseException: Parse exception in file">
+</error>
+</pmd>
+"#,
+    );
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[0].code, Some("UnusedPrivateField".to_string()));
+    assert_eq!(
+        issues[0].message,
+        "Avoid unused private fields such as 'cache'."
+    );
+    assert_eq!(issues[0].line, Some(9));
+    assert_eq!(issues[0].column, Some(20));
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[2].severity, IssueSeverity::Error);
+    assert_eq!(
+        issues[2].file,
+        Some(PathBuf::from(
+            "/work/shop/api/src/main/java/com/acme/Broken.java"
+        ))
+    );
+
+    assert!(Checkstyle::new().parse_output("").is_empty());
+
+    // google-java-format reports unformatted files on stdout and syntax errors
+    // on stderr
+    let issues = GoogleJavaFormat::new().parse_output(
+        "src/main/java/com/acme/App.java\n",
+        "src/main/java/com/acme/Broken.java:12:5: error: ';' expected\n",
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].severity, IssueSeverity::Style);
+    assert!(issues[0].fix_available);
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].message, "';' expected");
+    assert_eq!(issues[1].line, Some(12));
+}
+
+#[test]
+fn test_detect_java_modules_and_tools() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    let sources = root.join("api/src/main/java/com/acme");
+    fs::create_dir_all(&sources).unwrap();
+    fs::create_dir_all(root.join("config/checkstyle")).unwrap();
+    fs::write(root.join("settings.gradle.kts"), "include(\"api\")\n").unwrap();
+    fs::write(
+        root.join("build.gradle.kts"),
+        "plugins { id(\"com.diffplug.spotless\") }\nspotless { java { googleJavaFormat() } }\n",
+    )
+    .unwrap();
+    fs::write(root.join("api/build.gradle.kts"), "plugins { java }\n").unwrap();
+    fs::write(
+        sources.join("App.java"),
+        "package com.acme;\nclass App {}\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("config/checkstyle/checkstyle.xml"),
+        "<module name=\"Checker\"/>\n",
+    )
+    .unwrap();
+
+    // Each Gradle module is its own root
+    let api = root.join("api").canonicalize().unwrap();
+    assert_eq!(module_root(&sources.join("App.java")), Some(api.clone()));
+    assert_eq!(module_root(&root.join("api")), Some(api));
+    assert_eq!(module_root(root), Some(root.canonicalize().unwrap()));
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert!(info.languages.contains(&Language::Java));
+
+    let detected = |name: &str| {
+        info.detected_tools
+            .iter()
+            .find(|tool| tool.name == name)
+            .unwrap_or_else(|| panic!("{} should be detected", name))
+            .clone()
+    };
+    assert_eq!(
+        detected("checkstyle").config_path,
+        root.join("config/checkstyle/checkstyle.xml")
+    );
+    assert_eq!(detected("checkstyle").tool_type, ToolType::Linter);
+    assert_eq!(
+        detected("google-java-format").tool_type,
+        ToolType::Formatter
+    );
+    assert!(!info.detected_tools.iter().any(|tool| tool.name == "pmd"));
+}
+
+#[test]
+fn test_java_tools_run_per_module_with_its_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    // A shared Checkstyle config at the top, which web overrides; only core
+    // has a PMD ruleset
+    fs::create_dir_all(root.join("config/checkstyle")).unwrap();
+    fs::write(root.join("config/checkstyle/checkstyle.xml"), "<module/>").unwrap();
+    fs::create_dir_all(root.join("core/src/main/java")).unwrap();
+    fs::write(root.join("core/pom.xml"), "<project/>").unwrap();
+    fs::write(root.join("core/pmd.xml"), "<ruleset/>").unwrap();
+    fs::write(root.join("core/src/main/java/App.java"), "class App {}\n").unwrap();
+    fs::create_dir_all(root.join("web/src")).unwrap();
+    fs::write(root.join("web/build.gradle.kts"), "plugins {}\n").unwrap();
+    fs::write(root.join("web/checkstyle.xml"), "<module/>").unwrap();
+    fs::write(root.join("web/src/Main.java"), "class Main {}\n").unwrap();
+
+    let fakes = FakePath::install();
+    let checkstyle = fakes.tool(
+        "checkstyle",
+        "<checkstyle version=\"10\"></checkstyle>",
+        "",
+        0,
+    );
+    let pmd = fakes.tool("pmd", "<pmd></pmd>", "", 0);
+
+    let files = [root.join("core"), root.join("web")];
+    Checkstyle::new().execute(&files, &tool_config()).unwrap();
+    Pmd::new().execute(&files, &tool_config()).unwrap();
+
+    let path = |name: &str| root.join(name).display().to_string();
+
+    let runs = checkstyle.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].dir, root.join("core"));
+    assert_eq!(
+        runs[0].args,
+        [
+            "-f".to_string(),
+            "xml".to_string(),
+            "-c".to_string(),
+            path("config/checkstyle/checkstyle.xml"),
+            path("core/src/main/java/App.java")
+        ]
+    );
+    assert_eq!(runs[1].dir, root.join("web"));
+    assert_eq!(runs[1].args[3], path("web/checkstyle.xml"));
+    assert_eq!(runs[1].args[4..], [path("web/src/Main.java")]);
+
+    // Modules without a ruleset are skipped
+    let runs = pmd.runs();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].dir, root.join("core"));
+    assert_eq!(
+        runs[0].args[runs[0].args.len() - 2..],
+        ["-d".to_string(), path("core/src/main/java/App.java")]
+    );
+}
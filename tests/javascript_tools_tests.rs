@@ -1,12 +1,19 @@
 //! Integration tests for JavaScript/TypeScript tools
 
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use siren::models::{Language, ToolType};
-use siren::tools::javascript::{ESLint, Prettier};
+use siren::models::{IssueSeverity, Language, ToolType};
+use siren::tools::javascript::{tsconfig_projects, ESLint, Prettier, Tsc};
 use siren::tools::DefaultToolRegistry;
 use siren::tools::{LintTool, ToolRegistry};
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakeTool};
 
 #[test]
 fn test_prettier_can_handle() {
@@ -77,6 +84,151 @@ fn test_tool_registry_with_js_tools() {
     assert!(linters.iter().any(|t| t.name() == "eslint"));
 }
 
+#[test]
+fn test_parse_tsc_output() {
+    let tsc = Tsc::new();
+    let root = Path::new("/work/web");
+
+    assert!(tsc.can_handle(Path::new("src/app.tsx")));
+    assert!(tsc.can_handle(Path::new("vite.config.mts")));
+    assert!(!tsc.can_handle(Path::new("src/app.js")));
+
+    let output = "\
+src/app.ts(3,7): error TS2322: Type 'string' is not assignable to type 'number'.
+src/api/client.ts(12,5): error TS2345: Argument of type '{ id: string; }' is not assignable to parameter of type 'User'.
+  Property 'name' is missing in type '{ id: string; }' but required in type 'User'.
+error TS5083: Cannot read file '/work/web/tsconfig.base.json'.
+";
+    let issues = tsc.parse_output(output, root);
+    assert_eq!(issues.len(), 3);
+
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[0].file, Some(root.join("src/app.ts")));
+    assert_eq!(issues[0].line, Some(3));
+    assert_eq!(issues[0].column, Some(7));
+    assert_eq!(issues[0].code, Some("TS2322".to_string()));
+
+    // Continuation lines are folded into the message
+    assert!(issues[1].message.ends_with("but required in type 'User'."));
+    assert!(issues[1].message.starts_with("Argument of type"));
+
+    assert_eq!(issues[2].file, None);
+    assert_eq!(issues[2].code, Some("TS5083".to_string()));
+
+    assert!(tsc.parse_output("", root).is_empty());
+}
+
+#[test]
+fn test_tsconfig_projects_follow_references() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    // A solution-style root config referencing two packages, with comments
+    // and trailing commas as tsc allows
+    fs::write(
+        root.join("tsconfig.json"),
+        r#"{
+    // Solution config, builds nothing itself
+    "files": [],
+    "references": [
+        { "path": "./packages/app" },
+        { "path": "./packages/lib/tsconfig.json" }, /* shared code */
+    ],
+}"#,
+    )
+    .unwrap();
+    for package in ["app", "lib"] {
+        let dir = root.join("packages").join(package);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/index.ts"), "export const x = 1;\n").unwrap();
+    }
+    fs::write(
+        root.join("packages/app/tsconfig.json"),
+        r#"{"compilerOptions": {"strict": true}, "references": [{"path": "../lib"}]}"#,
+    )
+    .unwrap();
+    fs::write(root.join("packages/lib/tsconfig.json"), "{}").unwrap();
+
+    let app = root.join("packages/app/tsconfig.json");
+    let lib = root.join("packages/lib/tsconfig.json");
+
+    // The whole repository expands to both packages
+    let projects: Vec<PathBuf> = tsconfig_projects(std::slice::from_ref(&root))
+        .into_iter()
+        .collect();
+    assert_eq!(projects, vec![app.clone(), lib.clone()]);
+
+    // A single file maps to its nearest project, plus what that references
+    let projects: Vec<PathBuf> = tsconfig_projects(&[root.join("packages/app/src/index.ts")])
+        .into_iter()
+        .collect();
+    assert_eq!(projects, vec![app, lib.clone()]);
+
+    let projects: Vec<PathBuf> = tsconfig_projects(&[root.join("packages/lib/src/index.ts")])
+        .into_iter()
+        .collect();
+    assert_eq!(projects, vec![lib]);
+}
+
+#[test]
+fn test_tsc_runs_per_project_and_keeps_target_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    for package in ["app", "lib"] {
+        let dir = root.join("packages").join(package);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/index.ts"), "export const x = 1;\n").unwrap();
+        fs::write(dir.join("src/other.ts"), "export const y = 2;\n").unwrap();
+    }
+    fs::write(
+        root.join("packages/app/tsconfig.json"),
+        r#"{"references": [{"path": "../lib"}]}"#,
+    )
+    .unwrap();
+    fs::write(root.join("packages/lib/tsconfig.json"), "{}").unwrap();
+
+    // The workspace's own tsc, found without touching the PATH
+    let tsc = FakeTool::create(
+        &root.join("node_modules/.bin"),
+        "tsc",
+        "\
+src/index.ts(1,14): error TS2322: Type 'number' is not assignable to type 'string'.
+src/other.ts(1,14): error TS2322: Type 'number' is not assignable to type 'string'.
+error TS5083: Cannot read file 'tsconfig.base.json'.
+",
+        "",
+        2,
+    );
+
+    let app = root.join("packages/app");
+    let lib = root.join("packages/lib");
+    let result = Tsc::new()
+        .execute(&[app.join("src/index.ts")], &tool_config())
+        .unwrap();
+
+    // The file's project and the one it references each run from their own
+    // directory
+    let runs = tsc.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].dir, app);
+    assert_eq!(
+        runs[0].args,
+        [
+            "--project",
+            &app.join("tsconfig.json").display().to_string(),
+            "--noEmit",
+            "--pretty",
+            "false"
+        ]
+    );
+    assert_eq!(runs[1].dir, lib);
+
+    // Issues in other files are dropped, project-wide ones are kept
+    let files: Vec<_> = result.issues.iter().map(|i| i.file.clone()).collect();
+    assert_eq!(files, [Some(app.join("src/index.ts")), None, None]);
+}
+
 // Skip this test if the tools are not installed
 #[test]
 #[ignore]
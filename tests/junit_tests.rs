@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use siren::config::OutputConfig;
+use siren::models::{IssueSeverity, LintIssue, LintResult};
+use siren::output::{create_formatter, JunitFormatter, OutputFormatter};
+
+fn issue(severity: IssueSeverity, file: &str, line: usize, message: &str) -> LintIssue {
+    LintIssue {
+        severity,
+        message: message.to_string(),
+        file: Some(PathBuf::from(file)),
+        line: Some(line),
+        column: Some(1),
+        code: Some("E1".to_string()),
+        fix_available: false,
+    }
+}
+
+fn result(tool_name: &str, millis: u64, issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(millis),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+fn render(results: &[LintResult]) -> String {
+    JunitFormatter::new().format_results(results, &OutputConfig::default())
+}
+
+#[test]
+fn test_junit_suites_and_cases() {
+    let xml = render(&[
+        result(
+            "ruff",
+            1500,
+            vec![
+                issue(IssueSeverity::Warning, "app.py", 3, "line too long"),
+                issue(IssueSeverity::Error, "app.py", 7, "undefined name"),
+                issue(IssueSeverity::Warning, "util.py", 1, "unused import"),
+            ],
+        ),
+        result("mypy", 500, Vec::new()),
+    ]);
+
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(xml.contains(
+        "<testsuites name=\"siren\" tests=\"3\" failures=\"2\" errors=\"0\" time=\"2.000\">"
+    ));
+
+    // One suite per tool with its execution time
+    assert!(xml.contains(
+        "<testsuite name=\"ruff\" tests=\"2\" failures=\"2\" errors=\"0\" skipped=\"0\" time=\"1.500\">"
+    ));
+    assert!(xml.contains(
+        "<testsuite name=\"mypy\" tests=\"1\" failures=\"0\" errors=\"0\" skipped=\"0\" time=\"0.500\">"
+    ));
+
+    // One case per file, failing with the issues as the body
+    assert!(xml.contains("<testcase classname=\"ruff\" name=\"app.py\" time=\"0.750\">"));
+    assert!(xml.contains("<failure message=\"2 issues found\" type=\"error\">"));
+    assert!(xml.contains("3:1 [Warning] E1: line too long\n7:1 [Error] E1: undefined name"));
+
+    // A clean tool still reports a passing case
+    assert!(xml.contains("<testcase classname=\"mypy\" name=\"mypy\" time=\"0.500\"/>"));
+    assert!(xml.trim_end().ends_with("</testsuites>"));
+}
+
+#[test]
+fn test_junit_escapes_xml() {
+    let xml = render(&[result(
+        "eslint",
+        10,
+        vec![issue(
+            IssueSeverity::Error,
+            "a&b.js",
+            1,
+            "Expected '<' but found \"x\"",
+        )],
+    )]);
+
+    assert!(xml.contains("name=\"a&amp;b.js\""));
+    assert!(xml.contains("Expected &apos;&lt;&apos; but found &quot;x&quot;"));
+}
+
+#[test]
+fn test_junit_is_selectable() {
+    let formatter = create_formatter("junit").unwrap();
+    assert!(formatter.is_machine_readable());
+    assert!(formatter
+        .format_results(&[], &OutputConfig::default())
+        .contains("tests=\"0\""));
+}
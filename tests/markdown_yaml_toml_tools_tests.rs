@@ -0,0 +1,209 @@
+//! Integration tests for Markdown, YAML and TOML tools
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language, ToolType};
+use siren::tools::markdown::Markdownlint;
+use siren::tools::toml::Taplo;
+use siren::tools::toml::TaploLint;
+use siren::tools::yaml::Yamllint;
+use siren::tools::LintTool;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_parse_markdown_yaml_toml_output() {
+    // markdownlint-cli2, with and without the severity newer releases print
+    let issues = Markdownlint::new().parse_output(
+        "\
+README.md:3:81 MD013/line-length Line length [Expected: 80; Actual: 112]
+docs/setup.md:10 error MD022/blanks-around-headings Headings should be surrounded by blank lines [Expected: 1; Actual: 0; Below] [Context: \"## Install\"]
+docs/setup.md:14:1 warning MD009/no-trailing-spaces Trailing spaces [Expected: 0 or 2; Actual: 1]
+",
+    );
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].code, Some("MD013".to_string()));
+    assert_eq!(issues[0].message, "Line length [Expected: 80; Actual: 112]");
+    assert_eq!(issues[0].file, Some(PathBuf::from("README.md")));
+    assert_eq!(issues[0].column, Some(81));
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].line, Some(10));
+    assert_eq!(issues[1].column, None);
+    assert_eq!(issues[2].severity, IssueSeverity::Warning);
+
+    // yamllint -f parsable
+    let issues = Yamllint::new().parse_output(
+        "\
+deploy/values.yaml:4:81: [error] line too long (95 > 80 characters) (line-length)
+deploy/values.yaml:1:1: [warning] missing document start \"---\" (document-start)
+.github/workflows/ci.yml:7:3: [error] syntax error: mapping values are not allowed here
+",
+    );
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[0].message, "line too long (95 > 80 characters)");
+    assert_eq!(issues[0].code, Some("line-length".to_string()));
+    assert_eq!(issues[0].line, Some(4));
+    assert_eq!(issues[0].column, Some(81));
+    assert_eq!(issues[1].severity, IssueSeverity::Warning);
+    assert_eq!(issues[1].code, Some("document-start".to_string()));
+    assert_eq!(issues[2].code, None);
+    assert_eq!(
+        issues[2].file,
+        Some(PathBuf::from(".github/workflows/ci.yml"))
+    );
+
+    // taplo fmt --check and taplo lint both log to stderr
+    let issues = Taplo::new().parse_output(
+        "\
+ INFO taplo:format_files:collect_files: found files total=2 matched=2 cwd=\"/work/infra\"
+ERROR taplo:format_files: the file is not properly formatted path=\"/work/infra/Cargo.toml\"
+error: expected value
+  ┌─ /work/infra/config/app.toml:3:9
+  │
+3 │ timeout =
+  │         ^ expected value here
+
+ERROR operation failed error=some files were not valid
+",
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].severity, IssueSeverity::Style);
+    assert_eq!(
+        issues[0].file,
+        Some(PathBuf::from("/work/infra/Cargo.toml"))
+    );
+    assert!(issues[0].fix_available);
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].message, "expected value");
+    assert_eq!(
+        issues[1].file,
+        Some(PathBuf::from("/work/infra/config/app.toml"))
+    );
+    assert_eq!(issues[1].line, Some(3));
+    assert_eq!(issues[1].column, Some(9));
+}
+
+#[test]
+fn test_detect_markdown_yaml_toml_tools() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::write(root.join("README.md"), "# Infra\n").unwrap();
+    fs::write(root.join("values.yaml"), "replicas: 2\n").unwrap();
+    fs::write(root.join("config.toml"), "name = \"infra\"\n").unwrap();
+    fs::write(root.join(".markdownlint.yaml"), "MD013: false\n").unwrap();
+    fs::write(root.join(".yamllint"), "extends: default\n").unwrap();
+    fs::write(
+        root.join("taplo.toml"),
+        "[formatting]\nalign_entries = true\n",
+    )
+    .unwrap();
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    for language in [Language::Markdown, Language::Yaml, Language::Toml] {
+        assert!(info.languages.contains(&language), "{:?}", language);
+    }
+
+    let detected = |name: &str| {
+        info.detected_tools
+            .iter()
+            .find(|tool| tool.name == name)
+            .unwrap_or_else(|| panic!("{} should be detected", name))
+            .clone()
+    };
+    assert_eq!(
+        detected("markdownlint").config_path,
+        root.join(".markdownlint.yaml")
+    );
+    assert_eq!(detected("yamllint").config_path, root.join(".yamllint"));
+    assert_eq!(detected("taplo").tool_type, ToolType::Formatter);
+}
+
+#[test]
+fn test_markdown_yaml_toml_tools_only_get_their_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(root.join("docs/guide.md"), "# Guide\n").unwrap();
+    fs::write(root.join("docs/mkdocs.yml"), "site_name: Docs\n").unwrap();
+    for file in ["README.md", "ci.yml", "Cargo.toml", "Cargo.lock", "main.rs"] {
+        fs::write(root.join(file), "").unwrap();
+    }
+
+    // yamllint gets files, so ignored trees must be left out here
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(&root)
+        .output()
+        .unwrap();
+    fs::create_dir_all(root.join("docs/site")).unwrap();
+    fs::write(root.join("docs/site/search.yml"), "built: true\n").unwrap();
+    fs::write(root.join(".gitignore"), "site/\n").unwrap();
+
+    let fakes = FakePath::install();
+    let markdownlint = fakes.tool("markdownlint-cli2", "", "", 0);
+    let yamllint = fakes.tool("yamllint", "", "", 0);
+    let taplo = fakes.tool("taplo", "", "", 0);
+
+    let files = [
+        root.join("docs"),
+        root.join("README.md"),
+        root.join("ci.yml"),
+        root.join("Cargo.toml"),
+        root.join("Cargo.lock"),
+        root.join("main.rs"),
+    ];
+    let config = tool_config();
+    Markdownlint::new().execute(&files, &config).unwrap();
+    Yamllint::new().execute(&files, &config).unwrap();
+    TaploLint::new().execute(&files, &config).unwrap();
+
+    let path = |name: &str| root.join(name).display().to_string();
+    let docs = path("docs");
+
+    // Directories become globs for the tools that take globs, and installed
+    // packages are left out
+    assert_eq!(
+        markdownlint.runs()[0].args,
+        [
+            format!("{}/**/*.{{md,markdown}}", docs),
+            path("README.md"),
+            "#node_modules".to_string()
+        ]
+    );
+    assert_eq!(
+        yamllint.runs()[0].args,
+        [
+            "-f".to_string(),
+            "parsable".to_string(),
+            path("docs/mkdocs.yml"),
+            path("ci.yml")
+        ]
+    );
+    assert_eq!(
+        taplo.runs()[0].args,
+        [
+            "--colors".to_string(),
+            "never".to_string(),
+            "lint".to_string(),
+            format!("{}/**/*.toml", docs),
+            path("Cargo.toml")
+        ]
+    );
+
+    // Nothing to run on means no run at all
+    Yamllint::new()
+        .execute(&[root.join("main.rs")], &config)
+        .unwrap();
+    assert_eq!(yamllint.runs().len(), 1);
+}
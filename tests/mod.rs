@@ -1,13 +1,37 @@
 // Integration tests for the siren crate
 // Each test module should be publicly declared here
 
+mod baseline_tests;
+mod cache_tests;
 mod cli_tests;
 mod config_tests;
+mod cpp_tools_tests;
+mod csharp_swift_tools_tests;
+mod css_tools_tests;
 mod djlint_tests;
+mod docker_make_tools_tests;
 mod executor_tests;
+mod exit_code_tests;
 mod file_selection_tests;
+mod formatter_tests;
+mod git_diff_tests;
+mod github_tests;
+mod go_tools_tests;
+mod hook_tests;
+mod init_tests;
 mod integration_tests;
+mod java_tools_tests;
 mod javascript_tools_tests;
+mod junit_tests;
 mod language_tests;
+mod markdown_yaml_toml_tools_tests;
 mod path_optimization_tests;
+mod php_tools_tests;
 mod registry_tests;
+mod report_tests;
+mod ruby_tools_tests;
+mod sarif_tests;
+mod shell_tools_tests;
+mod suggest_tests;
+mod suppression_tests;
+mod watch_tests;
@@ -0,0 +1,263 @@
+//! Integration tests for PHP tools
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language, ToolType};
+use siren::tools::php::{
+    composer_root, php_paths_by_project, PhpCsFixer, PhpCsFixerCheck, Phpstan,
+};
+use siren::tools::{DefaultToolRegistry, LintTool, ToolRegistry};
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakeTool};
+
+#[test]
+fn test_parse_php_cs_fixer_and_phpstan_output() {
+    let issues = PhpCsFixer::new().parse_output(
+        r#"{"files":[
+            {"name":"src/Kernel.php","appliedFixers":["no_unused_imports","single_quote"],"diff":"--- src/Kernel.php\n+++ src/Kernel.php\n@@ -12,7 +12,6 @@\n use App\\Foo;\n-use App\\Bar;\n"},
+            {"name":"src/Util.php","appliedFixers":[]}
+        ],"time":{"total":0.052},"memory":14}"#,
+        Path::new("/work/shop"),
+    );
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].severity, IssueSeverity::Style);
+    assert_eq!(
+        issues[0].message,
+        "File needs fixing (no_unused_imports, single_quote)"
+    );
+    assert_eq!(
+        issues[0].file,
+        Some(PathBuf::from("/work/shop/src/Kernel.php"))
+    );
+    assert_eq!(issues[0].line, Some(12));
+    assert!(issues[0].fix_available);
+    assert_eq!(issues[1].message, "File needs fixing");
+    assert_eq!(issues[1].line, None);
+
+    let issues = Phpstan::new().parse_output(
+        r#"Note: Using configuration file /work/shop/phpstan.neon.
+{"totals":{"errors":1,"file_errors":2},"files":{
+    "/work/shop/src/Kernel.php":{"errors":1,"messages":[{"message":"Call to an undefined method App\\Kernel::boot2().","line":27,"ignorable":true,"identifier":"method.notFound"}]},
+    "/work/shop/src/Greets.php (in context of class App\\Hello)":{"errors":1,"messages":[{"message":"Property App\\Hello::$name has no type specified.","line":9,"ignorable":true,"tip":"See: https://phpstan.org/blog/solving-phpstan-no-value-type-specified-in-iterable-type"}]}
+},"errors":["Ignored error pattern #Foo# was not matched in reported errors."]}"#,
+    );
+    // Files come out in key order
+    assert_eq!(issues.len(), 3);
+    assert_eq!(
+        issues[0].file,
+        Some(PathBuf::from("/work/shop/src/Greets.php"))
+    );
+    assert!(issues[0].message.contains("(See: https://phpstan.org/"));
+    assert_eq!(issues[0].code, None);
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert_eq!(issues[1].code, Some("method.notFound".to_string()));
+    assert_eq!(
+        issues[1].message,
+        "Call to an undefined method App\\Kernel::boot2()."
+    );
+    assert_eq!(issues[1].line, Some(27));
+    assert_eq!(issues[2].file, None);
+}
+
+#[test]
+fn test_detect_php_project_and_tools() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::create_dir_all(root.join("src/Controller")).unwrap();
+    fs::write(root.join("composer.json"), "{\"name\": \"acme/shop\"}\n").unwrap();
+    fs::write(
+        root.join("src/Controller/HomeController.php"),
+        "<?php\n\nnamespace App\\Controller;\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join(".php-cs-fixer.dist.php"),
+        "<?php\n\nreturn new PhpCsFixer\\Config();\n",
+    )
+    .unwrap();
+    fs::write(root.join("phpstan.neon"), "parameters:\n  level: 6\n").unwrap();
+
+    assert_eq!(
+        composer_root(&root.join("src/Controller/HomeController.php")),
+        Some(root.canonicalize().unwrap())
+    );
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert!(info.languages.contains(&Language::Php));
+
+    let detected = |name: &str| {
+        info.detected_tools
+            .iter()
+            .find(|tool| tool.name == name)
+            .unwrap_or_else(|| panic!("{} should be detected", name))
+            .clone()
+    };
+    assert_eq!(
+        detected("php-cs-fixer").config_path,
+        root.join(".php-cs-fixer.dist.php")
+    );
+    assert_eq!(detected("phpstan").config_path, root.join("phpstan.neon"));
+    assert_eq!(detected("phpstan").tool_type, ToolType::TypeChecker);
+}
+
+#[test]
+fn test_php_paths_grouped_by_composer_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    for project in ["shop", "admin"] {
+        fs::create_dir_all(root.join(project).join("src")).unwrap();
+        fs::write(root.join(project).join("composer.json"), "{}\n").unwrap();
+        fs::write(root.join(project).join("src/App.php"), "<?php\n").unwrap();
+    }
+    fs::write(root.join("stray.php"), "<?php\n").unwrap();
+    fs::write(root.join("shop/README.md"), "# Shop\n").unwrap();
+
+    let groups = php_paths_by_project(&[
+        root.join("shop/src/App.php"),
+        root.join("shop/README.md"),
+        root.join("admin/src"),
+        root.join("stray.php"),
+    ]);
+
+    // Paths outside any project run from the absolute working directory
+    let current_dir = std::env::current_dir().unwrap().canonicalize().unwrap();
+    assert!(groups.keys().all(|root| root.is_absolute()));
+    assert_eq!(
+        groups[&root.join("shop")],
+        vec![root.join("shop/src/App.php")]
+    );
+    assert_eq!(groups[&root.join("admin")], vec![root.join("admin/src")]);
+    assert_eq!(groups[&current_dir], vec![root.join("stray.php")]);
+    assert_eq!(groups.len(), 3);
+}
+
+#[test]
+fn test_phpstan_runs_per_project_from_its_bin_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    for project in ["shop", "admin"] {
+        fs::create_dir_all(root.join(project).join("src")).unwrap();
+        fs::write(root.join(project).join("src/App.php"), "<?php\n").unwrap();
+        fs::write(root.join(project).join("src/Legacy.php"), "<?php\n").unwrap();
+    }
+    fs::write(root.join("shop/composer.json"), "{}\n").unwrap();
+    fs::write(root.join("shop/phpstan.neon"), "parameters:\n").unwrap();
+    fs::write(
+        root.join("admin/composer.json"),
+        r#"{"config": {"bin-dir": "tools"}}"#,
+    )
+    .unwrap();
+
+    // Each project has its own PHPStan, so nothing comes from the PATH
+    let shop_report = format!(
+        r#"{{"files": {{
+            "{app}": {{"messages": [{{"message": "Undefined variable: $cart", "line": 3}}]}},
+            "{legacy}": {{"messages": [{{"message": "Dead code", "line": 9}}]}}
+        }}, "errors": ["Ignored error pattern was not matched"]}}"#,
+        app = root.join("shop/src/App.php").display(),
+        legacy = root.join("shop/src/Legacy.php").display()
+    );
+    let shop = FakeTool::create(
+        &root.join("shop/vendor/bin"),
+        "phpstan",
+        &shop_report,
+        "",
+        1,
+    );
+    let admin = FakeTool::create(
+        &root.join("admin/tools"),
+        "phpstan",
+        r#"{"files": {}, "errors": []}"#,
+        "",
+        0,
+    );
+
+    let result = Phpstan::new()
+        .execute(
+            &[root.join("shop/src/App.php"), root.join("admin/src")],
+            &tool_config(),
+        )
+        .unwrap();
+
+    let analyse = [
+        "analyse",
+        "--error-format=json",
+        "--no-progress",
+        "--no-interaction",
+    ];
+
+    // Without a config the paths go on the command line
+    let runs = admin.runs();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].dir, root.join("admin"));
+    assert_eq!(runs[0].args[..4], analyse);
+    assert_eq!(
+        runs[0].args[4..],
+        [root.join("admin/src").display().to_string()]
+    );
+
+    // With one, PHPStan analyses what it lists
+    let runs = shop.runs();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].dir, root.join("shop"));
+    assert_eq!(
+        runs[0].args[4..],
+        [
+            "-c".to_string(),
+            root.join("shop/phpstan.neon").display().to_string()
+        ]
+    );
+
+    // so only issues in the requested paths are kept, plus those without a file
+    let files: Vec<_> = result.issues.iter().map(|i| i.file.clone()).collect();
+    assert_eq!(files, [Some(root.join("shop/src/App.php")), None]);
+}
+
+#[test]
+fn test_php_cs_fixer_check_reports_without_fixing() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("composer.json"), "{}\n").unwrap();
+    fs::write(root.join("src/Kernel.php"), "<?php\n").unwrap();
+
+    // Exit code 8 means files would have been changed
+    let fixer = FakeTool::create(
+        &root.join("vendor/bin"),
+        "php-cs-fixer",
+        r#"{"files":[{"name":"src/Kernel.php","appliedFixers":["single_quote"]}]}"#,
+        "",
+        8,
+    );
+
+    // `siren check` only runs linters and type checkers
+    let registry = DefaultToolRegistry::with_default_tools();
+    let checkers: Vec<_> = registry
+        .get_tools_for_language_and_type(Language::Php, ToolType::Linter)
+        .iter()
+        .map(|tool| tool.name().to_string())
+        .collect();
+    assert!(checkers.contains(&"php-cs-fixer-check".to_string()));
+
+    let result = PhpCsFixerCheck::new()
+        .execute(&[root.join("src/Kernel.php")], &tool_config())
+        .unwrap();
+
+    let runs = fixer.runs();
+    assert_eq!(runs.len(), 1);
+    assert!(runs[0].args.contains(&"--dry-run".to_string()));
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.issues[0].file, Some(root.join("src/Kernel.php")));
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use siren::models::{IssueSeverity, Language, LintIssue, LintResult, ProjectInfo};
+use siren::output::report::{Report, ReportFormat};
+
+fn issue(severity: IssueSeverity, file: &str, line: usize, code: &str, message: &str) -> LintIssue {
+    LintIssue {
+        severity,
+        message: message.to_string(),
+        file: Some(PathBuf::from(file)),
+        line: Some(line),
+        column: Some(1),
+        code: Some(code.to_string()),
+        fix_available: false,
+    }
+}
+
+fn result(tool_name: &str, issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(250),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+fn sample_report() -> Report {
+    let mut file_counts = HashMap::new();
+    file_counts.insert(Language::Python, 2);
+
+    let project_info = ProjectInfo {
+        languages: vec![Language::Python],
+        frameworks: Vec::new(),
+        file_counts,
+        detected_tools: Vec::new(),
+    };
+
+    let results = vec![
+        result(
+            "ruff",
+            vec![
+                issue(
+                    IssueSeverity::Warning,
+                    "app.py",
+                    12,
+                    "E501",
+                    "Line too long",
+                ),
+                issue(
+                    IssueSeverity::Error,
+                    "app.py",
+                    3,
+                    "F401",
+                    "`os` imported but unused",
+                ),
+                issue(
+                    IssueSeverity::Style,
+                    "util.py",
+                    1,
+                    "D100",
+                    "Missing <module> docstring",
+                ),
+            ],
+        ),
+        result("mypy", Vec::new()),
+    ];
+
+    Report::new(&project_info, &results)
+}
+
+#[test]
+fn test_report_format_parsing() {
+    assert_eq!("html".parse::<ReportFormat>(), Ok(ReportFormat::Html));
+    assert_eq!(
+        "Markdown".parse::<ReportFormat>(),
+        Ok(ReportFormat::Markdown)
+    );
+    assert_eq!("md".parse::<ReportFormat>(), Ok(ReportFormat::Markdown));
+    assert_eq!("json".parse::<ReportFormat>(), Ok(ReportFormat::Json));
+    assert!("pdf".parse::<ReportFormat>().is_err());
+}
+
+#[test]
+fn test_report_aggregates_by_tool_and_file() {
+    let report = sample_report();
+
+    assert_eq!(report.totals.total(), 3);
+    assert_eq!(report.totals.errors, 1);
+    assert_eq!(report.totals.warnings, 1);
+    assert_eq!(report.totals.style, 1);
+
+    // Tools with the most issues come first, clean tools are still listed
+    assert_eq!(report.tools.len(), 2);
+    assert_eq!(report.tools[0].name, "ruff");
+    assert_eq!(report.tools[1].counts.total(), 0);
+
+    // Files with the most issues come first, issues sorted by line
+    assert_eq!(report.files.len(), 2);
+    assert_eq!(report.files[0].path, PathBuf::from("app.py"));
+    assert_eq!(report.files[0].issues[0].issue.line, Some(3));
+    assert_eq!(report.files[0].issues[1].issue.line, Some(12));
+}
+
+#[test]
+fn test_report_renders_all_formats() {
+    let report = sample_report();
+
+    let html = report.render(ReportFormat::Html);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("app.py"));
+    // Messages must be escaped
+    assert!(html.contains("Missing &lt;module&gt; docstring"));
+    assert!(!html.contains("<module>"));
+
+    let markdown = report.render(ReportFormat::Markdown);
+    assert!(markdown.contains("# 🧜‍♀️ Siren Report"));
+    assert!(markdown.contains("| ruff |"));
+    assert!(markdown.contains("### `app.py` (2 issues)"));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&report.render(ReportFormat::Json)).expect("report should be JSON");
+    assert_eq!(json["totals"]["errors"], 1);
+    assert_eq!(json["files"][0]["issues"][0]["tool"], "ruff");
+    assert_eq!(json["files"][0]["issues"][0]["code"], "F401");
+}
@@ -0,0 +1,203 @@
+//! Integration tests for Ruby tools
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language, ToolType};
+use siren::tools::ruby::{RuboCop, Sorbet};
+use siren::tools::LintTool;
+use siren::utils::filename_language;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_parse_ruby_tool_output() {
+    // rubocop --format json, after a run with -a
+    let rubocop_output = r#"{
+        "metadata": {"rubocop_version": "1.57.2"},
+        "files": [
+            {
+                "path": "app/models/user.rb",
+                "offenses": [
+                    {
+                        "severity": "convention",
+                        "message": "Style/StringLiterals: Prefer single-quoted strings.",
+                        "cop_name": "Style/StringLiterals",
+                        "corrected": true,
+                        "correctable": true,
+                        "location": {"start_line": 3, "start_column": 10, "line": 3, "column": 10}
+                    },
+                    {
+                        "severity": "warning",
+                        "message": "Lint/UselessAssignment: Useless assignment to variable - `x`.",
+                        "cop_name": "Lint/UselessAssignment",
+                        "corrected": false,
+                        "correctable": false,
+                        "location": {"line": 7, "column": 5}
+                    }
+                ]
+            },
+            {"path": "Gemfile", "offenses": []}
+        ],
+        "summary": {"offense_count": 2, "target_file_count": 2, "inspected_file_count": 2}
+    }"#;
+
+    let remaining = RuboCop::new().parse_output(rubocop_output, false);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].severity, IssueSeverity::Warning);
+    assert_eq!(
+        remaining[0].message,
+        "Useless assignment to variable - `x`."
+    );
+    assert_eq!(
+        remaining[0].code,
+        Some("Lint/UselessAssignment".to_string())
+    );
+    assert_eq!(remaining[0].file, Some(PathBuf::from("app/models/user.rb")));
+    assert_eq!(remaining[0].line, Some(7));
+    assert_eq!(remaining[0].column, Some(5));
+    assert!(!remaining[0].fix_available);
+
+    let corrected = RuboCop::new().parse_output(rubocop_output, true);
+    assert_eq!(corrected.len(), 1);
+    assert_eq!(corrected[0].severity, IssueSeverity::Style);
+    assert_eq!(corrected[0].code, Some("Style/StringLiterals".to_string()));
+    assert!(corrected[0].fix_available);
+
+    assert!(RuboCop::new().parse_output("", false).is_empty());
+
+    // srb tc reports errors on stderr, each followed by a code excerpt
+    let root = Path::new("/work/shop");
+    let sorbet_output = "\
+app/models/user.rb:12: Method `nmae` does not exist on `User` https://srb.help/7003
+    12 |    nmae
+            ^^^^
+  Did you mean: `name`?
+sorbet/rbi/todo.rbi:4: Unable to resolve constant `Stripe` https://srb.help/5002
+     4 |  Stripe::Charge
+          ^^^^^^
+Errors: 2
+";
+    let issues = Sorbet::new().parse_output(sorbet_output, root);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[0].message, "Method `nmae` does not exist on `User`");
+    assert_eq!(issues[0].code, Some("7003".to_string()));
+    assert_eq!(issues[0].file, Some(root.join("app/models/user.rb")));
+    assert_eq!(issues[0].line, Some(12));
+    assert_eq!(issues[0].column, None);
+    assert_eq!(issues[1].file, Some(root.join("sorbet/rbi/todo.rbi")));
+
+    assert!(Sorbet::new()
+        .parse_output("No errors! Great job.\n", root)
+        .is_empty());
+}
+
+#[test]
+fn test_detect_ruby_tools() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::write(root.join("app.rb"), "puts 'hello'\n").unwrap();
+    fs::write(root.join(".rubocop.yml"), "AllCops:\n  NewCops: enable\n").unwrap();
+    fs::create_dir_all(root.join("sorbet")).unwrap();
+    fs::write(root.join("sorbet/config"), "--dir\n.\n").unwrap();
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert!(info.languages.contains(&Language::Ruby));
+
+    // Extensionless Ruby files are routed to the Ruby tools by name
+    assert_eq!(
+        filename_language(Path::new("Rakefile")),
+        Some(Language::Ruby)
+    );
+    let bare = TempDir::new().unwrap();
+    fs::write(bare.path().join("Gemfile"), "gem 'rake'\n").unwrap();
+    let (info_bare, _) = DefaultProjectDetector::new()
+        .detect(&[bare.path().to_path_buf()])
+        .unwrap();
+    assert_eq!(info_bare.languages, vec![Language::Ruby]);
+
+    let rubocop = info
+        .detected_tools
+        .iter()
+        .find(|tool| tool.name == "rubocop")
+        .expect("rubocop should be detected");
+    assert_eq!(rubocop.tool_type, ToolType::Linter);
+    assert_eq!(rubocop.config_path, root.join(".rubocop.yml"));
+
+    let sorbet = info
+        .detected_tools
+        .iter()
+        .find(|tool| tool.name == "sorbet")
+        .expect("sorbet should be detected");
+    assert_eq!(sorbet.tool_type, ToolType::TypeChecker);
+    assert_eq!(sorbet.languages, vec![Language::Ruby]);
+}
+
+#[test]
+fn test_sorbet_runs_once_per_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+
+    for project in ["billing", "shop"] {
+        fs::create_dir_all(root.join(project).join("app")).unwrap();
+        fs::create_dir_all(root.join(project).join("sorbet")).unwrap();
+        fs::write(root.join(project).join("sorbet/config"), "--dir\n.\n").unwrap();
+        fs::write(root.join(project).join("app/user.rb"), "# typed: true\n").unwrap();
+        fs::write(root.join(project).join("app/order.rb"), "# typed: true\n").unwrap();
+    }
+    fs::write(root.join("notes.txt"), "not ruby\n").unwrap();
+
+    let fakes = FakePath::install();
+    let srb = fakes.tool(
+        "srb",
+        "",
+        "\
+app/user.rb:3: Method `nmae` does not exist on `User` https://srb.help/7003
+app/order.rb:5: Unable to resolve constant `Stripe` https://srb.help/5002
+Errors: 2
+",
+        1,
+    );
+
+    let result = Sorbet::new()
+        .execute(
+            &[
+                root.join("shop/app/user.rb"),
+                root.join("billing"),
+                root.join("notes.txt"),
+            ],
+            &tool_config(),
+        )
+        .unwrap();
+
+    // Each project is checked once from the directory holding sorbet/config
+    let runs = srb.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].dir, root.join("billing"));
+    assert_eq!(runs[0].args, ["tc"]);
+    assert_eq!(runs[1].dir, root.join("shop"));
+
+    // Only issues in the requested paths are kept
+    let files: Vec<_> = result
+        .issues
+        .iter()
+        .filter_map(|i| i.file.clone())
+        .collect();
+    assert_eq!(
+        files,
+        [
+            root.join("billing/app/user.rb"),
+            root.join("billing/app/order.rb"),
+            root.join("shop/app/user.rb")
+        ]
+    );
+}
@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use siren::config::OutputConfig;
+use siren::models::{IssueSeverity, Language, LintIssue, LintResult, ToolInfo, ToolType};
+use siren::output::{OutputFormatter, SarifFormatter};
+
+fn issue(
+    severity: IssueSeverity,
+    file: Option<&str>,
+    line: Option<usize>,
+    code: Option<&str>,
+) -> LintIssue {
+    LintIssue {
+        severity,
+        message: "Something is off".to_string(),
+        file: file.map(PathBuf::from),
+        line,
+        column: line.map(|_| 5),
+        code: code.map(String::from),
+        fix_available: false,
+    }
+}
+
+fn result(tool_name: &str, version: Option<&str>, issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: Some(ToolInfo {
+            name: tool_name.to_string(),
+            tool_type: ToolType::Linter,
+            languages: vec![Language::Python],
+            available: true,
+            version: version.map(String::from),
+            description: format!("{} linter", tool_name),
+        }),
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(10),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+fn render(results: &[LintResult]) -> serde_json::Value {
+    let output = SarifFormatter::new().format_results(results, &OutputConfig::default());
+    serde_json::from_str(&output).expect("SARIF output should be valid JSON")
+}
+
+#[test]
+fn test_sarif_document_shape() {
+    let sarif = render(&[
+        result(
+            "ruff",
+            Some("0.4.1"),
+            vec![
+                issue(IssueSeverity::Error, Some("app.py"), Some(3), Some("F401")),
+                issue(
+                    IssueSeverity::Warning,
+                    Some("app.py"),
+                    Some(9),
+                    Some("E501"),
+                ),
+                issue(
+                    IssueSeverity::Warning,
+                    Some("util.py"),
+                    Some(1),
+                    Some("E501"),
+                ),
+            ],
+        ),
+        result("mypy", None, Vec::new()),
+    ]);
+
+    assert_eq!(sarif["version"], "2.1.0");
+    assert!(sarif["$schema"].as_str().unwrap().contains("sarif-2.1.0"));
+
+    // One run per tool, driver metadata from ToolInfo
+    let runs = sarif["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 2);
+    let driver = &runs[0]["tool"]["driver"];
+    assert_eq!(driver["name"], "ruff");
+    assert_eq!(driver["version"], "0.4.1");
+    assert_eq!(driver["shortDescription"]["text"], "ruff linter");
+    assert_eq!(driver["rules"].as_array().unwrap().len(), 2);
+    assert!(runs[1]["tool"]["driver"].get("version").is_none());
+    assert_eq!(runs[1]["results"].as_array().unwrap().len(), 0);
+
+    let first = &runs[0]["results"][0];
+    assert_eq!(first["ruleId"], "F401");
+    assert_eq!(first["level"], "error");
+    assert_eq!(first["message"]["text"], "Something is off");
+    let location = &first["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "app.py");
+    assert_eq!(location["region"]["startLine"], 3);
+    assert_eq!(location["region"]["startColumn"], 5);
+}
+
+#[test]
+fn test_sarif_levels_and_missing_fields() {
+    let sarif = render(&[result(
+        "pylint",
+        None,
+        vec![
+            issue(IssueSeverity::Info, Some("a.py"), None, None),
+            issue(IssueSeverity::Style, None, None, Some("C0114")),
+        ],
+    )]);
+
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+
+    // No code means no ruleId, no line means no region
+    assert_eq!(results[0]["level"], "note");
+    assert!(results[0].get("ruleId").is_none());
+    assert!(results[0]["locations"][0]["physicalLocation"]
+        .get("region")
+        .is_none());
+
+    // No file means no location at all
+    assert_eq!(results[1]["level"], "note");
+    assert!(results[1].get("locations").is_none());
+}
+
+#[test]
+fn test_sarif_empty_results_is_valid_log() {
+    let sarif = render(&[]);
+    assert_eq!(sarif["runs"].as_array().unwrap().len(), 0);
+}
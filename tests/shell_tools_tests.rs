@@ -0,0 +1,172 @@
+//! Integration tests for shell script tools
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::models::{IssueSeverity, Language, ToolConfig};
+use siren::tools::shell::{Shellcheck, Shfmt};
+use siren::tools::LintTool;
+use siren::utils::shebang_language;
+use tempfile::TempDir;
+
+#[path = "support/fake_tools.rs"]
+#[allow(clippy::duplicate_mod)]
+mod fake_tools;
+use fake_tools::{tool_config, FakePath};
+
+#[test]
+fn test_shell_scripts_detected_by_shebang() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::create_dir_all(root.join("ci")).unwrap();
+    fs::write(root.join("ci/build.sh"), "echo building\n").unwrap();
+    fs::write(root.join("deploy"), "#!/usr/bin/env bash\nset -e\n").unwrap();
+    fs::write(root.join("bootstrap"), "#!/usr/bin/env -S sh -eu\n").unwrap();
+    fs::write(root.join("manage"), "#!/usr/bin/python3\nprint('hi')\n").unwrap();
+    fs::write(root.join("NOTES"), "just some notes\n").unwrap();
+
+    assert_eq!(
+        shebang_language(&root.join("deploy")),
+        Some(Language::Shell)
+    );
+    assert_eq!(
+        shebang_language(&root.join("bootstrap")),
+        Some(Language::Shell)
+    );
+    assert_eq!(shebang_language(&root.join("manage")), None);
+    assert_eq!(shebang_language(&root.join("NOTES")), None);
+
+    let (info, _) = DefaultProjectDetector::new()
+        .detect(&[root.to_path_buf()])
+        .unwrap();
+    assert!(info.languages.contains(&Language::Shell));
+    assert_eq!(info.file_counts.get(&Language::Shell), Some(&3));
+
+    let shellcheck = Shellcheck::new();
+    assert!(shellcheck.can_handle(&root.join("ci/build.sh")));
+    assert!(shellcheck.can_handle(&root.join("deploy")));
+    assert!(!shellcheck.can_handle(&root.join("manage")));
+    assert!(!shellcheck.can_handle(&root.join("NOTES")));
+    assert!(Shfmt::new().can_handle(&root.join("bootstrap")));
+}
+
+#[test]
+fn test_parse_shellcheck_output() {
+    let output = r#"{"comments":[
+        {"file":"ci/build.sh","line":3,"endLine":3,"column":6,"endColumn":10,"level":"warning","code":2086,"message":"Double quote to prevent globbing and word splitting.","fix":{"replacements":[{"column":6,"endColumn":10,"insertionPoint":"afterEnd","line":3,"endLine":3,"precedence":7,"replacement":"\""}]}},
+        {"file":"deploy","line":1,"endLine":1,"column":1,"endColumn":1,"level":"error","code":2148,"message":"Tips depend on target shell and yours is unknown. Add a shebang or a 'shell' directive.","fix":null},
+        {"file":"deploy","line":7,"endLine":7,"column":3,"endColumn":12,"level":"style","code":2006,"message":"Use $(...) notation instead of legacy backticks `...`.","fix":null}
+    ]}"#;
+
+    let issues = Shellcheck::new().parse_output(output);
+    assert_eq!(issues.len(), 3);
+
+    assert_eq!(issues[0].code, Some("SC2086".to_string()));
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[0].file, Some(PathBuf::from("ci/build.sh")));
+    assert_eq!(issues[0].line, Some(3));
+    assert_eq!(issues[0].column, Some(6));
+    assert!(issues[0].fix_available);
+
+    assert_eq!(issues[1].severity, IssueSeverity::Error);
+    assert!(!issues[1].fix_available);
+    assert_eq!(issues[2].severity, IssueSeverity::Style);
+    assert_eq!(issues[2].code, Some("SC2006".to_string()));
+
+    assert!(Shellcheck::new()
+        .parse_output(r#"{"comments":[]}"#)
+        .is_empty());
+    assert!(Shellcheck::new().parse_output("").is_empty());
+}
+
+#[test]
+fn test_parse_shfmt_output() {
+    let issues = Shfmt::new().parse_output(
+        "ci/build.sh\ndeploy\n",
+        "scripts/release.sh:12:1: reached EOF without matching ( with )\n",
+    );
+    assert_eq!(issues.len(), 3);
+
+    assert_eq!(issues[0].severity, IssueSeverity::Style);
+    assert_eq!(issues[0].file, Some(PathBuf::from("ci/build.sh")));
+    assert!(issues[0].fix_available);
+    assert_eq!(issues[1].file, Some(PathBuf::from("deploy")));
+
+    assert_eq!(issues[2].severity, IssueSeverity::Error);
+    assert_eq!(issues[2].file, Some(PathBuf::from("scripts/release.sh")));
+    assert_eq!(issues[2].line, Some(12));
+    assert_eq!(issues[2].column, Some(1));
+    assert_eq!(issues[2].message, "reached EOF without matching ( with )");
+
+    assert!(Shfmt::new().parse_output("", "").is_empty());
+}
+
+#[test]
+fn test_shell_tools_get_scripts_from_directories() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(&root)
+        .output()
+        .unwrap();
+
+    fs::create_dir_all(root.join("ci")).unwrap();
+    fs::create_dir_all(root.join("vendor")).unwrap();
+    fs::write(root.join("ci/build.sh"), "echo building\n").unwrap();
+    fs::write(root.join("deploy"), "#!/usr/bin/env bash\nset -e\n").unwrap();
+    fs::write(root.join("manage"), "#!/usr/bin/python3\nprint('hi')\n").unwrap();
+    fs::write(root.join("vendor/lib.sh"), "echo vendored\n").unwrap();
+    fs::write(root.join(".gitignore"), "vendor/\n").unwrap();
+
+    let fakes = FakePath::install();
+    let shellcheck = fakes.tool("shellcheck", r#"{"comments": []}"#, "", 0);
+    let shfmt = fakes.tool("shfmt", "", "", 0);
+
+    let files = [root.clone(), root.join("manage")];
+    let config = tool_config();
+    Shellcheck::new().execute(&files, &config).unwrap();
+    Shfmt::new()
+        .execute(
+            &files,
+            &ToolConfig {
+                check: true,
+                ..tool_config()
+            },
+        )
+        .unwrap();
+
+    // Directories are walked here, skipping ignored files and picking up
+    // scripts by shebang; other explicit files are dropped
+    let scripts = |args: &[String]| {
+        let mut scripts: Vec<PathBuf> = args
+            .iter()
+            .filter(|arg| arg.starts_with('/'))
+            .map(PathBuf::from)
+            .collect();
+        scripts.sort();
+        scripts
+    };
+    let expected = vec![root.join("ci/build.sh"), root.join("deploy")];
+
+    let runs = shellcheck.runs();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].args[..3], ["-f", "json1", "--external-sources"]);
+    assert_eq!(scripts(&runs[0].args), expected);
+
+    let runs = shfmt.runs();
+    assert_eq!(runs.len(), 1);
+    // Only listing in check mode, without -w
+    assert_eq!(runs[0].args[0], "-l");
+    assert_eq!(runs[0].args.len(), 3);
+    assert_eq!(scripts(&runs[0].args), expected);
+
+    // No scripts means no run
+    Shellcheck::new()
+        .execute(&[root.join("manage")], &config)
+        .unwrap();
+    assert_eq!(shellcheck.runs().len(), 1);
+}
@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use clap::Parser;
+use siren::cli::{Cli, Commands, Verbosity};
+use siren::commands::{FixCommand, FormatCommand};
+use siren::config::SirenConfig;
+use siren::detection::{DefaultProjectDetector, ProjectDetector};
+use siren::errors::ToolError;
+use siren::models::{
+    DetectedTool, IssueSeverity, Language, LintIssue, LintResult, ProjectInfo, ToolConfig, ToolType,
+};
+use siren::output::JsonFormatter;
+use siren::suggestions::{
+    config_command, install_command, Priority, SuggestionEngine, SuggestionKind,
+};
+use siren::tools::{DefaultToolRegistry, LintTool, ToolInfo, ToolRegistry};
+use tempfile::TempDir;
+
+/// A Python tool that only records that it ran
+struct RecordingTool {
+    name: &'static str,
+    tool_type: ToolType,
+    ran: Arc<Mutex<Vec<String>>>,
+}
+
+impl LintTool for RecordingTool {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path.extension().is_some_and(|ext| ext == "py")
+    }
+
+    fn execute(&self, _files: &[PathBuf], _config: &ToolConfig) -> Result<LintResult, ToolError> {
+        self.ran.lock().unwrap().push(self.name.to_string());
+        Ok(result(self.name, Vec::new()))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        vec![Language::Python]
+    }
+
+    fn description(&self) -> &str {
+        "Recording tool"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn version(&self) -> Option<String> {
+        None
+    }
+}
+
+fn tool(name: &str, tool_type: ToolType, languages: Vec<Language>, available: bool) -> ToolInfo {
+    ToolInfo {
+        name: name.to_string(),
+        tool_type,
+        languages,
+        available,
+        version: None,
+        description: String::new(),
+    }
+}
+
+fn issue(file: &str, fix_available: bool) -> LintIssue {
+    LintIssue {
+        severity: IssueSeverity::Warning,
+        message: "Something is off".to_string(),
+        file: Some(PathBuf::from(file)),
+        line: Some(1),
+        column: None,
+        code: None,
+        fix_available,
+    }
+}
+
+fn result(tool_name: &str, issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(10),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+/// A Python + TypeScript project where only Python tools are installed
+fn project() -> ProjectInfo {
+    let mut file_counts = HashMap::new();
+    file_counts.insert(Language::Python, 12);
+    file_counts.insert(Language::TypeScript, 40);
+
+    ProjectInfo {
+        languages: vec![Language::Python, Language::TypeScript],
+        frameworks: Vec::new(),
+        file_counts,
+        detected_tools: vec![DetectedTool {
+            name: "ruff".to_string(),
+            config_path: PathBuf::from("ruff.toml"),
+            tool_type: ToolType::Linter,
+            languages: vec![Language::Python],
+        }],
+    }
+}
+
+fn tools() -> Vec<ToolInfo> {
+    vec![
+        tool("ruff", ToolType::Linter, vec![Language::Python], true),
+        tool("mypy", ToolType::Linter, vec![Language::Python], true),
+        tool("black", ToolType::Formatter, vec![Language::Python], true),
+        tool(
+            "eslint",
+            ToolType::Linter,
+            vec![Language::JavaScript, Language::TypeScript],
+            false,
+        ),
+    ]
+}
+
+#[test]
+fn test_suggestions_cover_each_rule() {
+    let project_info = project();
+    let mut ruff_issues: Vec<LintIssue> = (0..8).map(|_| issue("app.py", true)).collect();
+    ruff_issues.extend((0..2).map(|_| issue("util.py", false)));
+    let results = vec![result("ruff", ruff_issues), result("mypy", Vec::new())];
+
+    let suggestions = SuggestionEngine::new(&project_info, &results)
+        .with_tools(tools())
+        .suggest();
+
+    let find = |kind: SuggestionKind| {
+        suggestions
+            .iter()
+            .find(|s| s.kind == kind)
+            .unwrap_or_else(|| panic!("missing {:?} suggestion", kind))
+    };
+
+    let autofix = find(SuggestionKind::Autofix);
+    assert_eq!(autofix.priority, Priority::High);
+    assert!(autofix.title.starts_with("80% of ruff issues"));
+    assert_eq!(autofix.command, "siren fix --tools ruff");
+
+    let uncovered = find(SuggestionKind::UncoveredLanguage);
+    assert!(uncovered.title.contains("eslint"));
+    assert!(uncovered.title.contains("40 TypeScript files"));
+    assert!(uncovered.command.contains("npm install"));
+
+    // ruff has a config, mypy does not, black is not a checker
+    let missing: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.kind == SuggestionKind::MissingToolConfig)
+        .collect();
+    assert_eq!(missing.len(), 1);
+    assert!(missing[0].title.starts_with("mypy is available"));
+
+    let hotspot = find(SuggestionKind::Hotspot);
+    assert_eq!(hotspot.command, "siren check app.py");
+
+    assert_eq!(
+        find(SuggestionKind::MissingSirenConfig).command,
+        "siren init"
+    );
+}
+
+#[test]
+fn test_suggestions_are_ranked() {
+    let project_info = project();
+    let results = vec![result("ruff", vec![issue("app.py", true)])];
+
+    let suggestions = SuggestionEngine::new(&project_info, &results)
+        .with_tools(tools())
+        .suggest();
+
+    // Priorities never go up further down the list
+    for pair in suggestions.windows(2) {
+        assert!(pair[0].priority <= pair[1].priority);
+    }
+
+    // Within the same priority, bigger impact comes first
+    let high: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.priority == Priority::High)
+        .collect();
+    assert_eq!(high[0].kind, SuggestionKind::UncoveredLanguage);
+    assert_eq!(high[1].kind, SuggestionKind::Autofix);
+}
+
+#[test]
+fn test_clean_configured_project_has_no_suggestions() {
+    let mut project_info = project();
+    project_info.languages = vec![Language::Python];
+    project_info.detected_tools.push(DetectedTool {
+        name: "mypy".to_string(),
+        config_path: PathBuf::from("mypy.ini"),
+        tool_type: ToolType::Linter,
+        languages: vec![Language::Python],
+    });
+    let results = vec![result("ruff", Vec::new()), result("mypy", Vec::new())];
+
+    let suggestions = SuggestionEngine::new(&project_info, &results)
+        .with_tools(tools())
+        .with_siren_config(true)
+        .suggest();
+
+    assert!(suggestions.is_empty(), "unexpected: {:?}", suggestions);
+}
+
+#[test]
+fn test_uncovered_language_prefers_installable_linters() {
+    let mut project_info = project();
+    project_info.languages.push(Language::Go);
+    project_info.file_counts.insert(Language::Go, 7);
+    let results = vec![result("ruff", Vec::new())];
+
+    let mut tools = tools();
+    tools.push(tool(
+        "deno-lint",
+        ToolType::Linter,
+        vec![Language::TypeScript],
+        false,
+    ));
+    tools.push(tool(
+        "go-lint-x",
+        ToolType::Linter,
+        vec![Language::Go],
+        false,
+    ));
+
+    let suggestions = SuggestionEngine::new(&project_info, &results)
+        .with_tools(tools)
+        .suggest();
+    let uncovered: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.kind == SuggestionKind::UncoveredLanguage)
+        .collect();
+    assert_eq!(uncovered.len(), 2);
+
+    // deno-lint sorts first, but eslint is the one we can install
+    assert!(uncovered[0].title.starts_with("Install eslint"));
+    assert!(uncovered[0].command.contains("npm install"));
+
+    // Without an install command the language is still called out
+    assert!(uncovered[1].title.starts_with("Install go-lint-x"));
+    assert!(uncovered[1].title.contains("7 Go files"));
+    assert_eq!(uncovered[1].command, "siren check --tools go-lint-x");
+}
+
+/// Tools of each supported language, whether they can be installed on their
+/// own, and the name detection reports once their config command has run
+const LANGUAGE_TOOLS: &[(&str, bool, Option<&str>)] = &[
+    // go vet and gofmt ship with the Go toolchain and have no config file
+    ("golangci-lint", true, Some("golangci-lint")),
+    ("go-vet", false, None),
+    ("gofmt", false, None),
+    ("rubocop", true, Some("rubocop")),
+    ("sorbet", true, Some("sorbet")),
+    // tsconfig.json belongs to the project rather than to tsc
+    ("tsc", true, None),
+    ("stylelint", true, Some("stylelint")),
+    ("markdownlint", true, Some("markdownlint")),
+    ("yamllint", true, Some("yamllint")),
+    ("taplo-lint", true, Some("taplo")),
+    ("shellcheck", true, Some("shellcheck")),
+    // shfmt reads its settings from .editorconfig
+    ("shfmt", true, None),
+    ("clang-tidy", true, Some("clang-tidy")),
+    // Only checkers are asked for a config
+    ("clang-format", true, None),
+    ("checkstyle", true, Some("checkstyle")),
+    ("pmd", true, Some("pmd")),
+    ("google-java-format", true, None),
+    ("hadolint", true, Some("hadolint")),
+    ("checkmake", true, Some("checkmake")),
+    ("php-cs-fixer-check", true, Some("php-cs-fixer")),
+    ("phpstan", true, Some("phpstan")),
+    // dotnet format ships with the .NET SDK
+    ("dotnet-format-check", false, Some("dotnet-format")),
+    ("swift-format", true, Some("swift-format")),
+    ("swiftlint", true, Some("swiftlint")),
+];
+
+#[test]
+fn test_language_tools_have_install_and_config_commands() {
+    for &(name, installable, detected_as) in LANGUAGE_TOOLS {
+        assert_eq!(
+            install_command(name).is_some(),
+            installable,
+            "install command for {}",
+            name
+        );
+
+        let Some(detected_as) = detected_as else {
+            assert_eq!(config_command(name), None, "{} has no config file", name);
+            continue;
+        };
+        let command =
+            config_command(name).unwrap_or_else(|| panic!("missing config command for {}", name));
+
+        // Commands that only write a file can be checked against detection
+        if !command.starts_with("printf ") && !command.starts_with("touch ") {
+            continue;
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let status = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .current_dir(temp_dir.path())
+            .status()
+            .unwrap();
+        assert!(status.success(), "`{}` failed", command);
+
+        let (info, _) = DefaultProjectDetector::new()
+            .detect(&[temp_dir.path().to_path_buf()])
+            .unwrap();
+        assert!(
+            info.detected_tools.iter().any(|t| t.name == detected_as),
+            "{} is not detected after `{}`",
+            detected_as,
+            command
+        );
+    }
+}
+
+#[test]
+fn test_checker_companions_share_the_tools_config() {
+    let mut file_counts = HashMap::new();
+    file_counts.insert(Language::Toml, 3);
+    let project_info = ProjectInfo {
+        languages: vec![Language::Toml],
+        frameworks: Vec::new(),
+        file_counts,
+        detected_tools: vec![DetectedTool {
+            name: "taplo".to_string(),
+            config_path: PathBuf::from("taplo.toml"),
+            tool_type: ToolType::Formatter,
+            languages: vec![Language::Toml],
+        }],
+    };
+    let results = vec![result("taplo-lint", Vec::new())];
+    let tools = vec![tool(
+        "taplo-lint",
+        ToolType::Linter,
+        vec![Language::Toml],
+        true,
+    )];
+
+    // taplo.toml configures the lint run as well
+    let suggestions = SuggestionEngine::new(&project_info, &results)
+        .with_tools(tools)
+        .with_siren_config(true)
+        .suggest();
+    assert!(suggestions.is_empty(), "unexpected: {:?}", suggestions);
+}
+
+#[tokio::test]
+async fn test_autofix_command_only_runs_that_tools_fixer() {
+    let project_info = project();
+    let results = vec![result("ruff", vec![issue("app.py", true)])];
+    let suggestions = SuggestionEngine::new(&project_info, &results)
+        .with_tools(tools())
+        .suggest();
+    let autofix = suggestions
+        .iter()
+        .find(|s| s.kind == SuggestionKind::Autofix)
+        .unwrap();
+
+    let project = TempDir::new().unwrap();
+    fs::write(project.path().join("app.py"), "import os\n").unwrap();
+
+    let ran = Arc::new(Mutex::new(Vec::new()));
+    let mut registry = DefaultToolRegistry::new();
+    for (name, tool_type) in [
+        ("ruff-fix", ToolType::Fixer),
+        ("pylint-fix", ToolType::Fixer),
+        ("black", ToolType::Formatter),
+        ("ruff-format", ToolType::Formatter),
+    ] {
+        registry.register_tool(Arc::new(RecordingTool {
+            name,
+            tool_type,
+            ran: ran.clone(),
+        }));
+    }
+
+    // Run the suggested command exactly as printed
+    let cli = Cli::parse_from(autofix.command.split_whitespace());
+    let Some(Commands::Fix(args)) = cli.command else {
+        panic!("expected a fix command: {}", autofix.command);
+    };
+    FixCommand::new(
+        DefaultProjectDetector::new(),
+        registry.clone(),
+        JsonFormatter::new(),
+        Verbosity::Quiet,
+    )
+    .execute(
+        args,
+        vec![project.path().to_path_buf()],
+        false,
+        &SirenConfig::default(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(*ran.lock().unwrap(), vec!["ruff-fix"]);
+
+    // format honours --tools the same way
+    ran.lock().unwrap().clear();
+    let cli = Cli::parse_from(["siren", "format", "--tools", "black"]);
+    let Some(Commands::Format(args)) = cli.command else {
+        panic!("expected a format command");
+    };
+    FormatCommand::new(
+        DefaultProjectDetector::new(),
+        registry,
+        JsonFormatter::new(),
+        Verbosity::Quiet,
+    )
+    .execute(
+        args,
+        vec![project.path().to_path_buf()],
+        false,
+        &SirenConfig::default(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(*ran.lock().unwrap(), vec!["black"]);
+}
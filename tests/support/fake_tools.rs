@@ -0,0 +1,173 @@
+//! Stand-in executables for running tool adapters without the real tools
+//!
+//! A fake tool records the directory it ran in and its arguments, then prints
+//! canned output. Fakes that adapters look up on the PATH are installed
+//! through [`FakePath`], which holds a lock so tests never fight over the PATH.
+//!
+//! Test files include this with `#[path]`, so it is compiled once per file.
+
+#![allow(dead_code)]
+
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use siren::models::ToolConfig;
+use tempfile::TempDir;
+
+/// Plain configuration for running a tool, without fixes or extra arguments
+pub fn tool_config() -> ToolConfig {
+    ToolConfig {
+        enabled: true,
+        extra_args: Vec::new(),
+        env_vars: Default::default(),
+        executable_path: None,
+        report_level: None,
+        auto_fix: false,
+        unsafe_fixes: false,
+        check: false,
+    }
+}
+
+/// One recorded run of a fake tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    /// Working directory, with symlinks resolved
+    pub dir: PathBuf,
+
+    /// Arguments, without the program name
+    pub args: Vec<String>,
+}
+
+/// A fake executable that prints canned output
+pub struct FakeTool {
+    log: PathBuf,
+}
+
+impl FakeTool {
+    /// Write an executable `name` into `dir`
+    ///
+    /// Every run prints `stdout` and `stderr` and exits with `code`, except
+    /// version queries (`version`, or `--version` anywhere, as in
+    /// `dotnet format --version`), which only print a version.
+    pub fn create(dir: &Path, name: &str, stdout: &str, stderr: &str, code: i32) -> Self {
+        fs::create_dir_all(dir).unwrap();
+
+        let data = dir.join(format!(".{}", name));
+        fs::create_dir_all(&data).unwrap();
+        fs::write(data.join("stdout"), stdout).unwrap();
+        fs::write(data.join("stderr"), stderr).unwrap();
+        let log = data.join("runs");
+
+        let script = format!(
+            r#"#!/bin/sh
+case "$1 $*" in
+    "version "* | *--version*)
+        echo "{name} 1.0.0"
+        exit 0
+        ;;
+esac
+{{ echo "@run"; pwd -P; for arg in "$@"; do printf '%s\n' "$arg"; done; }} >> '{log}'
+cat '{data}/stdout'
+cat '{data}/stderr' >&2
+exit {code}
+"#,
+            name = name,
+            log = log.display(),
+            data = data.display(),
+            code = code,
+        );
+
+        let path = dir.join(name);
+        fs::write(&path, script).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        Self { log }
+    }
+
+    /// Every run so far, oldest first
+    pub fn runs(&self) -> Vec<Run> {
+        let log = fs::read_to_string(&self.log).unwrap_or_default();
+
+        log.split("@run\n")
+            .filter(|run| !run.is_empty())
+            .map(|run| {
+                let mut lines = run.lines().map(String::from);
+                Run {
+                    dir: PathBuf::from(lines.next().unwrap()),
+                    args: lines.collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Lock on the PATH for the whole process
+///
+/// Every test file has its own copy of this module, so a static would not be
+/// shared between them; a lock file named after the process is.
+struct PathLock(PathBuf);
+
+impl PathLock {
+    fn acquire() -> Self {
+        let path =
+            std::env::temp_dir().join(format!("siren-fake-path-{}.lock", std::process::id()));
+        while OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .is_err()
+        {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        Self(path)
+    }
+}
+
+impl Drop for PathLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// A directory of fake tools put in front of the PATH until dropped
+pub struct FakePath {
+    dir: TempDir,
+    original: Option<OsString>,
+    _lock: PathLock,
+}
+
+impl FakePath {
+    /// Take the PATH lock and prepend an empty directory for fakes
+    pub fn install() -> Self {
+        let lock = PathLock::acquire();
+        let dir = TempDir::new().unwrap();
+
+        let original = std::env::var_os("PATH");
+        let mut paths = vec![dir.path().to_path_buf()];
+        paths.extend(original.iter().flat_map(std::env::split_paths));
+        std::env::set_var("PATH", std::env::join_paths(paths).unwrap());
+
+        Self {
+            dir,
+            original,
+            _lock: lock,
+        }
+    }
+
+    /// Add a fake tool, see [`FakeTool::create`]
+    pub fn tool(&self, name: &str, stdout: &str, stderr: &str, code: i32) -> FakeTool {
+        FakeTool::create(self.dir.path(), name, stdout, stderr, code)
+    }
+}
+
+impl Drop for FakePath {
+    fn drop(&mut self) {
+        match &self.original {
+            Some(path) => std::env::set_var("PATH", path),
+            None => std::env::remove_var("PATH"),
+        }
+    }
+}
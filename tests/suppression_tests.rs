@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use siren::models::{IssueSeverity, LintIssue, LintResult};
+use siren::output::{OutputFormatter, PrettyFormatter};
+use siren::suppression::{apply_suppressions, FileSuppressions, Rule, SuppressedCounts};
+use tempfile::TempDir;
+
+fn issue(file: Option<PathBuf>, line: usize, code: Option<&str>, message: &str) -> LintIssue {
+    LintIssue {
+        severity: IssueSeverity::Warning,
+        message: message.to_string(),
+        file,
+        line: Some(line),
+        column: Some(1),
+        code: code.map(String::from),
+        fix_available: false,
+    }
+}
+
+fn result(tool_name: &str, issues: Vec<LintIssue>) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: issues.is_empty(),
+        issues,
+        execution_time: Duration::from_millis(1),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+#[test]
+fn test_rule_matching() {
+    let e501 = issue(None, 1, Some("E501"), "Line too long");
+
+    assert!(Rule::parse("ruff:E501").matches("ruff", &e501));
+    assert!(Rule::parse("ruff").matches("ruff", &e501));
+    assert!(Rule::parse("E501").matches("ruff", &e501));
+    assert!(!Rule::parse("ruff:E402").matches("ruff", &e501));
+    assert!(!Rule::parse("pylint:E501").matches("ruff", &e501));
+
+    // Rust-style paths, rule names in the message, and `-` versus `_`
+    let unwrap = issue(None, 1, None, "[unwrap_used] used `unwrap()`");
+    assert!(Rule::parse("clippy::unwrap_used").matches("clippy", &unwrap));
+    assert!(Rule::parse("clippy:unwrap-used").matches("clippy", &unwrap));
+
+    let qualified = issue(None, 1, Some("clippy::unwrap_used"), "used `unwrap()`");
+    assert!(Rule::parse("clippy::unwrap_used").matches("clippy", &qualified));
+}
+
+#[test]
+fn test_directive_forms() {
+    let source = [
+        "x = 1  # siren: ignore[ruff:E501, pylint]",
+        "// siren-ignore-next-line clippy::unwrap_used",
+        "let y = x.unwrap();",
+        "/* siren-ignore stylelint */",
+        "z = 3  # siren: ignore",
+        "# siren: ignored is not a directive",
+        "w = 4",
+    ]
+    .join("\n");
+    let suppressions = FileSuppressions::parse(&source);
+
+    let at = |line, code: &str| issue(None, line, Some(code), "msg");
+
+    assert!(suppressions.suppresses("ruff", &at(1, "E501")));
+    assert!(suppressions.suppresses("pylint", &at(1, "C0301")));
+    assert!(!suppressions.suppresses("mypy", &at(1, "misc")));
+
+    // next-line applies below the comment, not on it
+    assert!(!suppressions.suppresses("clippy", &at(2, "unwrap_used")));
+    assert!(suppressions.suppresses("clippy", &at(3, "unwrap_used")));
+    assert!(!suppressions.suppresses("clippy", &at(3, "expect_used")));
+
+    // The closing `*/` is not taken for a rule
+    assert!(suppressions.suppresses("stylelint", &at(4, "color-no-invalid-hex")));
+
+    // A bare ignore covers every tool
+    assert!(suppressions.suppresses("mypy", &at(5, "misc")));
+    assert!(!suppressions.suppresses("mypy", &at(6, "misc")));
+    assert!(!suppressions.suppresses("mypy", &at(7, "misc")));
+}
+
+#[test]
+fn test_trailing_reason_is_not_a_rule() {
+    let source = [
+        "x = 1  # siren: ignore E501 -- long URL kept for grep",
+        "y = 2  # siren: ignore[ruff:F401] -- re-exported",
+        "// siren-ignore-next-line -- legacy code",
+        "let z = x.unwrap();",
+    ]
+    .join("\n");
+    let suppressions = FileSuppressions::parse(&source);
+
+    let at = |line, code: &str| issue(None, line, Some(code), "msg");
+
+    // Only the rules before `--` count; `long`, `URL`, ... are not rules
+    assert!(suppressions.suppresses("ruff", &at(1, "E501")));
+    assert!(!suppressions.suppresses("ruff", &at(1, "E402")));
+    assert!(!suppressions.suppresses("long", &at(1, "misc")));
+
+    assert!(suppressions.suppresses("ruff", &at(2, "F401")));
+    assert!(!suppressions.suppresses("re-exported", &at(2, "misc")));
+
+    // Nothing but a reason still ignores everything
+    assert!(suppressions.suppresses("clippy", &at(4, "unwrap_used")));
+    assert!(suppressions.suppresses("mypy", &at(4, "misc")));
+}
+
+#[test]
+fn test_apply_suppressions_counts_and_summary() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("app.py");
+    fs::write(
+        &file,
+        "import os  # siren: ignore[ruff:F401, pylint:W0611]\nx = eval(data)\n",
+    )
+    .unwrap();
+
+    let mut results = vec![
+        result(
+            "ruff",
+            vec![
+                issue(Some(file.clone()), 1, Some("F401"), "unused import"),
+                issue(Some(file.clone()), 2, Some("S307"), "use of eval"),
+            ],
+        ),
+        result(
+            "pylint",
+            vec![issue(Some(file.clone()), 1, Some("W0611"), "unused import")],
+        ),
+        result(
+            "mypy",
+            vec![issue(Some(file.clone()), 1, Some("misc"), "untyped import")],
+        ),
+    ];
+
+    let suppressed = apply_suppressions(&mut results);
+    assert_eq!(suppressed.total(), 2);
+    assert_eq!(suppressed.by_tool().get("ruff"), Some(&1));
+    assert_eq!(suppressed.by_tool().get("pylint"), Some(&1));
+
+    assert_eq!(results[0].issues.len(), 1);
+    assert!(results[1].issues.is_empty());
+    assert!(results[1].success);
+    assert_eq!(results[2].issues.len(), 1);
+
+    let summary = PrettyFormatter::new().format_summary(&results, &suppressed);
+    assert!(summary.contains("2 issues suppressed inline"));
+    assert!(summary.contains("pylint 1, ruff 1"));
+
+    let quiet = PrettyFormatter::new().format_summary(&results, &SuppressedCounts::default());
+    assert!(!quiet.contains("suppressed"));
+}
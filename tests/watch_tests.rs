@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use siren::commands::{affected_tools, merge_runs, CheckRun, WatchFilter};
+use siren::errors::ToolError;
+use siren::models::{Language, LintResult, ProjectInfo, ToolConfig, ToolType};
+use siren::tools::LintTool;
+use tempfile::TempDir;
+
+/// A tool that handles a single file extension
+struct MockTool {
+    name: &'static str,
+    extension: &'static str,
+    tool_type: ToolType,
+}
+
+impl LintTool for MockTool {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn can_handle(&self, file_path: &Path) -> bool {
+        file_path
+            .extension()
+            .is_some_and(|ext| ext == self.extension)
+    }
+
+    fn execute(&self, _files: &[PathBuf], _config: &ToolConfig) -> Result<LintResult, ToolError> {
+        Ok(result(self.name))
+    }
+
+    fn tool_type(&self) -> ToolType {
+        self.tool_type
+    }
+
+    fn languages(&self) -> Vec<Language> {
+        vec![Language::Python]
+    }
+
+    fn description(&self) -> &str {
+        "Mock tool"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn version(&self) -> Option<String> {
+        None
+    }
+}
+
+fn result(tool_name: &str) -> LintResult {
+    LintResult {
+        tool_name: tool_name.to_string(),
+        tool: None,
+        success: true,
+        issues: Vec::new(),
+        execution_time: Duration::from_millis(1),
+        stdout: None,
+        stderr: None,
+    }
+}
+
+fn run(results: &[&str], failed_tools: &[&str]) -> CheckRun {
+    CheckRun {
+        project_info: ProjectInfo {
+            languages: Vec::new(),
+            frameworks: Vec::new(),
+            file_counts: Default::default(),
+            detected_tools: Vec::new(),
+        },
+        results: results.iter().map(|name| result(name)).collect(),
+        failed_tools: failed_tools.iter().map(|name| name.to_string()).collect(),
+        suppressed: Default::default(),
+    }
+}
+
+#[test]
+fn test_affected_tools_match_changed_files() {
+    let tools: Vec<Arc<dyn LintTool>> = vec![
+        Arc::new(MockTool {
+            name: "pylint",
+            extension: "py",
+            tool_type: ToolType::Linter,
+        }),
+        Arc::new(MockTool {
+            name: "mypy",
+            extension: "py",
+            tool_type: ToolType::TypeChecker,
+        }),
+        Arc::new(MockTool {
+            name: "black",
+            extension: "py",
+            tool_type: ToolType::Formatter,
+        }),
+        Arc::new(MockTool {
+            name: "clippy",
+            extension: "rs",
+            tool_type: ToolType::Linter,
+        }),
+    ];
+
+    // Formatters are not part of a check, so they never re-run
+    assert_eq!(
+        affected_tools(&tools, &[PathBuf::from("src/app.py")]),
+        vec!["pylint", "mypy"]
+    );
+    assert_eq!(
+        affected_tools(&tools, &[PathBuf::from("a.py"), PathBuf::from("b.rs")]),
+        vec!["pylint", "mypy", "clippy"]
+    );
+    assert!(affected_tools(&tools, &[PathBuf::from("README.md")]).is_empty());
+}
+
+#[test]
+fn test_watch_filter_respects_gitignore() {
+    let project = TempDir::new().unwrap();
+    let root = project.path();
+    fs::write(root.join(".gitignore"), "build/\n*.log\n").unwrap();
+    fs::create_dir_all(root.join("pkg")).unwrap();
+    fs::write(root.join("pkg/.gitignore"), "generated.py\n").unwrap();
+
+    let filter = WatchFilter::new(&[root.to_path_buf()]);
+
+    assert!(filter.is_relevant(&root.join("pkg/app.py")));
+    assert!(!filter.is_relevant(&root.join("build/out.py")));
+    assert!(!filter.is_relevant(&root.join("debug.log")));
+    assert!(!filter.is_relevant(&root.join("pkg/generated.py")));
+    assert!(!filter.is_relevant(&root.join(".git/index")));
+    assert!(!filter.is_relevant(&root.join(".siren/cache/abc.json")));
+}
+
+#[test]
+fn test_merge_runs_replaces_rerun_tools() {
+    let previous = run(&["clippy", "mypy", "pylint"], &["eslint"]);
+    let fresh = run(&["pylint"], &["mypy"]);
+
+    let merged = merge_runs(previous, fresh, &["pylint".to_string(), "mypy".to_string()]);
+
+    let names: Vec<_> = merged
+        .results
+        .iter()
+        .map(|r| r.tool_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["clippy", "pylint"]);
+    assert_eq!(merged.failed_tools, vec!["eslint", "mypy"]);
+}